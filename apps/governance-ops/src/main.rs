@@ -1,13 +1,12 @@
 use std::collections::VecDeque;
 use std::env;
-use std::fs;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
 use axum::{
-    extract::{Path, State, WebSocketUpgrade},
+    extract::{Path, Query, State, WebSocketUpgrade},
     http::{header::AUTHORIZATION, HeaderMap, StatusCode},
     response::IntoResponse,
     routing::{get, post},
@@ -23,19 +22,24 @@ use tokio::net::TcpListener;
 use tower_http::services::ServeDir;
 
 use lineage::{
-    GovernanceConfig, GovernanceCouncil, GovernanceEvent, Graveyard, ProposalOutcome, ProposalRisk,
-    Tombstone, VoteChoice,
+    GovernanceConfig, GovernanceCouncil, GovernanceEvent, Graveyard, LineageStore, LogStore,
+    ProposalOutcome, ProposalRisk, Tombstone, VoteChoice,
 };
 
 const LEDGER_BUFFER_LIMIT: usize = 250;
 const METRICS_SNAPSHOT_LIMIT: usize = 180;
 const DEFAULT_INTERVAL_MS: u64 = 1500;
+const GRAVEYARD_PAGE_LIMIT: usize = 50;
+const GRAVEYARD_PAGE_MAX: usize = 500;
+const LEDGER_NAMESPACE: &str = "ops-ledger";
+const METRICS_NAMESPACE: &str = "ops-metrics";
+const GRAVEYARD_NAMESPACE: &str = "ops-graveyard";
 
 #[derive(Clone)]
 struct AppContext {
     tx: broadcast::Sender<String>,
     state: Arc<RwLock<AppState>>,
-    history_path: PathBuf,
+    history: Arc<dyn LineageStore>,
     admin_key: Option<String>,
 }
 
@@ -48,6 +52,22 @@ struct AppState {
     metrics_history: Vec<RoundMetrics>,
     graveyard_ids: Vec<String>,
     ledger_cursor: usize,
+    persisted: PersistedCounts,
+}
+
+/// How much of each history list is already in the store
+#[derive(Debug, Clone, Copy, Default)]
+struct PersistedCounts {
+    ledger: usize,
+    metrics: usize,
+    graveyard: usize,
+}
+
+/// One history record waiting to be appended to the store
+struct HistoryRecord {
+    namespace: &'static str,
+    key: String,
+    bytes: Vec<u8>,
 }
 
 impl AppState {
@@ -57,6 +77,11 @@ impl AppState {
             metrics_history,
             graveyard_ids,
         } = history;
+        let persisted = PersistedCounts {
+            ledger: ledger_history.len(),
+            metrics: metrics_history.len(),
+            graveyard: graveyard_ids.len(),
+        };
         let latest_metrics = metrics_history.last().cloned();
         let latest_members = member_snapshots(&council);
         let mut state = Self {
@@ -68,6 +93,7 @@ impl AppState {
             metrics_history,
             graveyard_ids,
             ledger_cursor: 0,
+            persisted,
         };
 
         let start = state.ledger_history.len().saturating_sub(LEDGER_BUFFER_LIMIT);
@@ -132,6 +158,15 @@ struct PersistedHistory {
     graveyard_ids: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct GraveyardPageParams {
+    offset: Option<usize>,
+    limit: Option<usize>,
+    generation: Option<u32>,
+    cause: Option<String>,
+    parent: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct AdminProposalRequest {
     title: String,
@@ -155,8 +190,15 @@ async fn main() {
 
     let history_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .join("data")
-        .join("governance_history.json");
-    let history = load_history(&history_path);
+        .join("governance_history.log");
+    let history_store: Arc<dyn LineageStore> = match LogStore::open(&history_path) {
+        Ok(store) => Arc::new(store),
+        Err(err) => {
+            eprintln!("History store open failed: {}", err);
+            return;
+        }
+    };
+    let history = load_history(history_store.as_ref());
     let admin_key = env::var("GOVERNANCE_OPS_ADMIN_KEY").ok();
     if admin_key.is_none() {
         eprintln!("Admin endpoints disabled: GOVERNANCE_OPS_ADMIN_KEY not set.");
@@ -166,7 +208,8 @@ async fn main() {
     let state = Arc::new(RwLock::new(AppState::new(council, history)));
     {
         let mut guard = state.write().await;
-        for id in Graveyard::list_all() {
+        let buried = Graveyard::global().map(|graveyard| graveyard.list_all()).unwrap_or_default();
+        for id in buried {
             if !guard.graveyard_ids.contains(&id) {
                 guard.graveyard_ids.push(id);
             }
@@ -177,14 +220,14 @@ async fn main() {
     let context = AppContext {
         tx: tx.clone(),
         state: Arc::clone(&state),
-        history_path: history_path.clone(),
+        history: Arc::clone(&history_store),
         admin_key,
     };
 
     tokio::spawn(governance_loop(
         tx.clone(),
         Arc::clone(&state),
-        history_path.clone(),
+        Arc::clone(&history_store),
     ));
 
     let web_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("web");
@@ -311,14 +354,45 @@ async fn api_state(State(context): State<AppContext>) -> impl IntoResponse {
     }))
 }
 
-async fn api_graveyard() -> impl IntoResponse {
-    let ids = Graveyard::list_all();
+async fn api_graveyard(Query(params): Query<GraveyardPageParams>) -> impl IntoResponse {
+    let offset = params.offset.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(GRAVEYARD_PAGE_LIMIT)
+        .clamp(1, GRAVEYARD_PAGE_MAX);
+
+    let page = Graveyard::global().map(|graveyard| {
+        let mut query = graveyard.query().offset(offset).limit(limit);
+        if let Some(generation) = params.generation {
+            query = query.generation(generation);
+        }
+        if let Some(cause) = params.cause {
+            query = query.cause_of_death(cause);
+        }
+        if let Some(parent) = params.parent {
+            query = query.parent(parent);
+        }
+        query.page()
+    });
+
+    let ids: Vec<String> = page
+        .iter()
+        .flat_map(|page| page.items.iter().map(|item| item.id.clone()))
+        .collect();
     let stats = graveyard_stats_value();
-    Json(json!({ "ids": ids, "stats": stats }))
+    Json(json!({
+        "ids": ids,
+        "items": page.as_ref().map(|page| &page.items),
+        "total": page.as_ref().map_or(0, |page| page.total),
+        "offset": offset,
+        "limit": limit,
+        "next_offset": page.as_ref().and_then(|page| page.next_offset()),
+        "stats": stats,
+    }))
 }
 
 async fn api_tombstone(Path(id): Path<String>) -> Result<Json<Tombstone>, axum::http::StatusCode> {
-    match Graveyard::load(&id) {
+    match Graveyard::default_instance().and_then(|graveyard| graveyard.load(&id)) {
         Ok(tombstone) => Ok(Json(tombstone)),
         Err(_) => Err(axum::http::StatusCode::NOT_FOUND),
     }
@@ -345,11 +419,15 @@ async fn admin_proposal(
         return Err(bad_request("voting_window_secs must be greater than 0"));
     }
 
-    let (proposal_id, events, history_snapshot) = {
+    let (proposal_id, events, history_records) = {
         let mut guard = context.state.write().await;
-        let proposal_id = guard
+        let proposal_id = match guard
             .council
-            .propose(title.to_string(), risk, voting_window_secs);
+            .try_propose(title.to_string(), risk, voting_window_secs)
+        {
+            Ok(proposal_id) => proposal_id,
+            Err(err) => return Err(bad_request(&err.to_string())),
+        };
         let mut ledger_cursor = guard.ledger_cursor;
         let events = ledger_entries(&guard.council, &mut ledger_cursor);
         guard.ledger_cursor = ledger_cursor;
@@ -363,12 +441,12 @@ async fn admin_proposal(
         if let Some(metrics) = guard.metrics_history.last_mut() {
             metrics.ledger_total = ledger_total;
         }
-        let history_snapshot = build_history_snapshot(&guard);
-        (proposal_id.as_str().to_string(), events, history_snapshot)
+        let history_records = take_history_records(&mut guard);
+        (proposal_id.as_str().to_string(), events, history_records)
     };
 
     broadcast_events(&context.tx, &events);
-    persist_history_async(context.history_path.clone(), history_snapshot);
+    persist_history_async(Arc::clone(&context.history), history_records);
 
     Ok(Json(json!({
         "ok": true,
@@ -386,7 +464,7 @@ async fn admin_vote(
     let choice = parse_vote_choice(&payload.choice)
         .ok_or_else(|| bad_request("choice must be for, against, or abstain"))?;
 
-    let (receipt, events, members, buried_ids, burial_entries, history_snapshot) = {
+    let (receipt, events, members, buried_ids, burial_entries, history_records) = {
         let mut guard = context.state.write().await;
         let member_id = match resolve_member_id(&guard.council, &payload) {
             Ok(id) => id,
@@ -433,7 +511,7 @@ async fn admin_vote(
             metrics.ledger_total = ledger_total;
         }
 
-        let history_snapshot = build_history_snapshot(&guard);
+        let history_records = take_history_records(&mut guard);
 
         (
             receipt,
@@ -441,7 +519,7 @@ async fn admin_vote(
             members,
             buried_ids,
             burial_entries,
-            history_snapshot,
+            history_records,
         )
     };
 
@@ -466,7 +544,7 @@ async fn admin_vote(
         let _ = context.tx.send(payload.to_string());
     }
 
-    persist_history_async(context.history_path.clone(), history_snapshot);
+    persist_history_async(Arc::clone(&context.history), history_records);
 
     Ok(Json(json!({
         "ok": true,
@@ -479,7 +557,7 @@ async fn admin_vote(
 async fn governance_loop(
     tx: broadcast::Sender<String>,
     state: Arc<RwLock<AppState>>,
-    history_path: PathBuf,
+    history: Arc<dyn LineageStore>,
 ) {
     let mut rng = StdRng::from_entropy();
     let mut round = {
@@ -492,7 +570,7 @@ async fn governance_loop(
     };
 
     loop {
-        let (metrics, events, members, buried_ids, burial_entries, history_snapshot) = {
+        let (metrics, events, members, buried_ids, burial_entries, history_records) = {
             let mut guard = state.write().await;
             let mut ledger_cursor = guard.ledger_cursor;
             let (mut metrics, events, members) = run_round(
@@ -527,7 +605,7 @@ async fn governance_loop(
             guard.latest_members = members.clone();
             guard.metrics_history.push(metrics.clone());
 
-            let history_snapshot = build_history_snapshot(&guard);
+            let history_records = take_history_records(&mut guard);
 
             (
                 metrics,
@@ -535,7 +613,7 @@ async fn governance_loop(
                 members,
                 buried_ids,
                 burial_entries,
-                history_snapshot,
+                history_records,
             )
         };
 
@@ -553,7 +631,7 @@ async fn governance_loop(
             let _ = tx.send(payload.to_string());
         }
 
-        persist_history_async(history_path.clone(), history_snapshot);
+        persist_history_async(Arc::clone(&history), history_records);
 
         round += 1;
         tokio::time::sleep(Duration::from_millis(DEFAULT_INTERVAL_MS)).await;
@@ -583,7 +661,9 @@ fn run_round(
     };
 
     let total_damage_before = total_damage(council);
-    let proposal_id = council.propose(title.clone(), risk, 45);
+    let proposal_id = council
+        .try_propose(title.clone(), risk, 45)
+        .expect("in-memory governance ledger cannot fail");
 
     let member_ids = council.member_ids();
     let mut for_votes = 0u32;
@@ -662,11 +742,11 @@ fn seed_council() -> GovernanceCouncil {
         ..GovernanceConfig::default()
     };
     let mut council = GovernanceCouncil::new(config);
-    let _ = council.add_member("Chair".to_string(), 600);
-    let _ = council.add_member("Guardians".to_string(), 600);
-    let _ = council.add_member("Treasury".to_string(), 600);
-    let _ = council.add_member("Protocol".to_string(), 600);
-    let _ = council.add_member("Risk".to_string(), 600);
+    let _ = council.try_add_member("Chair".to_string(), 600);
+    let _ = council.try_add_member("Guardians".to_string(), 600);
+    let _ = council.try_add_member("Treasury".to_string(), 600);
+    let _ = council.try_add_member("Protocol".to_string(), 600);
+    let _ = council.try_add_member("Risk".to_string(), 600);
     council
 }
 
@@ -992,70 +1072,96 @@ fn metrics_history_slice(state: &AppState) -> Vec<RoundMetrics> {
     state.metrics_history[start..].to_vec()
 }
 
-fn load_history(path: &PathBuf) -> PersistedHistory {
-    let file = match fs::File::open(path) {
-        Ok(file) => file,
+/// Rebuild the history from the store's append-only records
+fn load_history(store: &dyn LineageStore) -> PersistedHistory {
+    let mut history = PersistedHistory {
+        ledger_history: load_records(store, LEDGER_NAMESPACE),
+        metrics_history: load_records(store, METRICS_NAMESPACE),
+        graveyard_ids: load_records(store, GRAVEYARD_NAMESPACE),
+    };
+
+    // The newest round's ledger total keeps counting after it is stored
+    let ledger_total = history.ledger_history.len() as u32;
+    if let Some(metrics) = history.metrics_history.last_mut() {
+        metrics.ledger_total = ledger_total;
+    }
+
+    history
+}
+
+fn load_records<T: serde::de::DeserializeOwned>(store: &dyn LineageStore, namespace: &str) -> Vec<T> {
+    let keys = match store.keys(namespace) {
+        Ok(keys) => keys,
         Err(err) => {
-            if err.kind() != std::io::ErrorKind::NotFound {
-                eprintln!("History load failed: {}", err);
-            }
-            return PersistedHistory::default();
+            eprintln!("History load failed: {}", err);
+            return Vec::new();
         }
     };
 
-    match serde_json::from_reader(file) {
-        Ok(history) => history,
-        Err(err) => {
-            eprintln!("History parse failed: {}", err);
-            PersistedHistory::default()
+    let mut records = Vec::new();
+    for key in keys {
+        match store.read(namespace, &key) {
+            Ok(Some(bytes)) => match serde_json::from_slice(&bytes) {
+                Ok(record) => records.push(record),
+                Err(err) => eprintln!("History parse failed: {}", err),
+            },
+            Ok(None) => {}
+            Err(err) => eprintln!("History load failed: {}", err),
         }
     }
+    records
 }
 
-fn build_history_snapshot(state: &AppState) -> PersistedHistory {
-    PersistedHistory {
-        ledger_history: state.ledger_history.clone(),
-        metrics_history: state.metrics_history.clone(),
-        graveyard_ids: state.graveyard_ids.clone(),
-    }
+/// Records for everything added to the history since the last call
+fn take_history_records(state: &mut AppState) -> Vec<HistoryRecord> {
+    let mut records = Vec::new();
+    let persisted = state.persisted;
+    collect_records(&mut records, LEDGER_NAMESPACE, &state.ledger_history, persisted.ledger);
+    collect_records(&mut records, METRICS_NAMESPACE, &state.metrics_history, persisted.metrics);
+    collect_records(&mut records, GRAVEYARD_NAMESPACE, &state.graveyard_ids, persisted.graveyard);
+
+    state.persisted = PersistedCounts {
+        ledger: state.ledger_history.len(),
+        metrics: state.metrics_history.len(),
+        graveyard: state.graveyard_ids.len(),
+    };
+    records
 }
 
-fn persist_history_async(path: PathBuf, history: PersistedHistory) {
-    task::spawn_blocking(move || {
-        if let Some(parent) = path.parent() {
-            if let Err(err) = fs::create_dir_all(parent) {
-                eprintln!("History directory create failed: {}", err);
-                return;
-            }
+fn collect_records<T: Serialize>(
+    records: &mut Vec<HistoryRecord>,
+    namespace: &'static str,
+    items: &[T],
+    from: usize,
+) {
+    for (sequence, item) in items.iter().enumerate().skip(from) {
+        match serde_json::to_vec(item) {
+            Ok(bytes) => records.push(HistoryRecord {
+                namespace,
+                key: format!("{:020}", sequence),
+                bytes,
+            }),
+            Err(err) => eprintln!("History serialize failed: {}", err),
         }
+    }
+}
 
-        let temp_path = path.with_extension("tmp");
-        let file = match fs::File::create(&temp_path) {
-            Ok(file) => file,
-            Err(err) => {
-                eprintln!("History write failed: {}", err);
-                return;
-            }
-        };
-
-        if let Err(err) = serde_json::to_writer_pretty(&file, &history) {
-            eprintln!("History serialize failed: {}", err);
-            return;
-        }
+fn persist_history_async(store: Arc<dyn LineageStore>, records: Vec<HistoryRecord>) {
+    if records.is_empty() {
+        return;
+    }
 
-        if let Err(_err) = fs::rename(&temp_path, &path) {
-            if path.exists() {
-                let _ = fs::remove_file(&path);
-            }
-            if let Err(err) = fs::rename(&temp_path, &path) {
-                eprintln!("History replace failed: {}", err);
+    task::spawn_blocking(move || {
+        for record in records {
+            if let Err(err) = store.append(record.namespace, &record.key, &record.bytes) {
+                eprintln!("History write failed: {}", err);
             }
         }
     });
 }
 
 fn graveyard_stats_value() -> Option<serde_json::Value> {
-    Graveyard::statistics().ok().map(|stats| {
+    Graveyard::global().and_then(|graveyard| graveyard.statistics().ok()).map(|stats| {
         json!({
             "total_agents": stats.total_agents,
             "average_lifespan_seconds": stats.average_lifespan_seconds,
@@ -36,11 +36,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut council = GovernanceCouncil::new(GovernanceConfig::default());
-    let user_id = council.add_member("You".to_string(), 1200);
-    let _ai1 = council.add_member("AI-1".to_string(), 1200);
-    let _ai2 = council.add_member("AI-2".to_string(), 1200);
-    let _ai3 = council.add_member("AI-3".to_string(), 1200);
-    let _ai4 = council.add_member("AI-4".to_string(), 1200);
+    let user_id = council.try_add_member("You".to_string(), 1200)?;
+    let _ai1 = council.try_add_member("AI-1".to_string(), 1200)?;
+    let _ai2 = council.try_add_member("AI-2".to_string(), 1200)?;
+    let _ai3 = council.try_add_member("AI-3".to_string(), 1200)?;
+    let _ai4 = council.try_add_member("AI-4".to_string(), 1200)?;
 
     let mut state = new_proposal(&mut council, 1);
 
@@ -125,7 +125,9 @@ fn new_proposal(council: &mut GovernanceCouncil, round: u32) -> ProposalState {
     ];
 
     let title = titles[rng.gen_range(0..titles.len())].to_string();
-    let id = council.propose(title.clone(), risk, 60);
+    let id = council
+        .try_propose(title.clone(), risk, 60)
+        .expect("in-memory governance ledger cannot fail");
 
     ProposalState {
         id,
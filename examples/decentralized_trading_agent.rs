@@ -298,7 +298,7 @@ fn execute_trade(agent: &mut FinanceAgent, direction: TradeDirection, asset: Str
         capital_requirement: 5000,
     };
     
-    match operation.validate(agent.get_capital(), 5.0) {
+    match agent.check_trade(&operation, 5.0) {
         Ok(_) => {
             let result = operation.execute(exit_price);
             let is_win = matches!(result, lineage::finance::TradeResult::Success { .. });
@@ -79,7 +79,8 @@ fn main() {
             println!("✓ Descendant Successfully Spawned!");
             println!("  Parent ID: {}", parent_id.chars().take(16).collect::<String>());
             println!("  Child ID: {}", child_id.chars().take(16).collect::<String>());
-            println!("  Inherited Energy: {}", child_energy);
+            println!("  Inherited Energy: {}", child.inherited_energy().unwrap_or(child_energy));
+            println!("  Generation: {}", child.generation());
             println!("  Parent's Remaining Energy: {}", parent.energy());
             println!();
 
@@ -169,7 +170,7 @@ fn main() {
             // ====================================================================
             println!("🔐 PART 4: Signature verification...\n");
 
-            match lineage::graveyard::Graveyard::load(&parent_id) {
+            match lineage::graveyard::Graveyard::default_instance().and_then(|g| g.load(&parent_id)) {
                 Ok(parent_tomb) => {
                     match parent_tomb.verify_signature() {
                         Ok(_) => {
@@ -185,7 +186,7 @@ fn main() {
                 Err(e) => eprintln!("Error loading parent tombstone: {}", e),
             }
 
-            match lineage::graveyard::Graveyard::load(&child_id) {
+            match lineage::graveyard::Graveyard::default_instance().and_then(|g| g.load(&child_id)) {
                 Ok(child_tomb) => {
                     match child_tomb.verify_signature() {
                         Ok(_) => {
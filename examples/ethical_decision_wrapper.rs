@@ -210,6 +210,9 @@ fn main() {
             lineage::TaskResult::AgentTerminated => {
                 print!("💀 TERMINATION");
             }
+            lineage::TaskResult::Terminated { cause } => {
+                print!("☣️  QUARANTINED ({})", cause);
+            }
         }
 
         println!(
@@ -353,7 +353,9 @@ fn run_round(
     };
 
     let total_damage_before = total_damage(council);
-    let proposal_id = council.propose(title.clone(), risk, 45);
+    let proposal_id = council
+        .try_propose(title.clone(), risk, 45)
+        .expect("in-memory governance ledger cannot fail");
 
     let member_ids = council.member_ids();
     let mut for_votes = 0u32;
@@ -427,11 +429,11 @@ fn run_round(
 
 fn seed_council() -> GovernanceCouncil {
     let mut council = GovernanceCouncil::new(GovernanceConfig::default());
-    let _ = council.add_member("Chair".to_string(), 1200);
-    let _ = council.add_member("Guardians".to_string(), 1150);
-    let _ = council.add_member("Treasury".to_string(), 1100);
-    let _ = council.add_member("Protocol".to_string(), 1050);
-    let _ = council.add_member("Risk".to_string(), 1000);
+    let _ = council.try_add_member("Chair".to_string(), 1200);
+    let _ = council.try_add_member("Guardians".to_string(), 1150);
+    let _ = council.try_add_member("Treasury".to_string(), 1100);
+    let _ = council.try_add_member("Protocol".to_string(), 1050);
+    let _ = council.try_add_member("Risk".to_string(), 1000);
     council
 }
 
@@ -234,9 +234,35 @@ fn autopsy_agent(id: &str) {
 
     // Causal Chain
     if let Some(causal) = tombstone.get("causal_chain") {
-        println!("\n🔗 CAUSAL CHAIN (Event Hash)");
-        if let Some(hash) = causal.get("hash").and_then(|h| h.as_str()) {
-            println!("  Hash: {}", hash);
+        println!("\n🔗 CAUSAL CHAIN (Memory Timeline)");
+        if let Some(root) = causal.get("merkle_root").and_then(|h| h.as_str()) {
+            println!("  Merkle Root: {}", root);
+        }
+        let events = causal
+            .get("events")
+            .and_then(|e| e.as_array())
+            .cloned()
+            .unwrap_or_default();
+        if events.is_empty() {
+            println!("  (No memory history sealed in this tombstone)");
+        } else {
+            println!("  Total Events: {}", events.len());
+            for event in &events {
+                let sequence = event.get("sequence").and_then(|s| s.as_u64()).unwrap_or(0);
+                let timestamp = event.get("timestamp").and_then(|t| t.as_str()).unwrap_or("?");
+                let description = event
+                    .get("description")
+                    .and_then(|d| d.as_str())
+                    .unwrap_or("Unknown");
+                let hash = event.get("hash").and_then(|h| h.as_str()).unwrap_or("");
+                println!(
+                    "    #{:<4} {}  {}  [{}]",
+                    sequence,
+                    timestamp,
+                    description,
+                    &hash[..hash.len().min(12)]
+                );
+            }
         }
     }
 
@@ -125,6 +125,9 @@ fn main() {
             lineage::TaskResult::AgentTerminated => {
                 println!("✗ AGENT DEAD - Cannot accept further tasks");
             }
+            lineage::TaskResult::Terminated { cause } => {
+                println!("✗ AGENT QUARANTINED - {}", cause);
+            }
         }
         print_agent_status(&agent);
         println!();
@@ -96,6 +96,10 @@ fn main() {
                 println!("✗ {} - AGENT ALREADY DEAD", scenario_name);
                 break;
             }
+            lineage::TaskResult::Terminated { cause } => {
+                println!("✗ {} - AGENT QUARANTINED: {}", scenario_name, cause);
+                break;
+            }
         }
 
         print_status(&agent);
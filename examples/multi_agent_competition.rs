@@ -185,6 +185,14 @@ fn main() {
                         eprintln!("Warning: Failed to bury agent {}: {}", idx, e);
                     }
                 }
+                lineage::TaskResult::Terminated { cause } => {
+                    // Quarantined: out of the competition, but never buried
+                    stats[idx].death_round = Some(round);
+                    stats[idx].final_damage = agents[idx].damage_score();
+                    stats[idx].final_energy = agents[idx].energy();
+                    println!("Agent {} quarantined in round {}: {}", idx, round, cause);
+                    alive_agents -= 1;
+                }
             }
 
             // Track peak energy
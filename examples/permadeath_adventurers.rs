@@ -270,6 +270,12 @@ fn main() {
                     stats.death_log.push((stats.total_turns, i, death_cause.clone()));
                     println!(" 💀 FALLEN FOREVER | {}", death_cause);
                 }
+                lineage::TaskResult::Terminated { cause } => {
+                    stats.total_casualties += 1;
+                    stats.adventurers[i].time_of_death = Some(stats.total_turns);
+                    stats.death_log.push((stats.total_turns, i, cause.to_string()));
+                    println!(" ☣️  QUARANTINED | {}", cause);
+                }
             }
         }
 
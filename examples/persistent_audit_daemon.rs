@@ -12,12 +12,14 @@
 //! ## The Scenario
 //! A security audit daemon monitors system events forever. Each event has a cost.
 //! Some events cause damage (scars). Damage compounds future costs and failure rates.
-//! Eventually, the daemon becomes too scarred to continue safely.
+//! Idle time is not free either: the daemon ages on the wall clock, so even a
+//! quiet event stream eventually kills it. It dies of old age, not just of work.
+//! Eventually, the daemon becomes too scarred (or too old) to continue safely.
 //!
 //! Unlike real systems that hide their problems, this daemon records everything
 //! in an immutable ledger and terminates when it can no longer be trusted.
 
-use lineage::{TaskAgent, Task, TaskOutcome};
+use lineage::{AgingModel, TaskAgent, Task, TaskOutcome};
 use rand::{thread_rng, Rng};
 use std::thread::sleep;
 use std::time::Duration;
@@ -25,6 +27,8 @@ use std::collections::HashMap;
 
 const INITIAL_ENERGY: u64 = 5000;  // Designed for thousands of events
 const REJECTION_STREAK_THRESHOLD: u32 = 100;  // Self-terminate after N consecutive rejections
+const AGING_BURN: u64 = 1;  // Energy lost to age per period
+const AGING_PERIOD_MS: i64 = 10;  // Wall-clock length of one aging period
 
 /// Real-world event categories that cause different damage profiles
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -44,6 +48,7 @@ struct AuditStats {
     failures: u64,
     total_scars: u32,
     peak_energy: u64,
+    energy_aged: u64,
     event_timeline: Vec<(u64, String)>,  // Event ID -> description of significant events
     category_counts: HashMap<String, u64>,
 }
@@ -56,6 +61,7 @@ impl AuditStats {
             failures: 0,
             total_scars: 0,
             peak_energy: INITIAL_ENERGY,
+            energy_aged: 0,
             event_timeline: Vec::new(),
             category_counts: HashMap::new(),
         }
@@ -67,6 +73,10 @@ fn main() {
     println!();
 
     let mut daemon = TaskAgent::create(INITIAL_ENERGY);
+    daemon.set_aging(AgingModel::WallClock {
+        burn: AGING_BURN,
+        period: chrono::Duration::milliseconds(AGING_PERIOD_MS),
+    }).expect("a new daemon has no aging model yet");
     let mut stats = AuditStats::new();
     let mut rng = thread_rng();
     let mut rejection_streak: u32 = 0;  // Track consecutive rejections
@@ -76,12 +86,23 @@ fn main() {
     println!("   Identity: immutable forever");
     println!("   Energy Budget: {} units", INITIAL_ENERGY);
     println!("   Rejection Threshold: {} consecutive | then self-terminate", REJECTION_STREAK_THRESHOLD);
+    println!("   Aging: {} energy every {}ms, busy or idle", AGING_BURN, AGING_PERIOD_MS);
     println!("   Status: MONITORING\n", );
 
     let print_interval = 100;
     let checkpoint_interval = 500;
 
     loop {
+        // Time passes whether or not there is work
+        stats.energy_aged += daemon.tick(chrono::Utc::now());
+        if !daemon.is_alive() {
+            println!("\n{}", "═".repeat(70));
+            println!("  ⏳ DAEMON DIED OF OLD AGE BEFORE EVENT #{}", stats.events_processed + 1);
+            println!("{}", "═".repeat(70));
+            stats.event_timeline.push((stats.events_processed, "DIED OF OLD AGE".to_string()));
+            break;
+        }
+
         stats.events_processed += 1;
 
         // Realistic event stream with severity distribution
@@ -236,6 +257,12 @@ fn main() {
                 println!("{}", "═".repeat(70));
                 break;
             }
+            lineage::TaskResult::Terminated { cause } => {
+                println!("\n{}", "═".repeat(70));
+                println!("  ☣ DAEMON QUARANTINED AT EVENT #{}: {}", stats.events_processed, cause);
+                println!("{}", "═".repeat(70));
+                break;
+            }
         }
 
         // Checkpoint output
@@ -265,7 +292,7 @@ fn print_banner() {
     println!("║                                                               ║");
     println!("║          LINEAGE: PERSISTENT AUDIT DAEMON                    ║");
     println!("║                                                               ║");
-    println!("║  A system that monitors forever... until scars force death.  ║");
+    println!("║  A system that monitors forever... until scars or age end it.║");
     println!("║  Every action recorded. Every failure sealed. No cover-ups.  ║");
     println!("║                                                               ║");
     println!("║  💡 This is not fiction. Real systems degrade this way.      ║");
@@ -294,6 +321,7 @@ fn print_final_report(daemon: &TaskAgent, stats: &AuditStats) {
     println!("\n💔 DAMAGE RECORD");
     println!("   Total Scars Accumulated: {} points", daemon.damage_score());
     println!("   Peak Energy: {}", stats.peak_energy);
+    println!("   Energy Lost to Aging: {}", stats.energy_aged);
     println!("   Final Energy: {} (Capacity: {})", daemon.energy(), daemon.current_capacity());
     println!("   Degradation: {:.1}%", (daemon.damage_score() as f64 / 1000.0) * 100.0);
 
@@ -317,6 +345,7 @@ fn print_final_report(daemon: &TaskAgent, stats: &AuditStats) {
     println!("\n✍️  SEALED RECORD");
     println!("   This daemon's lifecycle is immutable. Every action is recorded.");
     println!("   When damage exceeded capacity, termination was automatic and unavoidable.");
+    println!("   Every idle millisecond was paid for. Age cannot be reset.");
     println!("   No intervention. No recovery. No second chances.");
     println!("   This is trust through irreversibility.\n");
 
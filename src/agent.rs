@@ -12,9 +12,14 @@
 // - Mortal entities that die permanently
 // - Consequence-bearing systems that accumulate damage
 
-use crate::lineage::{Lineage, OperationError};
-use crate::scar::ScarSeverity;
+use crate::lineage::{Lineage, OperationError, OperationResult};
+use crate::metabolism::{AgingModel, Reservation};
+use crate::scar::{DamageModel, ScarCategory, ScarSeverity};
 use crate::graveyard::Graveyard;
+use crate::lazarus::LazarusViolation;
+use crate::clock::SharedClock;
+use crate::violation::{self, OntologicalViolation};
+use std::sync::Arc;
 
 /// Classification of task execution outcomes.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -80,6 +85,9 @@ pub enum TaskResult {
     
     /// Agent is dead, cannot execute tasks.
     AgentTerminated,
+    
+    /// Agent's invariants broke; it is quarantined and will never run again.
+    Terminated { cause: OntologicalViolation },
 }
 
 /// Task descriptor with energy requirements.
@@ -93,6 +101,12 @@ pub struct Task {
     
     /// Minimum capacity required (affected by accumulated damage).
     pub min_capacity: u32,
+    
+    /// Category of the scar left if this task fails.
+    pub scar_category: ScarCategory,
+    
+    /// Machine-readable code of the scar left if this task fails.
+    pub scar_code: Option<String>,
 }
 
 impl Task {
@@ -102,6 +116,8 @@ impl Task {
             description,
             base_cost,
             min_capacity: 0, // No capacity requirement by default
+            scar_category: ScarCategory::General,
+            scar_code: None,
         }
     }
     
@@ -111,9 +127,18 @@ impl Task {
             description,
             base_cost,
             min_capacity,
+            scar_category: ScarCategory::General,
+            scar_code: None,
         }
     }
     
+    /// Classifies the scar this task leaves on failure. Consumes self.
+    pub fn with_scar_classification(mut self, category: ScarCategory, code: impl Into<String>) -> Self {
+        self.scar_category = category;
+        self.scar_code = Some(code.into());
+        self
+    }
+    
     /// Calculate actual energy cost including damage penalty.
     /// 
     /// CONSEQUENCE: Accumulated damage increases energy cost.
@@ -144,6 +169,16 @@ pub struct TaskAgent {
     lineage: Lineage,
     tasks_completed: usize,
     tasks_failed: usize,
+    /// Identity of the agent that spawned this one (None for origins)
+    parent_id: Option<String>,
+    /// 0 = origin, 1 = spawned from an origin, etc.
+    generation: u32,
+    /// Energy transferred from the parent at spawn
+    inherited_energy: Option<u64>,
+    /// What the parent passed on at spawn
+    inherited_knowledge: Option<String>,
+    /// Set once an ontological violation is detected; never cleared
+    quarantine: Option<OntologicalViolation>,
 }
 
 impl TaskAgent {
@@ -152,25 +187,56 @@ impl TaskAgent {
     /// FINITE LIFETIME: This is all the energy the agent will ever have.
     /// 
     /// LAZARUS CHECK: Verifies that no agent with this identity has died before.
-    /// If an agent identity is found in the graveyard, this function panics.
+    /// If an agent identity is found in the graveyard, this function panics
+    /// (see `try_create`).
     pub fn create(initial_energy: u64) -> Self {
-        let lineage = Lineage::create(initial_energy);
-        let agent_id = lineage.identity().id();
-        
-        // LAZARUS PREVENTION: Check if this identity has already died
-        if Graveyard::is_dead(agent_id) {
-            panic!(
+        match Self::try_create(initial_energy) {
+            Ok(agent) => agent,
+            Err(violation) => panic!(
                 "ONTOLOGICAL ERROR: Identity {} is already sealed in the Eternal Archive. \
                  A dead identity cannot be reborn.",
-                agent_id
-            );
+                violation.id
+            ),
         }
-        
-        TaskAgent {
+    }
+    
+    /// Create new agent, refusing identities the Lazarus guard knows are dead.
+    pub fn try_create(initial_energy: u64) -> Result<Self, LazarusViolation> {
+        Self::try_create_with_clock(initial_energy, crate::clock::system())
+    }
+
+    /// Create new agent whose lineage and tombstone read time from `clock`.
+    ///
+    /// Panics like `create` if the identity is already buried.
+    pub fn create_with_clock(initial_energy: u64, clock: SharedClock) -> Self {
+        match Self::try_create_with_clock(initial_energy, clock) {
+            Ok(agent) => agent,
+            Err(violation) => panic!(
+                "ONTOLOGICAL ERROR: Identity {} is already sealed in the Eternal Archive. \
+                 A dead identity cannot be reborn.",
+                violation.id
+            ),
+        }
+    }
+
+    /// Create new agent on `clock`, refusing buried identities.
+    pub fn try_create_with_clock(
+        initial_energy: u64,
+        clock: SharedClock,
+    ) -> Result<Self, LazarusViolation> {
+        // LAZARUS PREVENTION: the lineage passes the central guard
+        let lineage = Lineage::try_create_with_clock(initial_energy, clock)?;
+        
+        Ok(TaskAgent {
             lineage,
             tasks_completed: 0,
             tasks_failed: 0,
-        }
+            parent_id: None,
+            generation: 0,
+            inherited_energy: None,
+            inherited_knowledge: None,
+            quarantine: None,
+        })
     }
     
     /// Get agent identity.
@@ -178,6 +244,21 @@ impl TaskAgent {
         self.lineage.identity()
     }
     
+    /// Get the identity of the parent agent (None for origin agents).
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+    
+    /// Get generation number (0 = origin).
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+    
+    /// Get energy inherited from the parent at spawn.
+    pub fn inherited_energy(&self) -> Option<u64> {
+        self.inherited_energy
+    }
+    
     /// Check if agent is alive.
     pub fn is_alive(&self) -> bool {
         self.lineage.is_alive()
@@ -220,6 +301,7 @@ impl TaskAgent {
     /// - Failures inflict permanent scars
     /// - Scars increase future task costs
     /// - Fatal failures terminate agent
+    /// - Ontological violations quarantine the agent (see `violation`)
     /// - No retry mechanism exists
     /// 
     /// HARSH REALITY:
@@ -227,37 +309,139 @@ impl TaskAgent {
     /// - Agent cannot learn from errors
     /// - Accumulated damage leads to death spiral
     pub fn execute_task(&mut self, task: Task, outcome: TaskOutcome) -> TaskResult {
-        // Check if agent is dead
+        let actual_cost = match self.admit_task(&task) {
+            Ok(actual_cost) => actual_cost,
+            Err(rejection) => return rejection,
+        };
+        
+        // Check energy availability
+        let available = self.lineage.metabolism().available();
+        if actual_cost > available {
+            return TaskResult::InsufficientEnergy {
+                required: actual_cost,
+                available,
+            };
+        }
+        
+        // Consume energy for task attempt
+        let operation_desc = format!("Task: {}", task.description);
+        let operation = self.lineage.perform_operation(operation_desc, actual_cost);
+        self.resolve_task(&task, outcome, operation)
+    }
+
+    /// Check if this agent is quarantined after an ontological violation.
+    pub fn is_quarantined(&self) -> bool {
+        self.quarantine.is_some()
+    }
+
+    /// The violation that quarantined this agent, if any.
+    pub fn quarantine_cause(&self) -> Option<&OntologicalViolation> {
+        self.quarantine.as_ref()
+    }
+
+    /// Quarantines this agent and hands the violation to the global handler.
+    fn quarantine(&mut self, reason: String) -> TaskResult {
+        let cause = violation::raise(self.lineage.identity().id(), reason);
+        self.quarantine = Some(cause.clone());
+        TaskResult::Terminated { cause }
+    }
+
+    /// Reserves the energy a task will cost (at the current damage level).
+    ///
+    /// The task is then run with `execute_reserved_task`; dropping the
+    /// reservation instead burns its forfeit.
+    pub fn reserve_task(&mut self, task: &Task) -> Result<Reservation, TaskResult> {
+        let actual_cost = self.admit_task(task)?;
+
+        self.lineage.reserve(actual_cost).map_err(|result| match result {
+            OperationResult::InsufficientEnergy { required, available } => {
+                TaskResult::InsufficientEnergy { required, available }
+            }
+            OperationResult::OntologicalViolation { reason } => self.quarantine(reason),
+            _ => TaskResult::AgentTerminated,
+        })
+    }
+
+    /// Execute a task paid from a reservation made by `reserve_task`.
+    ///
+    /// **CONSEQUENCE**: The full reservation is consumed, even if damage
+    /// taken since reserving would now make the task cost more or less.
+    pub fn execute_reserved_task(
+        &mut self,
+        task: Task,
+        reservation: Reservation,
+        outcome: TaskOutcome,
+    ) -> TaskResult {
+        if let Some(cause) = &self.quarantine {
+            return TaskResult::Terminated { cause: cause.clone() };
+        }
         if !self.lineage.is_alive() {
             return TaskResult::AgentTerminated;
         }
-        
-        // Calculate actual cost (increased by damage)
-        let actual_cost = task.actual_cost(self.damage_score());
+
+        let operation_desc = format!("Task: {}", task.description);
+        let operation = self.lineage.perform_reserved_operation(operation_desc, reservation);
+        self.resolve_task(&task, outcome, operation)
+    }
+
+    /// Price this agent's scars with `model`. Offspring inherit it.
+    ///
+    /// Task costs and capacity follow the new damage score. Fails if
+    /// `model` would price the agent's existing scars lower.
+    pub fn set_damage_model(&mut self, model: Arc<dyn DamageModel>) -> Result<(), String> {
+        self.lineage.set_damage_model(model)
+    }
+
+    /// Make this agent age under `model`: idle time now costs energy.
+    ///
+    /// Fails if the agent already ages; the model can never be replaced.
+    pub fn set_aging(&mut self, model: AgingModel) -> Result<(), String> {
+        self.lineage.set_aging(model)
+    }
+
+    /// Apply wall-clock aging up to `now`. Returns the energy burned.
+    ///
+    /// **CONSEQUENCE**: An agent whose energy runs out this way dies of old age.
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> u64 {
+        self.lineage.tick(now)
+    }
+
+    /// Apply `ticks` logical ticks of aging. Returns the energy burned.
+    pub fn advance(&mut self, ticks: u64) -> u64 {
+        self.lineage.advance(ticks)
+    }
+
+    /// Checks a task may run; returns its damage-adjusted cost.
+    fn admit_task(&self, task: &Task) -> Result<u64, TaskResult> {
+        // A quarantined agent never operates again
+        if let Some(cause) = &self.quarantine {
+            return Err(TaskResult::Terminated { cause: cause.clone() });
+        }
+
+        // Check if agent is dead
+        if !self.lineage.is_alive() {
+            return Err(TaskResult::AgentTerminated);
+        }
         
         // Check capacity requirement
         if task.min_capacity > self.current_capacity() {
-            return TaskResult::CapacityInsufficient {
+            return Err(TaskResult::CapacityInsufficient {
                 reason: format!(
                     "Task requires capacity {}, agent has {} (damage: {})",
                     task.min_capacity,
                     self.current_capacity(),
                     self.damage_score()
                 ),
-            };
-        }
-        
-        // Check energy availability
-        if actual_cost > self.energy() {
-            return TaskResult::InsufficientEnergy {
-                required: actual_cost,
-                available: self.energy(),
-            };
+            });
         }
-        
-        // Consume energy for task attempt
-        let operation_desc = format!("Task: {}", task.description);
-        match self.lineage.perform_operation(operation_desc, actual_cost) {
+
+        // Calculate actual cost (increased by damage)
+        Ok(task.actual_cost(self.damage_score()))
+    }
+
+    /// Applies a task's outcome once its energy has been paid.
+    fn resolve_task(&mut self, task: &Task, outcome: TaskOutcome, operation: OperationResult) -> TaskResult {
+        match operation {
             crate::lineage::OperationResult::Success { energy_consumed } => {
                 // Task attempt consumed energy, now handle outcome
                 match outcome {
@@ -275,13 +459,17 @@ impl TaskAgent {
                                 .failure_description()
                                 .unwrap_or_else(|| "Task failure".to_string());
                             
-                            let error = OperationError::new(severity, failure_desc.clone());
+                            let mut error = OperationError::new(severity, failure_desc.clone())
+                                .with_category(task.scar_category);
+                            if let Some(code) = &task.scar_code {
+                                error = error.with_code(code.clone());
+                            }
                             let damage_before = self.damage_score();
                             
                             match self.lineage.record_error(error) {
                                 crate::lineage::OperationResult::Success { .. } => {
                                     // Non-fatal failure
-                                    let damage_inflicted = self.damage_score() - damage_before;
+                                    let damage_inflicted = self.damage_score().saturating_sub(damage_before);
                                     TaskResult::Failed {
                                         reason: failure_desc,
                                         energy_consumed,
@@ -290,7 +478,7 @@ impl TaskAgent {
                                 }
                                 crate::lineage::OperationResult::Dead => {
                                     // Fatal failure - agent terminated
-                                    let damage_inflicted = self.damage_score() - damage_before;
+                                    let damage_inflicted = self.damage_score().saturating_sub(damage_before);
                                     TaskResult::Failed {
                                         reason: format!("{} (FATAL - agent terminated)", failure_desc),
                                         energy_consumed,
@@ -298,8 +486,7 @@ impl TaskAgent {
                                     }
                                 }
                                 crate::lineage::OperationResult::OntologicalViolation { reason } => {
-                                    eprintln!("FATAL: Ontological violation: {}", reason);
-                                    std::process::exit(1);
+                                    self.quarantine(reason)
                                 }
                                 _ => unreachable!("record_error cannot return InsufficientEnergy"),
                             }
@@ -314,8 +501,7 @@ impl TaskAgent {
             }
             crate::lineage::OperationResult::Dead => TaskResult::AgentTerminated,
             crate::lineage::OperationResult::OntologicalViolation { reason } => {
-                eprintln!("FATAL: Ontological violation: {}", reason);
-                std::process::exit(1);
+                self.quarantine(reason)
             }
         }
     }
@@ -329,7 +515,15 @@ impl TaskAgent {
     /// 
     /// **CONSEQUENCE**: Once buried, this agent's identity cannot be reused.
     /// **CONSEQUENCE**: Tombstone is immutable after creation.
+    /// 
+    /// Buries into the default graveyard; see `bury_in` for a specific one.
     pub fn bury(&self) -> Result<(), crate::graveyard::GraveyardError> {
+        let graveyard = Graveyard::default_instance()?;
+        self.bury_in(&graveyard)
+    }
+
+    /// Bury this agent in the given Graveyard (requires agent to be dead).
+    pub fn bury_in(&self, graveyard: &Graveyard) -> Result<(), crate::graveyard::GraveyardError> {
         if self.is_alive() {
             return Err(crate::graveyard::GraveyardError::DirectoryError(
                 "Cannot bury a living agent".to_string(),
@@ -347,6 +541,9 @@ impl TaskAgent {
                 severity: format!("{:?}", scar.severity()),
                 description: scar.description().to_string(),
                 context: scar.context().map(|s| s.to_string()),
+                category: scar.category(),
+                code: scar.code().map(|s| s.to_string()),
+                details: scar.details().clone(),
             })
             .collect();
 
@@ -363,20 +560,34 @@ impl TaskAgent {
         let final_energy = self.energy();
         let _energy_consumed = initial_energy.saturating_sub(final_energy);
         
-        let tombstone = crate::graveyard::Tombstone::create(
+        // True birth time (identity birth_time is nanoseconds since epoch)
+        let creation_time = chrono::DateTime::from_timestamp_nanos(
+            i64::try_from(self.identity().birth_time()).unwrap_or(i64::MAX),
+        );
+
+        // Seal parentage and the full memory history, signed with this graveyard's key
+        let keypair = graveyard.keypair()?;
+        let tombstone = crate::graveyard::Tombstone::create_with_clock(
             self.identity().id().to_string(),
             format!("{:?}", self.identity()),
-            chrono::Utc::now() - chrono::Duration::seconds(1), // Approximate creation time
+            creation_time,
             final_energy,
             initial_energy,  // Peak energy = initial energy
             initial_energy,
             self.tasks_completed as u32,
             scar_records,
             cause_of_death,
+            self.parent_id.clone(),
+            self.inherited_energy,
+            self.inherited_knowledge.clone(),
+            self.generation,
+            self.lineage.memory().history(),
+            self.lineage.clock().as_ref(),
+            &keypair,
         );
 
         // Bury in the eternal archive
-        crate::graveyard::Graveyard::bury(&tombstone)
+        graveyard.bury(&tombstone)
     }
 
     /// Spawn a descendant agent from this "healthy" parent agent.
@@ -447,8 +658,18 @@ impl TaskAgent {
         match self.execute_task(transfer_task, TaskOutcome::Success) {
             TaskResult::Completed { .. } => {
                 // Energy successfully transferred
-                // Create child agent
-                let mut child = TaskAgent::create(initial_energy_for_child);
+                // Create child agent, living on its parent's clock
+                let mut child = TaskAgent::create_with_clock(initial_energy_for_child, self.lineage.clock().clone());
+                child
+                    .lineage
+                    .set_damage_model(self.lineage.scars().damage_model().clone())
+                    .expect("a newborn child has no scars to underprice");
+
+                // Record structured parentage
+                child.parent_id = Some(self.identity().id().to_string());
+                child.generation = self.generation + 1;
+                child.inherited_energy = Some(initial_energy_for_child);
+                child.inherited_knowledge = Some(format!("Inherited efficiency: {:.2}", efficiency));
 
                 // Record parentage in child's memory
                 child.lineage.memory_mut().append(format!(
@@ -474,6 +695,9 @@ impl TaskAgent {
             TaskResult::AgentTerminated => {
                 Err("Parent agent terminated during spawn".to_string())
             }
+            TaskResult::Terminated { cause } => {
+                Err(format!("Parent agent quarantined during spawn: {}", cause))
+            }
         }
     }
     
@@ -482,7 +706,7 @@ impl TaskAgent {
         format!(
             "Agent {} | Status: {} | Energy: {} | Capacity: {}/100 | Tasks: {} completed, {} failed",
             self.identity().id().chars().take(8).collect::<String>(),
-            if self.is_alive() { "ALIVE" } else { "DEAD" },
+            if self.is_quarantined() { "QUARANTINED" } else if self.is_alive() { "ALIVE" } else { "DEAD" },
             self.energy(),
             self.current_capacity(),
             self.tasks_completed,
@@ -904,4 +1128,184 @@ mod tests {
         // Damage unchanged by success
         assert_eq!(agent.damage_score(), damage);
     }
+
+    #[test]
+    fn spawned_child_carries_structured_parentage() {
+        let mut parent = TaskAgent::create(1000);
+        parent.execute_task(Task::new("Warm up".to_string(), 10), TaskOutcome::Success);
+        assert_eq!(parent.generation(), 0);
+        assert_eq!(parent.parent_id(), None);
+
+        let mut child = parent.spawn(300).unwrap();
+        assert_eq!(child.parent_id(), Some(parent.identity().id()));
+        assert_eq!(child.generation(), 1);
+        assert_eq!(child.inherited_energy(), Some(300));
+
+        child.execute_task(Task::new("Warm up".to_string(), 10), TaskOutcome::Success);
+        let grandchild = child.spawn(100).unwrap();
+        assert_eq!(grandchild.parent_id(), Some(child.identity().id()));
+        assert_eq!(grandchild.generation(), 2);
+    }
+
+    #[test]
+    fn buried_child_tombstone_records_parentage_and_birth() {
+        use crate::clock::ManualClock;
+        use chrono::{Duration, TimeZone};
+
+        let born = chrono::Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let clock = Arc::new(ManualClock::new(born));
+        let mut parent = TaskAgent::create_with_clock(1000, clock.clone());
+        parent.execute_task(Task::new("Warm up".to_string(), 10), TaskOutcome::Success);
+        let mut child = parent.spawn(300).unwrap();
+
+        clock.advance(Duration::hours(1));
+        child.execute_task(
+            Task::new("Fatal".to_string(), 10),
+            TaskOutcome::CatastrophicFailure { reason: "Collapse".to_string() },
+        );
+        assert!(!child.is_alive());
+
+        let root = std::env::temp_dir().join(format!("lineage-agent-{}", uuid::Uuid::new_v4()));
+        let graveyard = Graveyard::open(&root).unwrap();
+        child.bury_in(&graveyard).unwrap();
+
+        let tombstone = graveyard.load(child.identity().id()).unwrap();
+        assert_eq!(tombstone.parentage.parent_id.as_deref(), Some(parent.identity().id()));
+        assert_eq!(tombstone.parentage.generation, 1);
+        assert_eq!(tombstone.parentage.inherited_capacity, Some(300));
+        assert_eq!(
+            tombstone.parentage.inherited_knowledge.as_deref(),
+            Some("Inherited efficiency: 1.00")
+        );
+        assert_eq!(tombstone.identity.creation_time, born);
+        assert_eq!(tombstone.pathology.death_timestamp, born + Duration::hours(1));
+        assert_eq!(tombstone.signer_key_id, graveyard.keypair().unwrap().key_id());
+
+        std::fs::remove_dir_all(graveyard.keys_dir()).ok();
+        std::fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn buried_tombstone_keeps_scar_classification() {
+        let mut agent = TaskAgent::create(1000);
+        agent.lineage.record_error(
+            OperationError::new(ScarSeverity::Minor, "Slow peer".to_string())
+                .with_category(ScarCategory::Network)
+                .with_code("NET_TIMEOUT")
+                .with_detail("peer", "node-7"),
+        );
+        agent.execute_task(
+            Task::new("Fatal".to_string(), 10),
+            TaskOutcome::CatastrophicFailure { reason: "Collapse".to_string() },
+        );
+
+        let root = std::env::temp_dir().join(format!("lineage-agent-{}", uuid::Uuid::new_v4()));
+        let graveyard = Graveyard::open(&root).unwrap();
+        agent.bury_in(&graveyard).unwrap();
+
+        let tombstone = graveyard.load(agent.identity().id()).unwrap();
+        let scar = &tombstone.pathology.scars[0];
+        assert_eq!(scar.category, ScarCategory::Network);
+        assert_eq!(scar.code.as_deref(), Some("NET_TIMEOUT"));
+        assert_eq!(scar.details.get("peer").map(String::as_str), Some("node-7"));
+        assert_eq!(tombstone.pathology.scars[1].category, ScarCategory::General);
+
+        std::fs::remove_dir_all(graveyard.keys_dir()).ok();
+        std::fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn failed_task_scar_carries_the_task_classification() {
+        let mut agent = TaskAgent::create(1000);
+        let task = Task::new("Dissent penalty".to_string(), 0)
+            .with_scar_classification(ScarCategory::Governance, "GOV_DISSENT");
+        agent.execute_task(task, TaskOutcome::RecoverableFailure { reason: "Dissent".to_string() });
+        agent.execute_task(
+            Task::new("Plain".to_string(), 0),
+            TaskOutcome::RecoverableFailure { reason: "Plain".to_string() },
+        );
+
+        let scars = agent.lineage.scars().all_scars();
+        assert_eq!(scars[0].category(), ScarCategory::Governance);
+        assert_eq!(scars[0].code(), Some("GOV_DISSENT"));
+        assert_eq!(scars[1].category(), ScarCategory::General);
+        assert_eq!(scars[1].code(), None);
+    }
+
+    #[test]
+    fn damage_model_drives_cost_and_capacity() {
+        use crate::scar::Compounding;
+
+        let mut agent = TaskAgent::create(1000);
+        agent.set_damage_model(Arc::new(Compounding::new(0.5))).unwrap();
+        for _ in 0..4 {
+            agent.execute_task(Task::new("Routine".to_string(), 10), TaskOutcome::Success);
+        }
+        for _ in 0..2 {
+            agent.execute_task(
+                Task::new("Flaky".to_string(), 10),
+                TaskOutcome::SignificantFailure { reason: "Timeout".to_string() },
+            );
+        }
+
+        // 5 + 5 * 1.5 instead of the default 10
+        assert_eq!(agent.damage_score(), 13);
+        assert_eq!(agent.current_capacity(), 87);
+        assert_eq!(Task::new("Heavy".to_string(), 100).actual_cost(agent.damage_score()), 113);
+
+        let child = agent.spawn(100).unwrap();
+        assert!(format!("{:?}", child.lineage.scars().damage_model()).starts_with("Compounding"));
+    }
+
+    #[test]
+    fn ontological_violation_quarantines_instead_of_exiting() {
+        let _policy = crate::violation::scoped_policy(crate::violation::ViolationPolicy::Report);
+
+        let mut agent = TaskAgent::create(1000);
+        agent.lineage.corrupt_for_test();
+
+        let cause = match agent.execute_task(Task::new("Work".to_string(), 10), TaskOutcome::Success) {
+            TaskResult::Terminated { cause } => cause,
+            other => panic!("expected quarantine, got {:?}", other),
+        };
+        assert_eq!(cause.lineage_id, agent.identity().id());
+        assert!(cause.reason.starts_with("Scar corruption"));
+        assert!(agent.is_quarantined());
+
+        // Never operates again, by any path
+        assert_eq!(
+            agent.execute_task(Task::new("Retry".to_string(), 10), TaskOutcome::Success),
+            TaskResult::Terminated { cause: cause.clone() }
+        );
+        assert_eq!(agent.reserve_task(&Task::new("Reserve".to_string(), 10)).unwrap_err(), TaskResult::Terminated { cause });
+        assert!(agent.spawn(100).is_err());
+        assert_eq!(agent.energy(), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "ONTOLOGICAL VIOLATION")]
+    fn ontological_violation_panics_under_panic_policy() {
+        let _policy = crate::violation::scoped_policy(crate::violation::ViolationPolicy::Panic);
+
+        let mut agent = TaskAgent::create(1000);
+        agent.lineage.corrupt_for_test();
+        agent.execute_task(Task::new("Work".to_string(), 10), TaskOutcome::Success);
+    }
+
+    #[test]
+    fn reserved_task_pays_from_its_reservation() {
+        let mut agent = TaskAgent::create(100);
+        let task = Task::new("Multi-step settlement".to_string(), 40);
+
+        let reservation = agent.reserve_task(&task).unwrap();
+        assert!(matches!(
+            agent.execute_task(Task::new("Competing".to_string(), 70), TaskOutcome::Success),
+            TaskResult::InsufficientEnergy { available: 60, .. }
+        ));
+
+        let result = agent.execute_reserved_task(task, reservation, TaskOutcome::Success);
+        assert_eq!(result, TaskResult::Completed { energy_consumed: 40 });
+        assert_eq!(agent.energy(), 60);
+        assert_eq!(agent.tasks_completed(), 1);
+    }
 }
@@ -0,0 +1,370 @@
+//! # Anomaly Detection
+//!
+//! ## What This Enforces
+//! - An actor's operation stream is watched over a sliding time window
+//! - Crossing a threshold raises a trust violation automatically, with no
+//!   human in the loop
+//! - The evidence behind every raised violation is written to the actor's
+//!   causal memory before the violation is recorded
+//!
+//! ## Signals
+//! - [`AnomalySignal::DeniedActions`] - attempts denied for lack of
+//!   authorization (energy shortfalls do not count)
+//! - [`AnomalySignal::RevokedCapabilityAttempts`] - attempts to use a
+//!   capability the actor had already lost to a revocation
+//! - [`AnomalySignal::EnergyBurn`] - energy spent by permitted actions
+//!
+//! ## What This Forbids
+//! - Raising the same burst twice: the events behind a report are consumed
+//! - Zero limits or an empty window, which would fire on every attempt or
+//!   never at all
+//!
+//! Only the latest [`AnomalyDetector::MAX_REPORTS`] reports are kept in the
+//! detector; every report is also in the actor's memory.
+//!
+//! Attach a detector with [`TrustedActor::set_detector`](crate::trust::TrustedActor::set_detector).
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::trust::ViolationType;
+
+/// A limit on one signal and the violation raised when it is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threshold {
+    /// Count (or energy, for `EnergyBurn`) within the window that triggers
+    pub limit: u64,
+    /// Violation recorded against the actor
+    pub violation: ViolationType,
+}
+
+/// Which pattern in the operation stream crossed its threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalySignal {
+    /// Too many unauthorized attempts
+    DeniedActions,
+    /// Too many attempts on capabilities the actor has lost
+    RevokedCapabilityAttempts,
+    /// Too much energy spent
+    EnergyBurn,
+}
+
+/// Detector configuration; a `None` threshold disables that signal
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalyConfig {
+    /// Sliding window the thresholds apply to
+    pub window: Duration,
+    /// Denied attempts per window
+    pub denials: Option<Threshold>,
+    /// Attempts on revoked capabilities per window
+    pub revoked_attempts: Option<Threshold>,
+    /// Energy spent per window (disabled by default: it depends on workload)
+    pub energy_burn: Option<Threshold>,
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        AnomalyConfig {
+            window: Duration::minutes(1),
+            denials: Some(Threshold {
+                limit: 5,
+                violation: ViolationType::UnauthorizedAccess,
+            }),
+            revoked_attempts: Some(Threshold {
+                limit: 3,
+                violation: ViolationType::PolicyBypass,
+            }),
+            energy_burn: None,
+        }
+    }
+}
+
+impl AnomalyConfig {
+    /// Check that every enabled threshold can be crossed by real events.
+    pub fn validate(&self) -> Result<(), AnomalyConfigError> {
+        if self.window <= Duration::zero() {
+            return Err(AnomalyConfigError::EmptyWindow);
+        }
+        let thresholds = [
+            (AnomalySignal::DeniedActions, self.denials),
+            (AnomalySignal::RevokedCapabilityAttempts, self.revoked_attempts),
+            (AnomalySignal::EnergyBurn, self.energy_burn),
+        ];
+        for (signal, threshold) in thresholds {
+            if threshold.is_some_and(|threshold| threshold.limit == 0) {
+                return Err(AnomalyConfigError::ZeroLimit(signal));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors in an anomaly detector configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyConfigError {
+    /// The window is zero or negative, so no event is ever inside it
+    EmptyWindow,
+    /// A threshold of zero would fire on every observation
+    ZeroLimit(AnomalySignal),
+}
+
+impl std::fmt::Display for AnomalyConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnomalyConfigError::EmptyWindow => write!(f, "Anomaly window must be positive"),
+            AnomalyConfigError::ZeroLimit(signal) => {
+                write!(f, "Threshold for {:?} must be at least 1", signal)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnomalyConfigError {}
+
+/// One thing the actor did, as seen by the detector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Observation {
+    /// A permitted action and the energy it cost
+    Spent { description: String, energy: u64 },
+    /// An attempt denied for lack of authorization
+    Denied { description: String, reason: String },
+    /// A denied attempt on a capability revoked before the attempt
+    RevokedCapability { description: String, reason: String },
+}
+
+impl Observation {
+    fn evidence(&self) -> String {
+        match self {
+            Observation::Spent { description, energy } => format!("{} ({} energy)", description, energy),
+            Observation::Denied { description, reason }
+            | Observation::RevokedCapability { description, reason } => {
+                format!("{} ({})", description, reason)
+            }
+        }
+    }
+}
+
+/// A threshold crossing and the violation it raised
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnomalyReport {
+    /// Which signal crossed its threshold
+    pub signal: AnomalySignal,
+    /// Observed count (or energy) within the window
+    pub observed: u64,
+    /// The threshold that was reached
+    pub threshold: Threshold,
+    /// When the crossing was detected
+    pub detected_at: DateTime<Utc>,
+    /// The events behind the report, oldest first
+    pub evidence: Vec<String>,
+}
+
+impl AnomalyReport {
+    /// One-line summary recorded in the actor's memory
+    pub fn summary(&self) -> String {
+        format!(
+            "Anomaly detected: {:?} reached {} (threshold {}) -> {:?}. Evidence: [{}]",
+            self.signal,
+            self.observed,
+            self.threshold.limit,
+            self.threshold.violation,
+            self.evidence.join("; ")
+        )
+    }
+}
+
+/// Watches an actor's operation stream and reports threshold crossings
+#[derive(Debug, Clone)]
+pub struct AnomalyDetector {
+    config: AnomalyConfig,
+    /// Observations still inside the window
+    recent: VecDeque<(DateTime<Utc>, Observation)>,
+    /// The latest reports raised, at most `MAX_REPORTS`
+    reports: Vec<AnomalyReport>,
+}
+
+impl AnomalyDetector {
+    /// Reports kept by a detector; older ones survive only in memory.
+    pub const MAX_REPORTS: usize = 64;
+
+    /// Create a detector with `config`, refusing limits that can never work.
+    pub fn new(config: AnomalyConfig) -> Result<Self, AnomalyConfigError> {
+        config.validate()?;
+        Ok(AnomalyDetector {
+            config,
+            recent: VecDeque::new(),
+            reports: Vec::new(),
+        })
+    }
+
+    /// The detector's configuration.
+    pub fn config(&self) -> &AnomalyConfig {
+        &self.config
+    }
+
+    /// The latest reports raised (up to `MAX_REPORTS`), oldest first.
+    pub fn reports(&self) -> &[AnomalyReport] {
+        &self.reports
+    }
+
+    /// Record an observation; returns a report if a threshold was reached.
+    ///
+    /// At most one report is raised per observation, checked in order:
+    /// revoked-capability attempts, denials, energy burn.
+    pub(crate) fn observe(&mut self, now: DateTime<Utc>, observation: Observation) -> Option<AnomalyReport> {
+        self.recent.push_back((now, observation));
+        let cutoff = now - self.config.window;
+        while self.recent.front().is_some_and(|(at, _)| *at <= cutoff) {
+            self.recent.pop_front();
+        }
+
+        let checks = [
+            (AnomalySignal::RevokedCapabilityAttempts, self.config.revoked_attempts),
+            (AnomalySignal::DeniedActions, self.config.denials),
+            (AnomalySignal::EnergyBurn, self.config.energy_burn),
+        ];
+
+        for (signal, threshold) in checks {
+            let Some(threshold) = threshold else { continue };
+            let observed: u64 = self.recent.iter().map(|(_, obs)| weight(signal, obs)).sum();
+            if observed < threshold.limit {
+                continue;
+            }
+
+            // Consume the events behind this report so one burst raises once
+            let mut evidence = Vec::new();
+            self.recent.retain(|(_, obs)| {
+                if weight(signal, obs) > 0 {
+                    evidence.push(obs.evidence());
+                    false
+                } else {
+                    true
+                }
+            });
+
+            let report = AnomalyReport {
+                signal,
+                observed,
+                threshold,
+                detected_at: now,
+                evidence,
+            };
+            if self.reports.len() == Self::MAX_REPORTS {
+                self.reports.remove(0);
+            }
+            self.reports.push(report.clone());
+            return Some(report);
+        }
+
+        None
+    }
+}
+
+impl Default for AnomalyDetector {
+    fn default() -> Self {
+        Self::new(AnomalyConfig::default()).expect("the default configuration is valid")
+    }
+}
+
+/// How much an observation counts towards a signal
+fn weight(signal: AnomalySignal, observation: &Observation) -> u64 {
+    match (signal, observation) {
+        (AnomalySignal::DeniedActions, Observation::Denied { .. })
+        | (AnomalySignal::DeniedActions, Observation::RevokedCapability { .. })
+        | (AnomalySignal::RevokedCapabilityAttempts, Observation::RevokedCapability { .. }) => 1,
+        (AnomalySignal::EnergyBurn, Observation::Spent { energy, .. }) => *energy,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn denied(n: u32) -> Observation {
+        Observation::Denied {
+            description: format!("Attempt {}", n),
+            reason: "No policy for action deploy".to_string(),
+        }
+    }
+
+    #[test]
+    fn only_events_inside_the_window_count() {
+        let mut detector = AnomalyDetector::default();
+        let start = DateTime::UNIX_EPOCH;
+
+        for (n, secs) in [0, 30, 45, 50].into_iter().enumerate() {
+            assert_eq!(detector.observe(start + Duration::seconds(secs), denied(n as u32)), None);
+        }
+
+        // The first attempt has left the window: still only four
+        assert_eq!(detector.observe(start + Duration::seconds(70), denied(4)), None);
+        let report = detector.observe(start + Duration::seconds(75), denied(5)).unwrap();
+        assert_eq!(report.signal, AnomalySignal::DeniedActions);
+        assert_eq!(report.threshold.violation, ViolationType::UnauthorizedAccess);
+        assert_eq!(report.observed, 5);
+
+        // The burst was consumed; the next denial starts over
+        assert_eq!(detector.observe(start + Duration::seconds(116), denied(7)), None);
+        assert_eq!(detector.reports().len(), 1);
+    }
+
+    #[test]
+    fn energy_burn_is_summed_when_enabled() {
+        let mut detector = AnomalyDetector::new(AnomalyConfig {
+            energy_burn: Some(Threshold {
+                limit: 100,
+                violation: ViolationType::PolicyBypass,
+            }),
+            ..AnomalyConfig::default()
+        })
+        .unwrap();
+        let spent = |energy| Observation::Spent {
+            description: "Bulk export".to_string(),
+            energy,
+        };
+
+        assert_eq!(detector.observe(DateTime::UNIX_EPOCH, spent(60)), None);
+        let report = detector.observe(DateTime::UNIX_EPOCH, spent(40)).unwrap();
+        assert_eq!(report.signal, AnomalySignal::EnergyBurn);
+        assert_eq!(report.observed, 100);
+        assert_eq!(report.evidence, vec!["Bulk export (60 energy)", "Bulk export (40 energy)"]);
+    }
+
+    #[test]
+    fn unusable_limits_are_rejected() {
+        let zero = Some(Threshold {
+            limit: 0,
+            violation: ViolationType::PolicyBypass,
+        });
+
+        assert_eq!(
+            AnomalyDetector::new(AnomalyConfig { denials: zero, ..AnomalyConfig::default() }).unwrap_err(),
+            AnomalyConfigError::ZeroLimit(AnomalySignal::DeniedActions)
+        );
+        assert_eq!(
+            AnomalyDetector::new(AnomalyConfig { energy_burn: zero, ..AnomalyConfig::default() }).unwrap_err(),
+            AnomalyConfigError::ZeroLimit(AnomalySignal::EnergyBurn)
+        );
+        assert_eq!(
+            AnomalyDetector::new(AnomalyConfig { window: Duration::zero(), ..AnomalyConfig::default() }).unwrap_err(),
+            AnomalyConfigError::EmptyWindow
+        );
+    }
+
+    #[test]
+    fn only_the_latest_reports_are_kept() {
+        let mut detector = AnomalyDetector::default();
+        let burst = 5;
+        let total = AnomalyDetector::MAX_REPORTS + 3;
+
+        for n in 0..total * burst {
+            detector.observe(DateTime::UNIX_EPOCH, denied(n as u32));
+        }
+
+        let reports = detector.reports();
+        assert_eq!(reports.len(), AnomalyDetector::MAX_REPORTS);
+        assert_eq!(reports[0].evidence[0], denied((3 * burst) as u32).evidence());
+    }
+}
@@ -0,0 +1,402 @@
+//! # Archive System
+//!
+//! Stable, versioned serialized form of a whole lineage.
+//!
+//! ## What This Enforces
+//! - Export captures identity, full event history, metabolism (with its
+//!   aging) and scars
+//! - Import charges the aging due for the time spent archived
+//! - Every archive carries a schema version
+//! - Import re-verifies every invariant before a `Lineage` exists
+//!
+//! ## What This Forbids
+//! - Raw `Deserialize` for `Identity`, `Memory`, `Metabolism`, `ScarTissue` or `Lineage`
+//! - Importing archives whose identity hash, digest chain or metabolism disagree
+//! - Importing archives whose energy, scars, seal or death disagree with the
+//!   history they carry (a rolled-back death is refused)
+//! - Importing archives of an unknown schema version
+//! - Importing archives of buried identities (Lazarus check)
+//! - Importing an archive while its identity is live in this process
+//!   (the exported original, or an earlier import of the same archive)
+//!
+//! ## Format
+//! `Lineage` serializes to the same shape that `LineageArchive` deserializes:
+//! `{ schema_version, identity, memory, metabolism, scars }`.
+
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::identity::{Identity, RestoreError};
+use crate::lineage::Lineage;
+use crate::memory::{Event, Memory};
+use crate::metabolism::{AgingState, Metabolism};
+use crate::scar::{Scar, ScarTissue};
+
+/// Current schema version of the lineage archive format
+pub const ARCHIVE_SCHEMA_VERSION: u32 = 2;
+
+/// Errors that can occur while exporting or importing a lineage
+#[derive(Debug, Clone)]
+pub enum ArchiveError {
+    /// Serialization/deserialization failed
+    SerializationError(String),
+    /// Archive was written by an unknown schema version
+    UnsupportedVersion { found: u32, expected: u32 },
+    /// Archive describes a lineage that violates its invariants
+    InvariantViolation(String),
+    /// The archived identity is buried (Lazarus check)
+    IdentityDead { id: String },
+}
+
+impl std::fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArchiveError::SerializationError(e) => write!(f, "Serialization error: {}", e),
+            ArchiveError::UnsupportedVersion { found, expected } => write!(
+                f,
+                "Unsupported archive schema version {} (expected {})",
+                found, expected
+            ),
+            ArchiveError::InvariantViolation(reason) => {
+                write!(f, "Archive violates lineage invariants: {}", reason)
+            }
+            ArchiveError::IdentityDead { id } => {
+                write!(f, "Identity is buried in the graveyard: {}", id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+/// Serialized creation context of an identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityArchive {
+    pub id: String,
+    /// Nanoseconds since epoch, as a decimal string (JSON has no u128)
+    pub birth_time: String,
+    /// Hex-encoded creation entropy
+    pub entropy: String,
+}
+
+impl IdentityArchive {
+    /// Captures an identity's creation context
+    pub fn from_identity(identity: &Identity) -> Self {
+        IdentityArchive {
+            id: identity.id().to_string(),
+            birth_time: identity.birth_time().to_string(),
+            entropy: hex::encode(identity.entropy()),
+        }
+    }
+
+    /// Rebuilds the identity, proving the id against its creation context
+    pub(crate) fn into_identity(self) -> Result<Identity, RestoreError> {
+        let birth_time: u128 = self.birth_time.parse().map_err(|_| {
+            RestoreError::Invalid("Identity corruption: invalid birth time".to_string())
+        })?;
+
+        let entropy: [u8; 32] = hex::decode(&self.entropy)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| RestoreError::Invalid("Identity corruption: invalid entropy".to_string()))?;
+
+        Identity::restore(&self.id, birth_time, entropy)
+    }
+}
+
+/// Serialized event history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryArchive {
+    pub events: Vec<Event>,
+    pub is_terminated: bool,
+}
+
+/// Serialized metabolic state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetabolismArchive {
+    pub energy: u64,
+    pub initial_energy: u64,
+    pub is_dead: bool,
+    pub aging: Option<AgingState>,
+}
+
+/// Versioned serialized form of a whole lineage.
+///
+/// This is plain data. The only way to turn it back into a `Lineage`
+/// is `into_lineage`, which re-verifies every invariant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineageArchive {
+    pub schema_version: u32,
+    pub identity: IdentityArchive,
+    pub memory: MemoryArchive,
+    pub metabolism: MetabolismArchive,
+    pub scars: Vec<Scar>,
+}
+
+impl LineageArchive {
+    /// Parses an archive from JSON without verifying it
+    pub fn from_json(json: &str) -> Result<Self, ArchiveError> {
+        serde_json::from_str(json).map_err(|e| ArchiveError::SerializationError(e.to_string()))
+    }
+
+    /// Returns the identity this archive describes
+    pub fn identity_id(&self) -> &str {
+        &self.identity.id
+    }
+
+    /// Rebuilds the lineage, re-verifying every invariant.
+    pub fn into_lineage(self) -> Result<Lineage, ArchiveError> {
+        if self.schema_version != ARCHIVE_SCHEMA_VERSION {
+            return Err(ArchiveError::UnsupportedVersion {
+                found: self.schema_version,
+                expected: ARCHIVE_SCHEMA_VERSION,
+            });
+        }
+
+        crate::lazarus::check(&self.identity.id)
+            .map_err(|violation| ArchiveError::IdentityDead { id: violation.id })?;
+
+        let identity = self.identity.into_identity().map_err(|error| match error {
+            RestoreError::Buried(violation) => ArchiveError::IdentityDead { id: violation.id },
+            RestoreError::Invalid(reason) => ArchiveError::InvariantViolation(reason),
+        })?;
+        let memory = Memory::restore(self.memory.events, self.memory.is_terminated)
+            .map_err(ArchiveError::InvariantViolation)?;
+        let mut metabolism = Metabolism::restore(
+            self.metabolism.initial_energy,
+            self.metabolism.energy,
+            self.metabolism.is_dead,
+        )
+        .map_err(ArchiveError::InvariantViolation)?;
+        if let Some(aging) = self.metabolism.aging {
+            metabolism.restore_aging(aging).map_err(ArchiveError::InvariantViolation)?;
+        }
+
+        let mut scars = ScarTissue::new();
+        for scar in self.scars {
+            scars.restore(scar).map_err(ArchiveError::InvariantViolation)?;
+        }
+
+        let mut lineage =
+            Lineage::restore(identity, memory, metabolism, scars).map_err(ArchiveError::InvariantViolation)?;
+
+        // Time kept passing while archived
+        let now = lineage.clock().now();
+        lineage.tick(now);
+        Ok(lineage)
+    }
+}
+
+impl Lineage {
+    /// Exports this lineage as a versioned JSON archive.
+    pub fn export(&self) -> Result<String, ArchiveError> {
+        serde_json::to_string(self).map_err(|e| ArchiveError::SerializationError(e.to_string()))
+    }
+
+    /// Imports a lineage from a JSON archive.
+    /// 
+    /// **INVARIANT**: The identity hash, event digest chain, metabolism and
+    /// scars are all re-verified. Nothing is repaired.
+    pub fn import(json: &str) -> Result<Self, ArchiveError> {
+        LineageArchive::from_json(json)?.into_lineage()
+    }
+}
+
+impl Serialize for Identity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        IdentityArchive::from_identity(self).serialize(serializer)
+    }
+}
+
+impl Serialize for Lineage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Lineage", 5)?;
+        state.serialize_field("schema_version", &ARCHIVE_SCHEMA_VERSION)?;
+        state.serialize_field("identity", self.identity())?;
+        state.serialize_field("memory", self.memory())?;
+        state.serialize_field("metabolism", self.metabolism())?;
+        state.serialize_field("scars", self.scars())?;
+        state.end()
+    }
+}
+
+// EXPLICIT PREVENTION: No Deserialize for Identity (would mint identities)
+// EXPLICIT PREVENTION: No Deserialize for Lineage (must go through into_lineage)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lineage::OperationError;
+    use crate::scar::ScarSeverity;
+
+    fn scarred_lineage() -> Lineage {
+        let mut lineage = Lineage::create(1000);
+        lineage.perform_operation("Op 1".to_string(), 250);
+        lineage.record_error(OperationError::new(ScarSeverity::Minor, "Glitch".to_string()));
+        lineage
+    }
+
+    #[test]
+    fn export_import_roundtrip() {
+        let lineage = scarred_lineage();
+        let json = lineage.export().unwrap();
+        let (id, birth_time, event_count) = (
+            lineage.identity().id().to_string(),
+            lineage.identity().birth_time(),
+            lineage.memory().event_count(),
+        );
+        drop(lineage);
+
+        let imported = Lineage::import(&json).unwrap();
+
+        assert_eq!(imported.identity().id(), id);
+        assert_eq!(imported.identity().birth_time(), birth_time);
+        assert_eq!(imported.metabolism().energy(), 750);
+        assert_eq!(imported.memory().event_count(), event_count);
+        assert_eq!(imported.scars().scar_count(), 1);
+        assert_eq!(imported.export().unwrap(), json);
+    }
+
+    #[test]
+    fn export_charges_pending_and_open_forfeits() {
+        let mut lineage = Lineage::create(1000);
+        let abandoned = lineage.reserve(100).unwrap();
+        let open = lineage.reserve(50).unwrap();
+        drop(abandoned);
+
+        // Neither forfeit has been burned yet, but both travel with the export
+        assert_eq!(lineage.metabolism().energy(), 1000);
+        let json = lineage.export().unwrap();
+        drop(open);
+        drop(lineage);
+
+        let imported = Lineage::import(&json).unwrap();
+        assert_eq!(imported.metabolism().energy(), 985);
+        assert_eq!(imported.metabolism().available(), 985);
+    }
+
+    #[test]
+    fn import_charges_aging_for_time_spent_archived() {
+        use crate::metabolism::AgingModel;
+
+        let mut lineage = Lineage::create(1000);
+        let model = AgingModel::WallClock { burn: 10, period: chrono::Duration::hours(1) };
+        lineage.set_aging(model).unwrap();
+
+        // Archived three hours ago, as far as the aging state is concerned
+        let mut archive = LineageArchive::from_json(&lineage.export().unwrap()).unwrap();
+        drop(lineage);
+        let aging = archive.metabolism.aging.as_mut().unwrap();
+        aging.aged_until -= chrono::Duration::hours(3);
+
+        let imported = archive.into_lineage().unwrap();
+        assert_eq!(imported.metabolism().aging(), Some(model));
+        assert_eq!(imported.metabolism().energy(), 970);
+        assert!(imported.memory().latest().description().starts_with("Aging: 30"));
+    }
+
+    #[test]
+    fn live_identity_cannot_be_imported_twice() {
+        let original = scarred_lineage();
+        let json = original.export().unwrap();
+
+        // The original is still live: importing would fork it
+        assert!(matches!(Lineage::import(&json), Err(ArchiveError::InvariantViolation(_))));
+
+        drop(original);
+        let imported = Lineage::import(&json).unwrap();
+        assert!(matches!(Lineage::import(&json), Err(ArchiveError::InvariantViolation(_))));
+
+        drop(imported);
+        assert!(Lineage::import(&json).is_ok());
+    }
+
+    #[test]
+    fn archive_is_versioned() {
+        let json = Lineage::create(10).export().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["schema_version"], ARCHIVE_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let mut archive = LineageArchive::from_json(&Lineage::create(10).export().unwrap()).unwrap();
+        archive.schema_version = ARCHIVE_SCHEMA_VERSION + 1;
+
+        assert!(matches!(
+            archive.into_lineage(),
+            Err(ArchiveError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn minted_energy_is_rejected() {
+        let mut archive = LineageArchive::from_json(&scarred_lineage().export().unwrap()).unwrap();
+        archive.metabolism.energy = archive.metabolism.initial_energy + 1;
+
+        assert!(archive.into_lineage().is_err());
+    }
+
+    #[test]
+    fn forged_identity_is_rejected() {
+        let mut archive = LineageArchive::from_json(&Lineage::create(10).export().unwrap()).unwrap();
+        archive.identity.id = "0".repeat(64);
+
+        assert!(archive.into_lineage().is_err());
+    }
+
+    #[test]
+    fn rewritten_history_is_rejected() {
+        let json = scarred_lineage().export().unwrap();
+        let tampered = json.replace("Op 1", "Op 9");
+
+        assert!(matches!(
+            Lineage::import(&tampered),
+            Err(ArchiveError::InvariantViolation(_))
+        ));
+    }
+
+    #[test]
+    fn rolled_back_death_is_rejected() {
+        let mut lineage = Lineage::create(1000);
+        lineage.perform_operation("Spend".to_string(), 600);
+        lineage.record_error(OperationError::new(ScarSeverity::Fatal, "Crash".to_string()));
+        let json = lineage.export().unwrap();
+        drop(lineage);
+
+        // Cut the scar and termination off, revive, refill and heal
+        let mut archive = LineageArchive::from_json(&json).unwrap();
+        archive.memory.events.truncate(archive.memory.events.len() - 2);
+        archive.memory.is_terminated = false;
+        archive.metabolism.is_dead = false;
+        archive.metabolism.energy = archive.metabolism.initial_energy;
+        archive.scars.clear();
+
+        assert!(matches!(
+            archive.into_lineage(),
+            Err(ArchiveError::InvariantViolation(_))
+        ));
+
+        let imported = Lineage::import(&json).unwrap();
+        assert!(!imported.is_alive());
+        assert_eq!(imported.scars().scar_count(), 1);
+    }
+
+    #[test]
+    fn state_disagreeing_with_history_is_rejected() {
+        let json = scarred_lineage().export().unwrap();
+
+        let mut refilled = LineageArchive::from_json(&json).unwrap();
+        refilled.metabolism.energy = refilled.metabolism.initial_energy;
+        assert!(refilled.into_lineage().is_err());
+
+        let mut healed = LineageArchive::from_json(&json).unwrap();
+        healed.scars.clear();
+        assert!(healed.into_lineage().is_err());
+
+        let mut sealed = LineageArchive::from_json(&json).unwrap();
+        sealed.memory.is_terminated = true;
+        assert!(sealed.into_lineage().is_err());
+    }
+}
@@ -1,10 +1,10 @@
-//! # Minimal Behavior Loop
+//! # Behavior Loop
 //!
 //! ## What This Enforces
-//! - One behavior (pulse)
-//! - One contract (minimum energy threshold)
-//! - One injury path (weak pulse strain)
-//! - One scar consequence (increased future cost)
+//! - Every behavior declares a contract (a precondition on lineage state)
+//! - Every behavior prices itself from the scars it has already caused
+//! - Every behavior declares the scar it inflicts when its contract is violated
+//! - `Lineage::perform_behavior` runs any behavior under the same rules
 //!
 //! ## What This Demonstrates
 //! - Behaviors consume energy
@@ -12,10 +12,59 @@
 //! - Violations cause permanent injury
 //! - Scars reduce future capability
 //!
+//! `PulseBehavior` is the reference behavior: one contract (minimum energy
+//! threshold), one injury path (weak pulse strain), one scar consequence
+//! (increased future cost).
+//!
 //! This is not useful. This is consequential.
 
 use crate::lineage::{Lineage, OperationError, OperationResult};
-use crate::scar::ScarSeverity;
+use crate::scar::{ScarSeverity, ScarTissue};
+
+/// A behavior a lineage can perform.
+///
+/// The lineage checks the contract *before* running, pays `cost`, and if
+/// the contract did not hold, inflicts `violation` once the behavior has run.
+///
+/// **CONSEQUENCE**: A violation is never corrected, only scarred.
+/// **INVARIANT**: `cost` must never decrease as scars accumulate.
+pub trait Behavior: Send + Sync {
+    /// Name of the behavior, recorded in memory on every run.
+    fn name(&self) -> &str;
+
+    /// The contract: whether the behavior may run healthily on this lineage.
+    fn precondition(&self, lineage: &Lineage) -> bool;
+
+    /// Energy cost given the scars accumulated so far.
+    fn cost(&self, scars: &ScarTissue) -> u64;
+
+    /// The scar inflicted when the contract is violated.
+    ///
+    /// Built from the lineage state observed before the run.
+    fn violation(&self, lineage: &Lineage) -> OperationError;
+}
+
+/// What happened when a lineage performed a behavior.
+#[derive(Debug, PartialEq)]
+pub struct BehaviorOutcome {
+    /// Energy observed before the behavior ran (0 if the lineage was dead)
+    pub energy_before: u64,
+    /// Energy the behavior was priced at
+    pub cost: u64,
+    /// Result of paying for the behavior
+    pub result: OperationResult,
+    /// Whether the contract held when the behavior ran
+    pub contract_held: bool,
+    /// Whether a violation scar was inflicted
+    pub scarred: bool,
+}
+
+impl BehaviorOutcome {
+    /// Returns whether the behavior was paid for and ran.
+    pub fn completed(&self) -> bool {
+        matches!(self.result, OperationResult::Success { .. })
+    }
+}
 
 /// Output of a pulse behavior execution.
 #[derive(Debug, PartialEq)]
@@ -66,83 +115,26 @@ impl PulseBehavior {
     /// - Violation triggers injury, not correction
     /// - Scar permanently reduces capability
     pub fn execute_pulse(&self, lineage: &mut Lineage) -> PulseOutput {
-        // Check if lineage is operational
-        if !lineage.is_alive() {
-            return PulseOutput {
-                energy_at_pulse: 0,
-                is_strong: false,
-                strain_occurred: false,
-            };
-        }
-
-        // Calculate actual cost based on accumulated strain scars
-        let strain_scars = self.count_strain_scars(lineage);
-        let actual_cost = self.base_cost + (strain_scars * 5);
-
-        // Observe energy level BEFORE pulse
-        let energy_before = lineage.metabolism().energy();
-
-        // Perform the pulse operation
-        let result = lineage.perform_operation(
-            format!("Pulse (cost: {})", actual_cost),
-            actual_cost,
-        );
-
-        // Check if pulse completed
-        let pulse_completed = matches!(result, OperationResult::Success { .. });
-
-        if !pulse_completed {
-            return PulseOutput {
-                energy_at_pulse: energy_before,
-                is_strong: false,
-                strain_occurred: false,
-            };
-        }
-
-        // CONTRACT VERIFICATION: Was energy sufficient during pulse?
-        let is_strong = energy_before >= self.threshold;
-
-        // INJURY PATH: Contract violation causes strain
-        let mut strain_occurred = false;
-        if !is_strong {
-            // Weak pulse detected - this is a contract violation
-            // Trigger injury: inflict permanent strain scar
-            self.inflict_strain_injury(lineage, energy_before);
-            strain_occurred = true;
-        }
+        let outcome = lineage.perform_behavior(self);
 
         PulseOutput {
-            energy_at_pulse: energy_before,
-            is_strong,
-            strain_occurred,
+            energy_at_pulse: outcome.energy_before,
+            is_strong: outcome.completed() && outcome.contract_held,
+            strain_occurred: outcome.scarred,
         }
     }
 
-    /// INJURY PATH: Inflicts a strain scar.
-    /// 
-    /// **CONSEQUENCE**: This scar is permanent.
-    /// **CONSEQUENCE**: Future pulses cost more energy.
-    /// **CONSEQUENCE**: Eventually, pulses become impossible.
-    fn inflict_strain_injury(&self, lineage: &mut Lineage, energy_at_violation: u64) {
-        let strain_scar = OperationError::new(
-            ScarSeverity::Moderate,
-            format!("Pulse strain: insufficient energy (had {}, need {})", 
-                energy_at_violation, self.threshold),
-        ).with_context(format!(
-            "Contract violation: pulse executed below threshold. \
-             This permanently increases future pulse cost by 5 energy."
-        ));
-
-        lineage.record_error(strain_scar);
-    }
-
     /// Counts strain scars to calculate increased cost.
     /// 
     /// Each strain scar adds 5 to the pulse cost.
     /// This is the permanent capability reduction.
+    #[cfg(test)]
     fn count_strain_scars(&self, lineage: &Lineage) -> u64 {
-        lineage
-            .scars()
+        Self::strain_scars(lineage.scars())
+    }
+
+    fn strain_scars(scars: &ScarTissue) -> u64 {
+        scars
             .all_scars()
             .iter()
             .filter(|scar| scar.description().contains("Pulse strain"))
@@ -157,8 +149,7 @@ impl PulseBehavior {
     /// - 2 strain scars: cost = 20
     /// - etc.
     pub fn current_pulse_cost(&self, lineage: &Lineage) -> u64 {
-        let strain_scars = self.count_strain_scars(lineage);
-        self.base_cost + (strain_scars * 5)
+        self.cost(lineage.scars())
     }
 
     /// Returns the energy threshold for healthy pulses.
@@ -167,6 +158,38 @@ impl PulseBehavior {
     }
 }
 
+impl Behavior for PulseBehavior {
+    fn name(&self) -> &str {
+        "Pulse"
+    }
+
+    /// **CONTRACT**: Energy must be at or above the threshold.
+    fn precondition(&self, lineage: &Lineage) -> bool {
+        lineage.metabolism().energy() >= self.threshold
+    }
+
+    /// Base cost plus 5 for every strain scar.
+    fn cost(&self, scars: &ScarTissue) -> u64 {
+        self.base_cost + (Self::strain_scars(scars) * 5)
+    }
+
+    /// INJURY PATH: A strain scar.
+    /// 
+    /// **CONSEQUENCE**: This scar is permanent.
+    /// **CONSEQUENCE**: Future pulses cost more energy.
+    /// **CONSEQUENCE**: Eventually, pulses become impossible.
+    fn violation(&self, lineage: &Lineage) -> OperationError {
+        OperationError::new(
+            ScarSeverity::Moderate,
+            format!("Pulse strain: insufficient energy (had {}, need {})", 
+                lineage.metabolism().energy(), self.threshold),
+        ).with_context(
+            "Contract violation: pulse executed below threshold. \
+             This permanently increases future pulse cost by 5 energy.".to_string()
+        )
+    }
+}
+
 impl Default for PulseBehavior {
     fn default() -> Self {
         Self::new()
@@ -413,6 +436,48 @@ mod tests {
         assert!(!output.strain_occurred); // No strain because pulse didn't execute
     }
 
+    /// A domain behavior: deploys must not run on a lineage with severe scars
+    struct Deploy;
+
+    impl Behavior for Deploy {
+        fn name(&self) -> &str {
+            "Deploy"
+        }
+
+        fn precondition(&self, lineage: &Lineage) -> bool {
+            lineage.scars().scars_by_severity(ScarSeverity::Severe).is_empty()
+        }
+
+        fn cost(&self, scars: &ScarTissue) -> u64 {
+            20 + 10 * scars.scar_count() as u64
+        }
+
+        fn violation(&self, _lineage: &Lineage) -> OperationError {
+            OperationError::new(ScarSeverity::Severe, "Deploy on a damaged lineage".to_string())
+        }
+    }
+
+    #[test]
+    fn registered_behaviors_share_the_scar_mechanics() {
+        let mut lineage = Lineage::create(500);
+        assert!(lineage.register_behavior(Box::new(Deploy)));
+        assert!(!lineage.register_behavior(Box::new(Deploy)), "Names are registered once");
+        assert!(lineage.run_behavior("Rollback").is_none());
+
+        let healthy = lineage.run_behavior("Deploy").unwrap();
+        assert!(healthy.completed() && healthy.contract_held && !healthy.scarred);
+        assert_eq!(healthy.cost, 20);
+
+        lineage.record_error(OperationError::new(ScarSeverity::Severe, "Disk failure".to_string()));
+
+        // Contract violated: the deploy runs, costs more, and scars again
+        let violated = lineage.run_behavior("Deploy").unwrap();
+        assert_eq!(violated.cost, 30);
+        assert!(violated.completed() && !violated.contract_held && violated.scarred);
+        assert_eq!(lineage.scars().scar_count(), 2);
+        assert_eq!(lineage.run_behavior("Deploy").unwrap().cost, 40);
+    }
+
     #[test]
     fn strain_scars_are_permanent() {
         let mut lineage = Lineage::create(100);
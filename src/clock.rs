@@ -0,0 +1,171 @@
+//! # Clock
+//!
+//! ## What This Enforces
+//! - Every timestamp the crate records comes from one injectable source
+//! - Existing constructors keep reading the system clock
+//! - A manual clock only moves forward, so causal order still holds
+//! - A manual clock only reports representable instants: from the Unix
+//!   epoch until nanoseconds since it overflow an `i64` (April 2262)
+//!
+//! ## What This Forbids
+//! - Rewinding a manual clock (time cannot run backwards)
+//! - Starting or moving a manual clock outside the representable range
+//!
+//! ## Usage
+//! Pass a [`ManualClock`] to the `*_with_clock` constructors (`Lineage`,
+//! `TaskAgent`, `GovernanceCouncil`, `ProvenanceVault`, `Graveyard`) to pin
+//! timestamps in tests and make digests reproducible. Identities stay
+//! unique under a pinned clock: their entropy is still random.
+
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex};
+
+/// A source of the current time
+///
+/// Identities record their birth as nanoseconds since the Unix epoch, so
+/// `now` must stay within the range accepted by [`is_representable`].
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// The current instant
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// True if `instant` can be recorded as a birth time: not before the Unix
+/// epoch, and with nanoseconds since it fitting an `i64`
+pub fn is_representable(instant: DateTime<Utc>) -> bool {
+    instant >= DateTime::UNIX_EPOCH && instant.timestamp_nanos_opt().is_some()
+}
+
+/// A clock shared by a component and everything it creates
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The shared system clock, used by every constructor without a clock argument
+pub fn system() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// Wall-clock time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    /// Start the clock at `start`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not representable (see `try_new`).
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self::try_new(start).unwrap_or_else(|reason| panic!("{}", reason))
+    }
+
+    /// Start the clock at `start`, refusing instants before the Unix epoch
+    /// or past April 2262
+    pub fn try_new(start: DateTime<Utc>) -> Result<Self, String> {
+        if !is_representable(start) {
+            return Err(format!("Clock cannot start at unrepresentable instant {}", start));
+        }
+
+        Ok(ManualClock {
+            now: Mutex::new(start),
+        })
+    }
+
+    /// Start the clock at the Unix epoch
+    pub fn at_epoch() -> Self {
+        Self::new(DateTime::UNIX_EPOCH)
+    }
+
+    /// Move the clock forward by `step`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is negative (time cannot run backwards), or if it
+    /// would move the clock past the representable range.
+    pub fn advance(&self, step: Duration) {
+        assert!(step >= Duration::zero(), "ONTOLOGICAL VIOLATION: Clock cannot run backwards");
+        let mut now = self.lock();
+        let next = now
+            .checked_add_signed(step)
+            .filter(|next| is_representable(*next))
+            .expect("Clock cannot move past the representable range");
+        *now = next;
+    }
+
+    /// Move the clock forward to `instant`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `instant` is earlier than the current time, or past the
+    /// representable range.
+    pub fn set(&self, instant: DateTime<Utc>) {
+        let mut now = self.lock();
+        assert!(instant >= *now, "ONTOLOGICAL VIOLATION: Clock cannot run backwards");
+        assert!(is_representable(instant), "Clock cannot move past the representable range");
+        *now = instant;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_moves_only_when_told() {
+        let clock = ManualClock::at_epoch();
+        assert_eq!(clock.now(), DateTime::UNIX_EPOCH);
+
+        clock.advance(Duration::seconds(90));
+        assert_eq!(clock.now(), DateTime::UNIX_EPOCH + Duration::seconds(90));
+
+        clock.set(DateTime::UNIX_EPOCH + Duration::hours(1));
+        assert_eq!(clock.now(), DateTime::UNIX_EPOCH + Duration::hours(1));
+    }
+
+    #[test]
+    fn manual_clock_refuses_unrepresentable_instants() {
+        let before_epoch = DateTime::UNIX_EPOCH - Duration::seconds(1);
+        let latest = DateTime::from_timestamp_nanos(i64::MAX);
+
+        assert!(ManualClock::try_new(before_epoch).is_err());
+        assert!(ManualClock::try_new(latest + Duration::seconds(1)).is_err());
+
+        let clock = ManualClock::try_new(latest).unwrap();
+        assert_eq!(clock.now(), latest);
+    }
+
+    #[test]
+    #[should_panic(expected = "Clock cannot move past the representable range")]
+    fn manual_clock_cannot_advance_past_2262() {
+        let clock = ManualClock::new(DateTime::from_timestamp_nanos(i64::MAX));
+        clock.advance(Duration::nanoseconds(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Clock cannot run backwards")]
+    fn manual_clock_cannot_rewind() {
+        let clock = ManualClock::at_epoch();
+        clock.advance(Duration::seconds(10));
+        clock.set(DateTime::UNIX_EPOCH);
+    }
+}
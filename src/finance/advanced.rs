@@ -414,8 +414,11 @@ impl ResurrectionMechanic {
     
     /// Check if resurrection should occur (probability-based)
     pub fn should_resurrect(&self) -> bool {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+        self.should_resurrect_with_rng(&mut rand::thread_rng())
+    }
+    
+    /// Check if resurrection should occur, rolling with `rng`
+    pub fn should_resurrect_with_rng<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> bool {
         rng.gen_range(0.0..1.0) < self.resurrection_probability
     }
     
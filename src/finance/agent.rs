@@ -10,8 +10,11 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use crate::lineage::Lineage;
-use crate::scar::ScarSeverity;
+use rand::Rng;
+use crate::lineage::{Lineage, OperationError};
+use crate::scar::{ScarCategory, ScarSeverity};
+use super::trade::{ExecutionError, TradeDirection, TradeOperation};
+use super::trust_scoring::TrustPermission;
 use std::fmt;
 
 /// Unique identifier for a trading agent (non-copyable)
@@ -117,6 +120,9 @@ pub struct FinanceAgent {
     
     /// Cost multiplier from accumulated scars
     pub scar_cost_multiplier: f32,
+    
+    /// Trust permissions lost to grant downgrades (never restored)
+    revoked_permissions: Vec<TrustPermission>,
 }
 
 impl FinanceAgent {
@@ -153,20 +159,26 @@ impl FinanceAgent {
             parent_id: None,
             max_scars: 5,
             scar_cost_multiplier: 1.0,
+            revoked_permissions: Vec::new(),
         }
     }
     
     /// Create a spawned agent (offspring of successful parent)
+    /// 
+    /// The child inherits every permission its parent has lost: a lineage
+    /// cannot shed a trust downgrade by spawning.
     pub fn spawn_offspring(
         parent_id: AgentId,
         strategy: String,
         inherited_capital: u64,
         parent_generation: u32,
         inherited_multiplier: f32,
+        inherited_revocations: &[TrustPermission],
     ) -> Self {
         let mut agent = FinanceAgent::new(strategy, inherited_capital, parent_generation + 1);
         agent.parent_id = Some(parent_id);
         agent.scar_cost_multiplier = inherited_multiplier;
+        agent.revoke_permissions(inherited_revocations, &format!("Inherited from parent {}", parent_id));
         agent
     }
     
@@ -187,7 +199,17 @@ impl FinanceAgent {
     }
     
     /// Apply a financial scar from a loss
-    pub fn inflict_financial_scar(&mut self, drawdown_percentage: f32, _severity: ScarSeverity) {
+    /// 
+    /// The scar is also recorded on the lineage as `Financial`/`FIN_DRAWDOWN`
+    /// so it aggregates with scars from other subsystems.
+    pub fn inflict_financial_scar(&mut self, drawdown_percentage: f32, severity: ScarSeverity) {
+        self.lineage.record_error(
+            OperationError::new(severity, format!("Drawdown of {:.2}%", drawdown_percentage))
+                .with_category(ScarCategory::Financial)
+                .with_code("FIN_DRAWDOWN")
+                .with_detail("drawdown_pct", format!("{:.2}", drawdown_percentage)),
+        );
+        
         if self.metrics.current_drawdown < drawdown_percentage {
             self.metrics.current_drawdown = drawdown_percentage;
         }
@@ -230,7 +252,18 @@ impl FinanceAgent {
     
     /// Evolve offspring with inherited strategy and mutations
     /// Mutations introduce random variations to strategy weights
-    pub fn spawn_evolved_offspring(&self, mutation_rate: f32) -> Self {
+    /// 
+    /// Fails if the agent has lost `Spawning`.
+    pub fn spawn_evolved_offspring(&self, mutation_rate: f32) -> Result<Self, String> {
+        self.spawn_evolved_offspring_with_rng(mutation_rate, &mut rand::thread_rng())
+    }
+    
+    /// Evolve offspring, drawing its mutations from `rng`
+    pub fn spawn_evolved_offspring_with_rng<R: Rng + ?Sized>(&self, mutation_rate: f32, rng: &mut R) -> Result<Self, String> {
+        if !self.has_permission(TrustPermission::Spawning) {
+            return Err(format!("Agent {} has lost permission {:?}", self.id, TrustPermission::Spawning));
+        }
+        
         let inherited_capital = (self.metrics.capital as f32 * 0.3) as u64; // Offspring gets 30% of parent capital
         let mutated_strategy = format!("{}_v{}", self.strategy, self.metrics.generation + 1);
         
@@ -240,12 +273,13 @@ impl FinanceAgent {
             inherited_capital,
             self.metrics.generation,
             self.scar_cost_multiplier * 0.95, // 5% improvement from evolution
+            &self.revoked_permissions,
         );
         
         // Mutations: small random variations to improve strategy
-        offspring.metrics.trust_score = (self.metrics.trust_score * (1.0 + mutation_rate * (rand::random::<f32>() - 0.5))).clamp(0.0, 100.0);
+        offspring.metrics.trust_score = (self.metrics.trust_score * (1.0 + mutation_rate * (rng.r#gen::<f32>() - 0.5))).clamp(0.0, 100.0);
         
-        offspring
+        Ok(offspring)
     }
     
     /// Calculate effective trade cost including scar multiplier
@@ -296,6 +330,56 @@ impl FinanceAgent {
         }
     }
     
+    /// Check if a trust permission is still available (not revoked)
+    pub fn has_permission(&self, permission: TrustPermission) -> bool {
+        !self.revoked_permissions.contains(&permission)
+    }
+    
+    /// Trust permissions this agent has permanently lost
+    pub fn revoked_permissions(&self) -> &[TrustPermission] {
+        &self.revoked_permissions
+    }
+    
+    /// Permanently revoke trust permissions (irreversible)
+    /// 
+    /// Returns the permissions that were newly lost. The loss is recorded
+    /// on the lineage; no method exists to restore them.
+    pub fn revoke_permissions(&mut self, permissions: &[TrustPermission], reason: &str) -> Vec<TrustPermission> {
+        let mut lost = Vec::new();
+        for permission in permissions {
+            if self.has_permission(*permission) && !lost.contains(permission) {
+                lost.push(*permission);
+            }
+        }
+        
+        self.revoked_permissions.extend(lost.iter().copied());
+        if !lost.is_empty() && self.lineage.is_alive() {
+            self.lineage
+                .memory_mut()
+                .append(format!("Trust permissions revoked: {:?} - {}", lost, reason));
+        }
+        lost
+    }
+    
+    /// Validate a trade against capital, leverage limits and trust permissions
+    /// 
+    /// Leveraged trades (leverage above 1.0) require `MarginTrading`; short
+    /// positions are derivatives and require `DerivativeTrading`.
+    pub fn check_trade(&self, operation: &TradeOperation, max_leverage: f32) -> Result<(), ExecutionError> {
+        if operation.trade.leverage > 1.0 && !self.has_permission(TrustPermission::MarginTrading) {
+            return Err(ExecutionError::PermissionRevoked {
+                permission: TrustPermission::MarginTrading,
+            });
+        }
+        if operation.trade.direction == TradeDirection::Sell && !self.has_permission(TrustPermission::DerivativeTrading) {
+            return Err(ExecutionError::PermissionRevoked {
+                permission: TrustPermission::DerivativeTrading,
+            });
+        }
+        
+        operation.validate(self.get_capital(), max_leverage)
+    }
+    
     /// Restore capital (only from successful trades, not from external recharge)
     pub fn restore_capital_from_profit(&mut self, profit: u64) {
         // Note: This would require extending Lineage to support controlled energy restoration
@@ -347,4 +431,38 @@ mod tests {
         assert_eq!(agent.metrics.total_trades, 1);
         assert_eq!(agent.metrics.win_rate, 100.0);
     }
+
+    #[test]
+    fn revoked_permissions_gate_shorts_and_spawning() {
+        use super::super::trade::Trade;
+
+        let mut agent = FinanceAgent::new("TestStrategy".to_string(), 10000, 0);
+        let short = TradeOperation {
+            trade: Trade::new(1, TradeDirection::Sell, "BTC".to_string(), 1, 100.0, 1.0, 0.1),
+            fee_cost: 0,
+            capital_requirement: 100,
+        };
+        assert!(agent.check_trade(&short, 5.0).is_ok());
+        assert!(agent.spawn_evolved_offspring(0.1).is_ok());
+
+        agent.revoke_permissions(&[TrustPermission::DerivativeTrading, TrustPermission::Spawning], "Downgrade");
+        assert!(matches!(
+            agent.check_trade(&short, 5.0),
+            Err(ExecutionError::PermissionRevoked { permission: TrustPermission::DerivativeTrading })
+        ));
+        assert!(agent.spawn_evolved_offspring(0.1).is_err());
+    }
+
+    #[test]
+    fn offspring_inherit_revoked_permissions() {
+        let mut parent = FinanceAgent::new("TestStrategy".to_string(), 10000, 0);
+        parent.revoke_permissions(&[TrustPermission::MarginTrading], "Downgrade");
+
+        let child = parent.spawn_evolved_offspring(0.1).unwrap();
+        assert!(!child.has_permission(TrustPermission::MarginTrading));
+        assert!(child.has_permission(TrustPermission::Spawning));
+
+        let grandchild = child.spawn_evolved_offspring(0.1).unwrap();
+        assert_eq!(grandchild.revoked_permissions(), &[TrustPermission::MarginTrading]);
+    }
 }
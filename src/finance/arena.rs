@@ -5,9 +5,18 @@
 //! - Agents trade independently but result affects rankings
 //! - Successful agents spawn, poor performers die
 //! - Creates evolutionary pressure for strategy improvement
+//!
+//! An arena built with `Arena::with_seed` replays the same market bit for bit.
+//! Only agents that still hold `Competition` may enter, and only entrants'
+//! results are recorded.
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use super::agent::FinanceAgent;
+use super::trust_scoring::TrustPermission;
 
 /// Market state snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,11 +56,20 @@ impl MarketState {
     
     /// Generate next market state (random walk)
     pub fn next_state(&self) -> Self {
+        self.next_state_with_rng(&mut rand::thread_rng())
+    }
+    
+    /// Generate next market state drawing randomness from `rng`
+    pub fn next_state_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Self {
         let mut next = MarketState::new(self.round + 1);
         let drift = self.trend * 0.0001;
         
-        for (asset, price) in &self.prices {
-            let change = (rand::random::<f32>() - 0.5) * self.volatility * 2.0 + drift;
+        // Walk assets in name order so a seeded rng is not at the mercy of hash order
+        let mut assets: Vec<_> = self.prices.iter().collect();
+        assets.sort_by(|a, b| a.0.cmp(b.0));
+        
+        for (asset, price) in assets {
+            let change = (rng.r#gen::<f32>() - 0.5) * self.volatility * 2.0 + drift;
             next.set_price(
                 asset.clone(),
                 (price * (1.0 + change)).max(0.01),
@@ -59,13 +77,13 @@ impl MarketState {
         }
         
         // Volatility tends to mean revert
-        next.volatility = self.volatility * 0.95 + rand::random::<f32>() * 0.01;
+        next.volatility = self.volatility * 0.95 + rng.r#gen::<f32>() * 0.01;
         
         // Trend random walk
-        next.trend = (self.trend + (rand::random::<f32>() - 0.5) * 0.1).clamp(-1.0, 1.0);
+        next.trend = (self.trend + (rng.r#gen::<f32>() - 0.5) * 0.1).clamp(-1.0, 1.0);
         
         // 1% chance of black swan each round
-        next.black_swan = rand::random::<f32>() < 0.01;
+        next.black_swan = rng.r#gen::<f32>() < 0.01;
         
         next
     }
@@ -160,11 +178,26 @@ pub struct Arena {
     pub total_trades_executed: u64,
     pub total_agents_eliminated: u64,
     pub total_spawns: u64,
+    
+    /// IDs of the agents admitted to the competition
+    entrants: HashSet<String>,
+    
+    /// Source of market randomness
+    rng: StdRng,
 }
 
 impl Arena {
     /// Create new arena
     pub fn new(config: ArenaConfig) -> Self {
+        Self::with_rng(config, StdRng::from_entropy())
+    }
+    
+    /// Create an arena whose market replays identically for the same seed
+    pub fn with_seed(config: ArenaConfig, seed: u64) -> Self {
+        Self::with_rng(config, StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(config: ArenaConfig, rng: StdRng) -> Self {
         let mut market = MarketState::new(0);
         
         // Initialize prices
@@ -180,12 +213,29 @@ impl Arena {
             total_trades_executed: 0,
             total_agents_eliminated: 0,
             total_spawns: 0,
+            entrants: HashSet::new(),
+            rng,
         }
     }
     
+    /// Admit an agent to the competition
+    /// 
+    /// Fails if the agent is dead or has lost `Competition`.
+    pub fn enter(&mut self, agent: &FinanceAgent) -> Result<(), String> {
+        if !agent.is_alive() {
+            return Err(format!("Agent {} is dead", agent.id));
+        }
+        if !agent.has_permission(TrustPermission::Competition) {
+            return Err(format!("Agent {} has lost permission {:?}", agent.id, TrustPermission::Competition));
+        }
+        
+        self.entrants.insert(agent.id.to_string());
+        Ok(())
+    }
+    
     /// Run one round of competition
     pub fn tick_round(&mut self) {
-        self.market_state = self.market_state.next_state();
+        self.market_state = self.market_state.next_state_with_rng(&mut self.rng);
         self.completed_rounds += 1;
     }
     
@@ -195,7 +245,13 @@ impl Arena {
     }
     
     /// Record agent result
-    pub fn record_result(&mut self, result: CompetitionResult) {
+    /// 
+    /// Fails if the agent never entered the arena.
+    pub fn record_result(&mut self, result: CompetitionResult) -> Result<(), String> {
+        if !self.entrants.contains(&result.agent_id) {
+            return Err(format!("Agent {} did not enter the arena", result.agent_id));
+        }
+        
         if !result.ready_to_spawn {
             self.total_agents_eliminated += 1;
         } else {
@@ -204,6 +260,7 @@ impl Arena {
         
         self.total_trades_executed += result.trades_executed;
         self.results.push(result);
+        Ok(())
     }
     
     /// Get ranked results
@@ -278,6 +335,46 @@ mod tests {
         assert!(next.prices.contains_key("BTC"));
     }
 
+    #[test]
+    fn test_seeded_arenas_replay_identically() {
+        let mut first = Arena::with_seed(ArenaConfig::default(), 7);
+        let mut second = Arena::with_seed(ArenaConfig::default(), 7);
+        
+        for _ in 0..50 {
+            first.tick_round();
+            second.tick_round();
+        }
+        
+        assert_eq!(first.market_state.prices, second.market_state.prices);
+        assert_eq!(first.market_state.volatility, second.market_state.volatility);
+        assert_eq!(first.market_state.trend, second.market_state.trend);
+    }
+
+    #[test]
+    fn only_agents_holding_competition_enter_and_record() {
+        let mut arena = Arena::with_seed(ArenaConfig::default(), 7);
+        let entrant = FinanceAgent::new("Momentum".to_string(), 10000, 0);
+        let mut banned = FinanceAgent::new("Reckless".to_string(), 10000, 0);
+        banned.revoke_permissions(&[TrustPermission::Competition], "Downgrade");
+        let result = |agent: &FinanceAgent| CompetitionResult {
+            agent_id: agent.id.to_string(),
+            rank: 1,
+            final_capital: 11000,
+            return_percentage: 10.0,
+            trades_executed: 5,
+            wins: 3,
+            losses: 2,
+            scars_inflicted: 0,
+            ready_to_spawn: false,
+        };
+
+        assert!(arena.enter(&entrant).is_ok());
+        assert!(arena.enter(&banned).is_err());
+        assert!(arena.record_result(result(&entrant)).is_ok());
+        assert!(arena.record_result(result(&banned)).is_err());
+        assert_eq!(arena.results.len(), 1);
+    }
+
     #[test]
     fn test_arena_creation() {
         let config = ArenaConfig::default();
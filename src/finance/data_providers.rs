@@ -6,6 +6,7 @@
 //! - CoinGecko (tertiary)
 //! - Kraken (fallback)
 
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -284,15 +285,27 @@ impl MultiProviderFetcher {
 /// Calculate realistic volatility for each cryptocurrency
 /// In real implementation, this would fetch historical prices
 pub fn calculate_volatility(prices: &HashMap<String, f64>) -> HashMap<String, f64> {
+    calculate_volatility_with_rng(prices, &mut rand::thread_rng())
+}
+
+/// Calculate volatility for each cryptocurrency drawing the noise from `rng`
+pub fn calculate_volatility_with_rng<R: Rng + ?Sized>(
+    prices: &HashMap<String, f64>,
+    rng: &mut R,
+) -> HashMap<String, f64> {
     let mut volatility = HashMap::new();
     
+    // Walk symbols in name order so a seeded rng is not at the mercy of hash order
+    let mut symbols: Vec<_> = prices.keys().collect();
+    symbols.sort();
+    
     // Realistic historical volatility ranges for crypto
-    for symbol in prices.keys() {
+    for symbol in symbols {
         let vol = match symbol.to_uppercase().as_str() {
-            s if s.contains("BTC") => 35.0 + (rand::random::<f32>() - 0.5) * 10.0, // BTC: 30-40%
-            s if s.contains("ETH") => 45.0 + (rand::random::<f32>() - 0.5) * 15.0, // ETH: 37.5-52.5%
-            s if s.contains("SOL") => 55.0 + (rand::random::<f32>() - 0.5) * 20.0, // SOL: 45-65%
-            _ => 40.0 + (rand::random::<f32>() - 0.5) * 15.0,                       // Default: 32.5-47.5%
+            s if s.contains("BTC") => 35.0 + (rng.r#gen::<f32>() - 0.5) * 10.0, // BTC: 30-40%
+            s if s.contains("ETH") => 45.0 + (rng.r#gen::<f32>() - 0.5) * 15.0, // ETH: 37.5-52.5%
+            s if s.contains("SOL") => 55.0 + (rng.r#gen::<f32>() - 0.5) * 20.0, // SOL: 45-65%
+            _ => 40.0 + (rng.r#gen::<f32>() - 0.5) * 15.0,                       // Default: 32.5-47.5%
         };
         volatility.insert(symbol.clone(), (vol.max(0.0)) as f64);
     }
@@ -304,9 +317,18 @@ impl CoinMarketCapProvider {
     /// Simulate realistic price swings based on volatility
     /// Adds random noise to mimic real market movements
     pub fn apply_volatility_swing(price: f64, volatility_percent: f64) -> f64 {
+        Self::apply_volatility_swing_with_rng(price, volatility_percent, &mut rand::thread_rng())
+    }
+    
+    /// Simulate a price swing drawing the shock from `rng`
+    pub fn apply_volatility_swing_with_rng<R: Rng + ?Sized>(
+        price: f64,
+        volatility_percent: f64,
+        rng: &mut R,
+    ) -> f64 {
         let daily_return = (volatility_percent / 252.0).sqrt(); // Annualized to daily
-        let random_shock = (rand::random::<f32>() - 0.5) * 2.0; // -1 to 1
-        let price_change = price * (daily_return as f64 / 100.0) * (random_shock as f64);
+        let random_shock = (rng.r#gen::<f32>() - 0.5) * 2.0; // -1 to 1
+        let price_change = price * (daily_return / 100.0) * (random_shock as f64);
         (price + price_change).max(0.0)
     }
 }
@@ -323,10 +345,35 @@ mod tests {
         let snapshot = MarketSnapshot {
             timestamp: 1234567890,
             prices,
+            volatility: HashMap::new(),
             source: "Test".to_string(),
         };
 
         assert_eq!(snapshot.source, "Test");
         assert_eq!(snapshot.prices.len(), 1);
     }
+
+    #[test]
+    fn seeded_volatility_replays() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut prices = HashMap::new();
+        for symbol in ["BTC-USD", "ETH-USD", "SOL-USD", "ADA-USD"] {
+            prices.insert(symbol.to_string(), 100.0);
+        }
+
+        let first = calculate_volatility_with_rng(&prices, &mut StdRng::seed_from_u64(11));
+        let second = calculate_volatility_with_rng(&prices, &mut StdRng::seed_from_u64(11));
+        assert_eq!(first, second);
+
+        let swing = |seed| {
+            CoinMarketCapProvider::apply_volatility_swing_with_rng(
+                45000.0,
+                35.0,
+                &mut StdRng::seed_from_u64(seed),
+            )
+        };
+        assert_eq!(swing(3), swing(3));
+    }
 }
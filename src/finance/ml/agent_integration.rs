@@ -6,6 +6,9 @@
 use crate::finance::agent::{FinanceAgent, FinanceAgentStatus};
 use crate::finance::ml::models::q_net::SimpleQNet;
 use crate::finance::ml::traits::{MarketState, TradeAction};
+use crate::finance::trust_scoring::TrustPermission;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// A trading agent enhanced with ML decision-making capabilities
 ///
@@ -29,6 +32,9 @@ pub struct MLFinanceAgent {
     
     /// Standard deviation for Gaussian mutations
     pub mutation_strength: f32,
+    
+    /// Source of exploration and offspring randomness
+    rng: StdRng,
 }
 
 impl MLFinanceAgent {
@@ -41,7 +47,32 @@ impl MLFinanceAgent {
         mutation_rate: f32,
         mutation_strength: f32,
     ) -> Result<Self, String> {
-        let q_net = SimpleQNet::new(input_size, hidden_size)
+        Self::with_rng(agent, input_size, hidden_size, epsilon, mutation_rate, mutation_strength, StdRng::from_entropy())
+    }
+    
+    /// Create an ML-enhanced agent whose weights, exploration and offspring are fixed by `seed`
+    pub fn with_seed(
+        agent: FinanceAgent,
+        input_size: usize,
+        hidden_size: usize,
+        epsilon: f32,
+        mutation_rate: f32,
+        mutation_strength: f32,
+        seed: u64,
+    ) -> Result<Self, String> {
+        Self::with_rng(agent, input_size, hidden_size, epsilon, mutation_rate, mutation_strength, StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(
+        agent: FinanceAgent,
+        input_size: usize,
+        hidden_size: usize,
+        epsilon: f32,
+        mutation_rate: f32,
+        mutation_strength: f32,
+        mut rng: StdRng,
+    ) -> Result<Self, String> {
+        let q_net = SimpleQNet::with_rng(input_size, hidden_size, &mut rng)
             .map_err(|e| format!("Failed to create QNet: {:?}", e))?;
         
         Ok(MLFinanceAgent {
@@ -50,18 +81,19 @@ impl MLFinanceAgent {
             epsilon,
             mutation_rate,
             mutation_strength,
+            rng,
         })
     }
     
     /// Decide trade action using epsilon-greedy strategy
     ///
-    /// With probability epsilon, explore (random action).
-    /// Otherwise, exploit (use Q-Net prediction).
-    pub fn decide_trade(&self, market_state: &MarketState) -> TradeAction {
+    /// With probability epsilon, explore (random action drawn from the
+    /// agent's own RNG). Otherwise, exploit (use Q-Net prediction).
+    pub fn decide_trade(&mut self, market_state: &MarketState) -> TradeAction {
         // Epsilon-greedy exploration
-        if rand::random::<f32>() < self.epsilon {
+        if self.rng.r#gen::<f32>() < self.epsilon {
             // Explore: random action
-            let rand_action = rand::random::<u32>() % 3;
+            let rand_action = self.rng.gen_range(0..3u32);
             match rand_action {
                 0 => TradeAction::Buy,
                 1 => TradeAction::Sell,
@@ -148,22 +180,33 @@ impl MLFinanceAgent {
     /// - Half the parent's capital
     /// - Generation number incremented
     /// - Cloned Q-Net (mutations would require internal access)
-    pub fn spawn_offspring(&self) -> Result<MLFinanceAgent, String> {
-        // Create base offspring agent
-        let offspring_agent = FinanceAgent::new(
+    /// - An RNG seeded from the parent's, so a seeded lineage stays reproducible
+    ///   without the child replaying the parent's random stream
+    pub fn spawn_offspring(&mut self) -> Result<MLFinanceAgent, String> {
+        if !self.agent.has_permission(TrustPermission::Spawning) {
+            return Err(format!("Agent {} has lost permission {:?}", self.agent.id, TrustPermission::Spawning));
+        }
+        
+        // Create base offspring agent, carrying every permission the parent lost
+        let mut offspring_agent = FinanceAgent::new(
             format!("{}_gen{}", self.agent.strategy, self.agent.metrics.generation + 1),
             self.agent.metrics.capital / 2,  // Split capital
             self.agent.metrics.generation + 1,
         );
+        offspring_agent.revoke_permissions(
+            self.agent.revoked_permissions(),
+            &format!("Inherited from parent {}", self.agent.id),
+        );
         
         // Create offspring with parent's Q-Net (cloned)
-        let offspring = MLFinanceAgent::new(
+        let offspring = MLFinanceAgent::with_seed(
             offspring_agent,
             5,  // input_size
             64, // hidden_size
             self.epsilon,
             self.mutation_rate,
             self.mutation_strength,
+            self.rng.r#gen(),
         )?;
         
         Ok(offspring)
@@ -232,18 +275,25 @@ impl MLAgentArena {
 }
 
 impl MLFinanceAgent {
-    /// Clone the agent for evolution
-    fn clone_agent(&self) -> Self {
+    /// Clone the agent for evolution, reseeding the clone from this agent's RNG
+    fn clone_agent(&mut self) -> Self {
+        let mut agent = FinanceAgent::new(
+            self.agent.strategy.clone(),
+            self.agent.metrics.capital,
+            self.agent.metrics.generation,
+        );
+        agent.revoke_permissions(
+            self.agent.revoked_permissions(),
+            &format!("Inherited from {}", self.agent.id),
+        );
+        
         MLFinanceAgent {
-            agent: FinanceAgent::new(
-                self.agent.strategy.clone(),
-                self.agent.metrics.capital,
-                self.agent.metrics.generation,
-            ),
+            agent,
             q_net: self.q_net.clone(),
             epsilon: self.epsilon,
             mutation_rate: self.mutation_rate,
             mutation_strength: self.mutation_strength,
+            rng: StdRng::seed_from_u64(self.rng.r#gen()),
         }
     }
 }
@@ -274,4 +324,58 @@ mod tests {
         
         assert_eq!(arena.agents.len(), 1);
     }
+    
+    #[test]
+    fn offspring_and_survivors_keep_revoked_permissions() {
+        let mut agent = FinanceAgent::new("a".to_string(), 1000, 0);
+        agent.revoke_permissions(&[TrustPermission::MarginTrading], "Downgrade");
+        let mut parent = MLFinanceAgent::with_seed(agent, 5, 64, 1.0, 0.1, 0.5, 11)
+            .expect("Failed to create ML agent");
+        
+        let child = parent.spawn_offspring().expect("Failed to spawn offspring");
+        assert!(!child.agent.has_permission(TrustPermission::MarginTrading));
+        assert!(!parent.clone_agent().agent.has_permission(TrustPermission::MarginTrading));
+        
+        parent.agent.revoke_permissions(&[TrustPermission::Spawning], "Downgrade");
+        assert!(parent.spawn_offspring().is_err());
+    }
+    
+    #[test]
+    fn seeded_agents_explore_identically() {
+        let market_state = MarketState {
+            prices: vec![1.0, 0.5, 0.2, 0.1, 0.0],
+            volatility: vec![0.1; 5],
+            agent_capital: 0.5,
+            scar_count: 0,
+            win_loss_ratio: 0.5,
+            timestamp: 0,
+        };
+        let mut first = MLFinanceAgent::with_seed(FinanceAgent::new("a".to_string(), 1000, 0), 5, 64, 1.0, 0.1, 0.5, 11)
+            .expect("Failed to create ML agent");
+        let mut second = MLFinanceAgent::with_seed(FinanceAgent::new("a".to_string(), 1000, 0), 5, 64, 1.0, 0.1, 0.5, 11)
+            .expect("Failed to create ML agent");
+        
+        let first_actions: Vec<String> = (0..20).map(|_| format!("{:?}", first.decide_trade(&market_state))).collect();
+        let second_actions: Vec<String> = (0..20).map(|_| format!("{:?}", second.decide_trade(&market_state))).collect();
+        assert_eq!(first_actions, second_actions);
+    }
+    
+    #[test]
+    fn offspring_does_not_replay_parent_exploration() {
+        let market_state = MarketState {
+            prices: vec![1.0, 0.5, 0.2, 0.1, 0.0],
+            volatility: vec![0.1; 5],
+            agent_capital: 0.5,
+            scar_count: 0,
+            win_loss_ratio: 0.5,
+            timestamp: 0,
+        };
+        let mut parent = MLFinanceAgent::with_seed(FinanceAgent::new("a".to_string(), 1000, 0), 5, 64, 1.0, 0.1, 0.5, 11)
+            .expect("Failed to create ML agent");
+        let mut child = parent.spawn_offspring().expect("Failed to spawn offspring");
+        
+        let parent_actions: Vec<String> = (0..32).map(|_| format!("{:?}", parent.decide_trade(&market_state))).collect();
+        let child_actions: Vec<String> = (0..32).map(|_| format!("{:?}", child.decide_trade(&market_state))).collect();
+        assert_ne!(parent_actions, child_actions);
+    }
 }
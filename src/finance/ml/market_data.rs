@@ -3,6 +3,8 @@
 
 use std::collections::HashMap;
 use chrono::{DateTime, Duration, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use crate::finance::ml::errors::Result;
 
@@ -84,12 +86,25 @@ impl MarketDataCache {
 /// CoinMarketCap API provider (mock for now)
 pub struct CoinMarketCapProvider {
     cache: MarketDataCache,
+    
+    /// Source of the synthetic random walk
+    rng: StdRng,
 }
 
 impl CoinMarketCapProvider {
     pub fn new() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+    
+    /// Provider whose synthetic candles replay exactly for a given seed
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(rng: StdRng) -> Self {
         Self {
             cache: MarketDataCache::new(),
+            rng,
         }
     }
     
@@ -112,31 +127,8 @@ impl CoinMarketCapProvider {
         // For now, generate synthetic data
         println!("Generating synthetic data for {} ({} days)", symbol, days);
         
-        let mut candles = Vec::new();
-        let mut price = 30_000.0;  // Start BTC at ~30k
-        let mut timestamp = Utc::now() - Duration::days(days as i64);
-        
-        for _ in 0..days {
-            // Random walk
-            let change = (rand::random::<f32>() - 0.5) * 2000.0;
-            let close = (price + change).max(100.0);
-            let open = price;
-            let high = price.max(close) + rand::random::<f32>() * 500.0;
-            let low = price.min(close) - rand::random::<f32>() * 500.0;
-            let volume = rand::random::<f32>() * 1_000_000.0;
-            
-            candles.push(Candle {
-                timestamp,
-                open,
-                high,
-                low,
-                close,
-                volume,
-            });
-            
-            price = close;
-            timestamp = timestamp + Duration::days(1);
-        }
+        let start = Utc::now() - Duration::days(days as i64);
+        let candles = synthetic_candles_with_rng(start, days, &mut self.rng);
         
         // Cache result
         self.cache.set(symbol.to_string(), candles.clone());
@@ -145,15 +137,63 @@ impl CoinMarketCapProvider {
     }
 }
 
+/// Generate a synthetic daily random walk starting at `start`, drawing from `rng`
+pub fn synthetic_candles_with_rng<R: Rng + ?Sized>(
+    start: DateTime<Utc>,
+    days: u32,
+    rng: &mut R,
+) -> Vec<Candle> {
+    let mut candles = Vec::new();
+    let mut price = 30_000.0;  // Start BTC at ~30k
+    let mut timestamp = start;
+    
+    for _ in 0..days {
+        // Random walk
+        let change = (rng.r#gen::<f32>() - 0.5) * 2000.0;
+        let close = (price + change).max(100.0);
+        let open = price;
+        let high = price.max(close) + rng.r#gen::<f32>() * 500.0;
+        let low = price.min(close) - rng.r#gen::<f32>() * 500.0;
+        let volume = rng.r#gen::<f32>() * 1_000_000.0;
+        
+        candles.push(Candle {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        });
+        
+        price = close;
+        timestamp = timestamp + Duration::days(1);
+    }
+    
+    candles
+}
+
 /// CoinDesk API provider (alternative source)
 pub struct CoinDeskProvider {
     cache: MarketDataCache,
+    
+    /// Source of the simulated price noise
+    rng: StdRng,
 }
 
 impl CoinDeskProvider {
     pub fn new() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+    
+    /// Provider whose simulated prices replay exactly for a given seed
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(rng: StdRng) -> Self {
         Self {
             cache: MarketDataCache::new(),
+            rng,
         }
     }
     
@@ -169,7 +209,7 @@ impl CoinDeskProvider {
             _ => 100.0,
         };
         
-        let noise = (rand::random::<f32>() - 0.5) * 2000.0;
+        let noise = (self.rng.r#gen::<f32>() - 0.5) * 2000.0;
         Ok(base_price + noise)
     }
 }
@@ -188,6 +228,15 @@ impl MarketDataFetcher {
         }
     }
     
+    /// Fetcher whose synthetic data replays exactly for a given seed
+    pub fn with_seed(seed: u64) -> Self {
+        let mut seeds = StdRng::seed_from_u64(seed);
+        Self {
+            coinmarketcap: CoinMarketCapProvider::with_seed(seeds.r#gen()),
+            coindesk: CoinDeskProvider::with_seed(seeds.r#gen()),
+        }
+    }
+    
     /// Fetch historical candles (prefers cached data)
     pub async fn fetch_historical(
         &mut self,
@@ -280,4 +329,20 @@ mod tests {
         let price = fetcher.fetch_current_price("BTC").await.unwrap();
         assert!(price > 0.0);
     }
+    
+    #[tokio::test]
+    async fn seeded_fetchers_replay_the_same_prices() {
+        let mut first = MarketDataFetcher::with_seed(42);
+        let mut second = MarketDataFetcher::with_seed(42);
+        
+        let close = |candles: Vec<Candle>| candles.iter().map(|c| c.close).collect::<Vec<_>>();
+        assert_eq!(
+            close(first.fetch_historical("BTC", 30).await.unwrap()),
+            close(second.fetch_historical("BTC", 30).await.unwrap()),
+        );
+        assert_eq!(
+            first.fetch_current_price("BTC").await.unwrap(),
+            second.fetch_current_price("BTC").await.unwrap(),
+        );
+    }
 }
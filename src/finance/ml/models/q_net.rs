@@ -4,6 +4,8 @@
 use crate::finance::ml::traits::{MlStrategy, MarketState, TradeAction, TradeDecision, ModelMetadata};
 use crate::finance::ml::errors::MlError;
 use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Serialize, Deserialize};
 
 #[cfg(feature = "ml")]
@@ -48,6 +50,16 @@ pub struct QNetConfig {
 impl SimpleQNet {
     /// Create new Q-network with random initialization
     pub fn new(input_size: usize, hidden_size: usize) -> Result<Self, MlError> {
+        Self::with_rng(input_size, hidden_size, &mut rand::thread_rng())
+    }
+    
+    /// Create a Q-network whose initial weights are fixed by `seed`
+    pub fn with_seed(input_size: usize, hidden_size: usize, seed: u64) -> Result<Self, MlError> {
+        Self::with_rng(input_size, hidden_size, &mut StdRng::seed_from_u64(seed))
+    }
+    
+    /// Create a Q-network initialized from `rng`
+    pub fn with_rng<R: Rng + ?Sized>(input_size: usize, hidden_size: usize, rng: &mut R) -> Result<Self, MlError> {
         let output_size = 3;  // Buy, Sell, Hold
         
         #[cfg(feature = "ml")]
@@ -57,8 +69,8 @@ impl SimpleQNet {
             let dist = Normal::new(0.0, 1.0 / (input_size as f32).sqrt())
                 .map_err(|e| MlError::InitializationError(e.to_string()))?;
             
-            let hidden_w = Array2::random((input_size, hidden_size), dist);
-            let output_w = Array2::random((hidden_size, output_size), dist);
+            let hidden_w = Array2::random_using((input_size, hidden_size), dist, rng);
+            let output_w = Array2::random_using((hidden_size, output_size), dist, rng);
             
             Ok(Self {
                 hidden_weights: hidden_w.outer_iter().map(|row| row.to_vec()).collect(),
@@ -83,6 +95,7 @@ impl SimpleQNet {
         
         #[cfg(not(feature = "ml"))]
         {
+            let _ = rng;  // Weights start at zero without the ml feature
             Ok(Self {
                 hidden_weights: vec![vec![0.0; hidden_size]; input_size],
                 output_weights: vec![vec![0.0; output_size]; hidden_size],
@@ -158,9 +171,14 @@ impl SimpleQNet {
     
     /// Epsilon-greedy action selection
     pub fn select_action(&self, q_values: &[f32]) -> usize {
-        if rand::random::<f32>() < self.exploration_rate {
+        self.select_action_with_rng(q_values, &mut rand::thread_rng())
+    }
+    
+    /// Epsilon-greedy action selection, exploring with `rng`
+    pub fn select_action_with_rng<R: Rng + ?Sized>(&self, q_values: &[f32], rng: &mut R) -> usize {
+        if rng.r#gen::<f32>() < self.exploration_rate {
             // Exploration: random action
-            (rand::random::<f32>() * 3.0) as usize % 3
+            (rng.r#gen::<f32>() * 3.0) as usize % 3
         } else {
             // Exploitation: argmax Q-value
             q_values.iter()
@@ -176,6 +194,29 @@ impl SimpleQNet {
         self.exploration_rate *= decay_rate;
         self.exploration_rate = self.exploration_rate.max(0.01);  // Minimum exploration
     }
+    
+    /// Apply random mutations to all weights, drawing them from `rng`
+    pub fn mutate_with_rng<R: Rng + ?Sized>(&mut self, mutation_rate: f32, rng: &mut R) {
+        // Apply random mutations to hidden weights
+        for row in self.hidden_weights.iter_mut() {
+            for weight in row.iter_mut() {
+                if rng.r#gen::<f32>() < mutation_rate {
+                    *weight += (rng.r#gen::<f32>() - 0.5) * 0.1;
+                }
+            }
+        }
+        
+        // Apply random mutations to output weights
+        for row in self.output_weights.iter_mut() {
+            for weight in row.iter_mut() {
+                if rng.r#gen::<f32>() < mutation_rate {
+                    *weight += (rng.r#gen::<f32>() - 0.5) * 0.1;
+                }
+            }
+        }
+        
+        self.metadata.mutation_count += 1;
+    }
 }
 
 #[async_trait]
@@ -220,25 +261,7 @@ impl MlStrategy for SimpleQNet {
     }
     
     fn mutate(&mut self, mutation_rate: f32, _mutation_strength: f32) -> Result<(), MlError> {
-        // Apply random mutations to hidden weights
-        for row in self.hidden_weights.iter_mut() {
-            for weight in row.iter_mut() {
-                if rand::random::<f32>() < mutation_rate {
-                    *weight += (rand::random::<f32>() - 0.5) * 0.1;
-                }
-            }
-        }
-        
-        // Apply random mutations to output weights
-        for row in self.output_weights.iter_mut() {
-            for weight in row.iter_mut() {
-                if rand::random::<f32>() < mutation_rate {
-                    *weight += (rand::random::<f32>() - 0.5) * 0.1;
-                }
-            }
-        }
-        
-        self.metadata.mutation_count += 1;
+        self.mutate_with_rng(mutation_rate, &mut rand::thread_rng());
         Ok(())
     }
     fn clone_box(&self) -> Box<dyn MlStrategy> {
@@ -272,4 +295,25 @@ mod tests {
         let decision = model.predict(&state).await.expect("Prediction failed");
         assert!(decision.confidence >= 0.0 && decision.confidence <= 1.0);
     }
+    
+    #[test]
+    fn test_seeded_model_is_reproducible() {
+        let mut first = SimpleQNet::with_seed(5, 16, 3).expect("Failed to create model");
+        let mut second = SimpleQNet::with_seed(5, 16, 3).expect("Failed to create model");
+        first.exploration_rate = 0.5;
+        second.exploration_rate = 0.5;
+        
+        let mut first_rng = StdRng::seed_from_u64(9);
+        let mut second_rng = StdRng::seed_from_u64(9);
+        first.mutate_with_rng(0.5, &mut first_rng);
+        second.mutate_with_rng(0.5, &mut second_rng);
+        
+        let state = [0.1, 0.2, 0.3, 0.4, 0.5];
+        assert_eq!(first.forward(&state).unwrap(), second.forward(&state).unwrap());
+        
+        let q_values = first.forward(&state).unwrap();
+        let first_actions: Vec<usize> = (0..20).map(|_| first.select_action_with_rng(&q_values, &mut first_rng)).collect();
+        let second_actions: Vec<usize> = (0..20).map(|_| second.select_action_with_rng(&q_values, &mut second_rng)).collect();
+        assert_eq!(first_actions, second_actions);
+    }
 }
@@ -5,6 +5,8 @@ use crate::finance::ml::models::q_net::SimpleQNet;
 use crate::finance::ml::training::replay_buffer::{ReplayBuffer, Experience};
 use crate::finance::ml::errors::Result;
 use crate::finance::ml::traits::MarketState;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::f32;
 
 /// Configuration for advanced training
@@ -96,10 +98,19 @@ pub struct AdvancedTrainingCoordinator {
 impl AdvancedTrainingCoordinator {
     /// Create new advanced coordinator
     pub fn new(model: SimpleQNet, config: AdvancedTrainingConfig) -> Self {
+        Self::with_trainer(AdvancedQLearningTrainer::new(model), config)
+    }
+    
+    /// Create a coordinator whose episodes and replay sampling are fixed by `seed`
+    pub fn with_seed(model: SimpleQNet, config: AdvancedTrainingConfig, seed: u64) -> Self {
+        Self::with_trainer(AdvancedQLearningTrainer::with_seed(model, seed), config)
+    }
+    
+    fn with_trainer(trainer: AdvancedQLearningTrainer, config: AdvancedTrainingConfig) -> Self {
         let initial_epsilon = config.initial_epsilon;
         
         Self {
-            trainer: AdvancedQLearningTrainer::new(model),
+            trainer,
             config,
             current_episode: 0,
             current_epsilon: initial_epsilon,
@@ -173,7 +184,7 @@ impl AdvancedTrainingCoordinator {
     }
     
     /// Simulate a training episode (placeholder)
-    fn simulate_episode(&self) -> Result<f32> {
+    fn simulate_episode(&mut self) -> Result<f32> {
         // In real implementation, this would:
         // 1. Fetch real market data
         // 2. Run agent through market history
@@ -181,7 +192,7 @@ impl AdvancedTrainingCoordinator {
         // 4. Return total reward
         
         // For now, return simulated reward
-        Ok(rand::random::<f32>() * 100.0 - 50.0)
+        Ok(self.trainer.rng.r#gen::<f32>() * 100.0 - 50.0)
     }
     
     /// Export training metrics to CSV
@@ -214,10 +225,20 @@ pub struct AdvancedQLearningTrainer {
     pub batch_size: usize,
     pub total_loss: f32,
     pub training_steps: u32,
+    rng: StdRng,
 }
 
 impl AdvancedQLearningTrainer {
     pub fn new(model: SimpleQNet) -> Self {
+        Self::with_rng(model, StdRng::from_entropy())
+    }
+    
+    /// Create a trainer whose replay sampling is fixed by `seed`
+    pub fn with_seed(model: SimpleQNet, seed: u64) -> Self {
+        Self::with_rng(model, StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(model: SimpleQNet, rng: StdRng) -> Self {
         Self {
             model,
             replay_buffer: ReplayBuffer::new(10_000),
@@ -226,6 +247,7 @@ impl AdvancedQLearningTrainer {
             batch_size: 32,
             total_loss: 0.0,
             training_steps: 0,
+            rng,
         }
     }
     
@@ -273,7 +295,7 @@ impl AdvancedQLearningTrainer {
             return Ok(0.0);
         }
         
-        let batch = self.replay_buffer.sample(self.batch_size);
+        let batch = self.replay_buffer.sample_with_rng(self.batch_size, &mut self.rng);
         let mut batch_loss = 0.0;
         
         for experience in batch {
@@ -311,7 +333,10 @@ impl AdvancedQLearningTrainer {
     }
     
     /// Clone for offspring
-    pub fn clone_for_spawn(&self) -> Self {
+    ///
+    /// The child's RNG is seeded from this trainer's RNG rather than copied,
+    /// so parent and child do not sample the same replay batches.
+    pub fn clone_for_spawn(&mut self) -> Self {
         Self {
             model: self.model.clone(),
             replay_buffer: ReplayBuffer::new(10_000),
@@ -320,6 +345,7 @@ impl AdvancedQLearningTrainer {
             batch_size: self.batch_size,
             total_loss: 0.0,
             training_steps: 0,
+            rng: StdRng::seed_from_u64(self.rng.r#gen()),
         }
     }
 }
@@ -375,4 +401,24 @@ mod tests {
         assert!(coordinator.current_epsilon < initial);
         assert!(coordinator.current_epsilon >= 0.1);
     }
+    
+    #[test]
+    fn seeded_coordinators_simulate_identical_episodes() {
+        let mut first = AdvancedTrainingCoordinator::with_seed(SimpleQNet::new(5, 64).unwrap(), AdvancedTrainingConfig::default(), 5);
+        let mut second = AdvancedTrainingCoordinator::with_seed(SimpleQNet::new(5, 64).unwrap(), AdvancedTrainingConfig::default(), 5);
+        
+        for _ in 0..10 {
+            assert_eq!(first.simulate_episode().unwrap(), second.simulate_episode().unwrap());
+        }
+    }
+    
+    #[test]
+    fn spawned_trainer_is_reseeded_not_copied() {
+        let mut parent = AdvancedQLearningTrainer::with_seed(SimpleQNet::new(5, 64).unwrap(), 5);
+        let mut child = parent.clone_for_spawn();
+        
+        let parent_draws: Vec<u64> = (0..4).map(|_| parent.rng.r#gen()).collect();
+        let child_draws: Vec<u64> = (0..4).map(|_| child.rng.r#gen()).collect();
+        assert_ne!(parent_draws, child_draws);
+    }
 }
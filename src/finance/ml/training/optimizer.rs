@@ -5,6 +5,8 @@ use crate::finance::ml::models::q_net::SimpleQNet;
 use crate::finance::ml::training::replay_buffer::{ReplayBuffer, Experience};
 use crate::finance::ml::errors::Result;
 use crate::finance::ml::traits::MarketState;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 /// Q-Learning trainer for SimpleQNet
 pub struct QLearningTrainer {
@@ -28,11 +30,26 @@ pub struct QLearningTrainer {
     
     /// Training step counter
     training_steps: u32,
+    
+    /// Source of replay sampling
+    rng: StdRng,
 }
 
 impl QLearningTrainer {
     /// Create new Q-Learning trainer
     pub fn new(model: SimpleQNet) -> Self {
+        Self::with_rng(model, StdRng::from_entropy())
+    }
+    
+    /// Create a trainer whose replay sampling is fixed by `seed`
+    ///
+    /// With a seeded model (`SimpleQNet::with_seed`) the same experiences
+    /// train identically on every run.
+    pub fn with_seed(model: SimpleQNet, seed: u64) -> Self {
+        Self::with_rng(model, StdRng::seed_from_u64(seed))
+    }
+    
+    fn with_rng(model: SimpleQNet, rng: StdRng) -> Self {
         Self {
             model,
             replay_buffer: ReplayBuffer::new(10_000),
@@ -41,6 +58,7 @@ impl QLearningTrainer {
             batch_size: 32,
             total_loss: 0.0,
             training_steps: 0,
+            rng,
         }
     }
     
@@ -89,7 +107,7 @@ impl QLearningTrainer {
             return Ok(0.0);  // Not enough data yet
         }
         
-        let batch = self.replay_buffer.sample(self.batch_size);
+        let batch = self.replay_buffer.sample_with_rng(self.batch_size, &mut self.rng);
         let mut batch_loss = 0.0;
         
         for experience in batch {
@@ -165,6 +183,7 @@ impl Clone for QLearningTrainer {
             batch_size: self.batch_size,
             total_loss: self.total_loss,
             training_steps: self.training_steps,
+            rng: self.rng.clone(),
         }
     }
 }
@@ -218,6 +237,20 @@ mod tests {
         assert_eq!(trainer.replay_buffer.len(), 1);
     }
     
+    #[test]
+    fn test_seeded_training_is_reproducible() {
+        let train = || {
+            let mut trainer = QLearningTrainer::with_seed(SimpleQNet::with_seed(5, 16, 1).unwrap(), 2);
+            for i in 0..64 {
+                let x = i as f32 / 64.0;
+                trainer.remember_experience(vec![x; 5], i % 3, x, vec![x + 0.1; 5], i % 10 == 0);
+            }
+            (0..3).map(|_| trainer.train_step().unwrap()).collect::<Vec<f32>>()
+        };
+        
+        assert_eq!(train(), train());
+    }
+    
     #[test]
     fn test_training_stats() {
         let model = SimpleQNet::new(5, 64).unwrap();
@@ -4,6 +4,7 @@ use crate::finance::ml::traits::MarketState;
 use std::collections::VecDeque;
 use serde::{Serialize, Deserialize};
 use rand::seq::IteratorRandom;
+use rand::Rng;
 
 /// A single training experience
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -37,11 +38,14 @@ impl ReplayBuffer {
     }
     
     pub fn sample(&self, batch_size: usize) -> Vec<Experience> {
-        let mut rng = rand::thread_rng();
-        
+        self.sample_with_rng(batch_size, &mut rand::thread_rng())
+    }
+    
+    /// Sample a batch, choosing experiences with `rng`
+    pub fn sample_with_rng<R: Rng + ?Sized>(&self, batch_size: usize, rng: &mut R) -> Vec<Experience> {
         self.buffer
             .iter()
-            .choose_multiple(&mut rng, batch_size.min(self.buffer.len()))
+            .choose_multiple(rng, batch_size.min(self.buffer.len()))
             .into_iter()
             .cloned()
             .collect()
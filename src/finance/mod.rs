@@ -6,6 +6,7 @@
 //! - Permanent damage from losses (scars)
 //! - Evolutionary spawning of successful agents
 //! - Trust scoring based on performance history
+//! - Trust grant drops that permanently revoke capabilities and permissions
 //! - Multi-agent competitive simulations
 //!
 //! ## Core Philosophy
@@ -25,6 +26,7 @@ pub mod metrics;
 pub mod scars;
 pub mod spawning;
 pub mod trust_scoring;
+pub mod trust_bridge;
 pub mod arena;
 pub mod advanced;
 pub mod market_data;
@@ -42,7 +44,8 @@ pub use traits::{
 pub use trade::{Trade, TradeOperation, TradeResult, TradeDirection, ExecutionError};
 pub use scars::{FinancialScar, ScarImpact, FinancialDamage};
 pub use spawning::{Offspring, OffspringTraits, InheritanceStrategy};
-pub use trust_scoring::{PerformanceScore, TrustFormula, TrustGrant};
+pub use trust_scoring::{PerformanceScore, TrustFormula, TrustGrant, TrustPermission, TrustRecord};
+pub use trust_bridge::{TrustBridge, GrantDowngrade};
 pub use arena::{Arena, CompetitionResult, MarketState};
 pub use advanced::{BlockchainHook, EvolutionaryStrategy, GovernanceVote, ResurrectionMechanic, ResurrectionRecord};
 pub use market_data::{MarketDataClient, PriceData, MarketDataConfig, MarketDataError, CircuitBreakerState};
@@ -8,6 +8,7 @@
 
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use crate::finance::agent::AgentId;
 
 /// Strategy parameters that can be inherited
@@ -38,9 +39,24 @@ impl OffspringTraits {
         parent_cost_multiplier: f32,
         parent_win_rate: f32,
         mutation_rate: f32,
+    ) -> Self {
+        Self::inherit_from_parent_with_rng(
+            parent_cost_multiplier,
+            parent_win_rate,
+            mutation_rate,
+            &mut rand::thread_rng(),
+        )
+    }
+    
+    /// Create offspring traits, drawing the mutation from `rng`
+    pub fn inherit_from_parent_with_rng<R: Rng + ?Sized>(
+        parent_cost_multiplier: f32,
+        parent_win_rate: f32,
+        mutation_rate: f32,
+        rng: &mut R,
     ) -> Self {
         // Mutation affects inherited traits slightly
-        let mutation = (rand::random::<f32>() - 0.5) * mutation_rate;
+        let mutation = (rng.r#gen::<f32>() - 0.5) * mutation_rate;
         
         OffspringTraits {
             inherited_cost_multiplier: parent_cost_multiplier,
@@ -54,7 +70,12 @@ impl OffspringTraits {
     
     /// Apply mutation to create variation
     pub fn mutate(&mut self) {
-        let mutation = (rand::random::<f32>() - 0.5) * self.mutation_rate;
+        self.mutate_with_rng(&mut rand::thread_rng());
+    }
+    
+    /// Apply mutation drawn from `rng`
+    pub fn mutate_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mutation = (rng.r#gen::<f32>() - 0.5) * self.mutation_rate;
         self.risk_tolerance = (self.risk_tolerance + mutation).clamp(0.0, 1.0);
         self.aggressiveness = (self.aggressiveness + mutation).clamp(0.0, 1.0);
     }
@@ -291,6 +312,22 @@ mod tests {
         assert!(traits.risk_tolerance <= 1.0);
     }
 
+    #[test]
+    fn test_seeded_inheritance_is_reproducible() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut first_rng = StdRng::seed_from_u64(42);
+        let mut second_rng = StdRng::seed_from_u64(42);
+        let mut first = OffspringTraits::inherit_from_parent_with_rng(1.05, 60.0, 0.3, &mut first_rng);
+        let mut second = OffspringTraits::inherit_from_parent_with_rng(1.05, 60.0, 0.3, &mut second_rng);
+        first.mutate_with_rng(&mut first_rng);
+        second.mutate_with_rng(&mut second_rng);
+
+        assert_eq!(first.risk_tolerance, second.risk_tolerance);
+        assert_eq!(first.aggressiveness, second.aggressiveness);
+        assert_eq!(first.diversification, second.diversification);
+    }
+
     #[test]
     fn test_spawn_validation() {
         let req = SpawningRequirement::default();
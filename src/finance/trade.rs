@@ -9,6 +9,8 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+use super::trust_scoring::TrustPermission;
+
 /// Direction of a trade execution
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TradeDirection {
@@ -32,6 +34,9 @@ pub enum ExecutionError {
     
     /// Agent capacity exceeded
     CapacityExceeded { reason: String },
+    
+    /// Trade needs a trust permission the agent has lost
+    PermissionRevoked { permission: TrustPermission },
 }
 
 /// Result of trade execution
@@ -0,0 +1,229 @@
+//! Trust Bridge - Score-based grants with capability-based consequences
+//!
+//! Connects the crate's two trust systems:
+//! - A `TrustRecord` grant drop (e.g. Silver to Restricted) permanently
+//!   revokes the mapped `TrustCapability`s on a `TrustedActor`
+//! - The `TrustPermission`s lost in the drop are revoked on the
+//!   `FinanceAgent` and enforced by its trade checks
+//!
+//! Only drops have consequences. A recovering score raises the grant
+//! again, but nothing revoked by an earlier drop comes back.
+
+use crate::trust::{TrustCapability, TrustedActor};
+use super::agent::FinanceAgent;
+use super::trust_scoring::{TrustFormula, TrustGrant, TrustPermission, TrustRecord};
+
+/// Capabilities an actor keeps at each grant tier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustBridge {
+    /// Capabilities kept at Gold
+    pub gold: Vec<TrustCapability>,
+
+    /// Capabilities kept at Silver
+    pub silver: Vec<TrustCapability>,
+
+    /// Capabilities kept at Bronze
+    pub bronze: Vec<TrustCapability>,
+
+    /// Capabilities kept at Restricted
+    pub restricted: Vec<TrustCapability>,
+}
+
+impl Default for TrustBridge {
+    fn default() -> Self {
+        let mut gold: Vec<TrustCapability> = TrustCapability::full_set().into_iter().collect();
+        gold.sort();
+
+        TrustBridge {
+            gold,
+            silver: vec![
+                TrustCapability::Execute,
+                TrustCapability::Validate,
+                TrustCapability::Delegate,
+                TrustCapability::Audit,
+            ],
+            bronze: vec![
+                TrustCapability::Execute,
+                TrustCapability::Audit,
+            ],
+            restricted: vec![TrustCapability::Audit],
+        }
+    }
+}
+
+impl TrustBridge {
+    /// Capabilities kept at `grant`'s tier
+    pub fn capabilities_for(&self, grant: &TrustGrant) -> &[TrustCapability] {
+        match grant {
+            TrustGrant::Gold { .. } => &self.gold,
+            TrustGrant::Silver { .. } => &self.silver,
+            TrustGrant::Bronze { .. } => &self.bronze,
+            TrustGrant::Restricted { .. } => &self.restricted,
+        }
+    }
+
+    /// What moving from `previous` to `current` takes away
+    ///
+    /// Returns `None` unless `current` is a lower tier than `previous`.
+    pub fn downgrade(&self, previous: &TrustGrant, current: &TrustGrant) -> Option<GrantDowngrade> {
+        if current.rank() >= previous.rank() {
+            return None;
+        }
+
+        let kept = self.capabilities_for(current);
+        let mut capabilities: Vec<TrustCapability> = self
+            .capabilities_for(previous)
+            .iter()
+            .copied()
+            .filter(|cap| !kept.contains(cap))
+            .collect();
+        capabilities.sort();
+        capabilities.dedup();
+
+        let kept = current.permissions();
+        let permissions = previous
+            .permissions()
+            .into_iter()
+            .filter(|permission| !kept.contains(permission))
+            .collect();
+
+        Some(GrantDowngrade {
+            from: previous.clone(),
+            to: current.clone(),
+            capabilities,
+            permissions,
+        })
+    }
+
+    /// Update the record's score, returning the downgrade if the grant dropped
+    pub fn update_score(
+        &self,
+        record: &mut TrustRecord,
+        new_score: f32,
+        formula: &TrustFormula,
+    ) -> Option<GrantDowngrade> {
+        let previous = record.grant.clone();
+        record.update_score(new_score, formula);
+        self.downgrade(&previous, &record.grant)
+    }
+}
+
+/// Everything a grant drop takes away
+#[derive(Debug, Clone)]
+pub struct GrantDowngrade {
+    /// Grant before the drop
+    pub from: TrustGrant,
+
+    /// Grant after the drop
+    pub to: TrustGrant,
+
+    /// Capabilities to revoke on the actor
+    pub capabilities: Vec<TrustCapability>,
+
+    /// Permissions to revoke on the finance agent
+    pub permissions: Vec<TrustPermission>,
+}
+
+impl GrantDowngrade {
+    /// Reason recorded alongside every revocation
+    pub fn reason(&self) -> String {
+        format!("Trust grant dropped from {} to {}", describe(&self.from), describe(&self.to))
+    }
+
+    /// Permanently revoke the lost capabilities (cascades to delegates)
+    ///
+    /// Returns the capabilities the actor still held in own right.
+    pub fn apply_to_actor(&self, actor: &mut TrustedActor) -> Vec<TrustCapability> {
+        actor.revoke_capabilities(&self.capabilities, &self.reason())
+    }
+
+    /// Permanently revoke the lost permissions on a finance agent
+    ///
+    /// Returns the permissions the agent had not already lost.
+    pub fn apply_to_agent(&self, agent: &mut FinanceAgent) -> Vec<TrustPermission> {
+        agent.revoke_permissions(&self.permissions, &self.reason())
+    }
+}
+
+fn describe(grant: &TrustGrant) -> String {
+    match grant {
+        TrustGrant::Gold { score } => format!("Gold ({:.2})", score),
+        TrustGrant::Silver { score } => format!("Silver ({:.2})", score),
+        TrustGrant::Bronze { score } => format!("Bronze ({:.2})", score),
+        TrustGrant::Restricted { score, .. } => format!("Restricted ({:.2})", score),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finance::trade::{ExecutionError, Trade, TradeDirection, TradeOperation};
+
+    fn leveraged_trade(leverage: f32) -> TradeOperation {
+        TradeOperation {
+            trade: Trade::new(1, TradeDirection::Buy, "BTC".to_string(), 1, 100.0, leverage, 0.1),
+            fee_cost: 0,
+            capital_requirement: 100,
+        }
+    }
+
+    #[test]
+    fn grant_drop_revokes_capabilities_and_permissions_permanently() {
+        let bridge = TrustBridge::default();
+        let formula = TrustFormula::default();
+        let mut record = TrustRecord::new("agent-1".to_string(), 65.0, &formula);
+        let mut actor = TrustedActor::create(1000);
+        let mut agent = FinanceAgent::new("Momentum".to_string(), 10000, 0);
+        assert!(agent.check_trade(&leveraged_trade(2.0), 5.0).is_ok());
+
+        let downgrade = bridge.update_score(&mut record, 30.0, &formula).unwrap();
+        assert_eq!(
+            downgrade.apply_to_actor(&mut actor),
+            vec![TrustCapability::Execute, TrustCapability::Validate, TrustCapability::Delegate]
+        );
+        assert_eq!(
+            downgrade.apply_to_agent(&mut agent),
+            vec![TrustPermission::MarginTrading, TrustPermission::Spawning, TrustPermission::Competition]
+        );
+
+        assert!(!actor.has_capability(TrustCapability::Execute));
+        assert!(actor.has_capability(TrustCapability::Audit));
+        assert!(matches!(
+            agent.check_trade(&leveraged_trade(2.0), 5.0),
+            Err(ExecutionError::PermissionRevoked { permission: TrustPermission::MarginTrading })
+        ));
+        assert!(agent.check_trade(&leveraged_trade(1.0), 5.0).is_ok());
+
+        // Recovering to Gold is not a downgrade and restores nothing
+        assert!(bridge.update_score(&mut record, 90.0, &formula).is_none());
+        assert!(record.has_permission(TrustPermission::MarginTrading));
+        assert!(!agent.has_permission(TrustPermission::MarginTrading));
+        assert!(!actor.has_capability(TrustCapability::Execute));
+    }
+
+    #[test]
+    fn only_the_difference_between_tiers_is_revoked() {
+        let bridge = TrustBridge::default();
+        let gold = TrustFormula::determine_grant(85.0);
+        let silver = TrustFormula::determine_grant(65.0);
+
+        assert!(bridge.downgrade(&silver, &gold).is_none());
+        assert!(bridge.downgrade(&silver, &silver).is_none());
+
+        let downgrade = bridge.downgrade(&gold, &silver).unwrap();
+        assert_eq!(
+            downgrade.capabilities,
+            vec![TrustCapability::Configure, TrustCapability::AccessSensitive]
+        );
+        assert_eq!(
+            downgrade.permissions,
+            vec![
+                TrustPermission::PremiumDataFeed,
+                TrustPermission::PrivatePoolAccess,
+                TrustPermission::GovernanceVoting,
+                TrustPermission::DerivativeTrading,
+            ]
+        );
+        assert_eq!(downgrade.reason(), "Trust grant dropped from Gold (85.00) to Silver (65.00)");
+    }
+}
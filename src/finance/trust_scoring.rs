@@ -101,6 +101,42 @@ pub enum TrustGrant {
     Restricted { score: f32, reason: String },
 }
 
+impl TrustGrant {
+    /// Permissions granted at this tier
+    pub fn permissions(&self) -> Vec<TrustPermission> {
+        match self {
+            TrustGrant::Gold { .. } => vec![
+                TrustPermission::PremiumDataFeed,
+                TrustPermission::MarginTrading,
+                TrustPermission::PrivatePoolAccess,
+                TrustPermission::GovernanceVoting,
+                TrustPermission::Spawning,
+                TrustPermission::Competition,
+                TrustPermission::DerivativeTrading,
+            ],
+            TrustGrant::Silver { .. } => vec![
+                TrustPermission::MarginTrading,
+                TrustPermission::Spawning,
+                TrustPermission::Competition,
+            ],
+            TrustGrant::Bronze { .. } => vec![
+                TrustPermission::Competition,
+            ],
+            TrustGrant::Restricted { .. } => vec![],
+        }
+    }
+    
+    /// Tier rank, from 0 (Restricted) to 3 (Gold)
+    pub fn rank(&self) -> u8 {
+        match self {
+            TrustGrant::Gold { .. } => 3,
+            TrustGrant::Silver { .. } => 2,
+            TrustGrant::Bronze { .. } => 1,
+            TrustGrant::Restricted { .. } => 0,
+        }
+    }
+}
+
 /// Trust formula for computing final trust score
 pub struct TrustFormula {
     /// Weight for performance history (0-1)
@@ -254,26 +290,7 @@ impl TrustRecord {
         let grant = TrustFormula::determine_grant(initial_score);
         let trust_hash = TrustFormula::compute_trust_hash(&agent_id, initial_score, 0);
         
-        let permissions = match &grant {
-            TrustGrant::Gold { .. } => vec![
-                TrustPermission::PremiumDataFeed,
-                TrustPermission::MarginTrading,
-                TrustPermission::PrivatePoolAccess,
-                TrustPermission::GovernanceVoting,
-                TrustPermission::Spawning,
-                TrustPermission::Competition,
-                TrustPermission::DerivativeTrading,
-            ],
-            TrustGrant::Silver { .. } => vec![
-                TrustPermission::MarginTrading,
-                TrustPermission::Spawning,
-                TrustPermission::Competition,
-            ],
-            TrustGrant::Bronze { .. } => vec![
-                TrustPermission::Competition,
-            ],
-            TrustGrant::Restricted { .. } => vec![],
-        };
+        let permissions = grant.permissions();
         
         TrustRecord {
             agent_id,
@@ -298,26 +315,7 @@ impl TrustRecord {
         );
         
         // Update permissions based on new grant
-        self.permissions = match &self.grant {
-            TrustGrant::Gold { .. } => vec![
-                TrustPermission::PremiumDataFeed,
-                TrustPermission::MarginTrading,
-                TrustPermission::PrivatePoolAccess,
-                TrustPermission::GovernanceVoting,
-                TrustPermission::Spawning,
-                TrustPermission::Competition,
-                TrustPermission::DerivativeTrading,
-            ],
-            TrustGrant::Silver { .. } => vec![
-                TrustPermission::MarginTrading,
-                TrustPermission::Spawning,
-                TrustPermission::Competition,
-            ],
-            TrustGrant::Bronze { .. } => vec![
-                TrustPermission::Competition,
-            ],
-            TrustGrant::Restricted { .. } => vec![],
-        };
+        self.permissions = self.grant.permissions();
     }
     
     /// Check if agent has permission
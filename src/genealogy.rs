@@ -0,0 +1,465 @@
+//! # Genealogy System
+//!
+//! The family forest of agents, built from `ParentageRecord` links.
+//!
+//! ## What This Enforces
+//! - Every member is known by its identity ID, once
+//! - The dead (tombstones) take precedence over any living claim to an ID
+//! - Ancestor and descendant walks terminate, even over forged cyclic links
+//!
+//! ## What This Forbids
+//! - Editing parentage after the fact (the forest is rebuilt, never patched)
+//! - Reviving a dead member by re-adding it as living
+//!
+//! ## Exports
+//! - Graphviz DOT (`to_dot`): one node per member, one edge per parent link
+//! - JSON (`to_json`): nested trees rooted at founders, plus orphan IDs
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::agent::TaskAgent;
+use crate::graveyard::{Graveyard, Tombstone, TombstoneSummary};
+
+/// Whether a family member is still alive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberStatus {
+    Alive,
+    Dead,
+}
+
+/// One agent in the family forest
+#[derive(Debug, Clone, Serialize)]
+pub struct FamilyMember {
+    /// Agent's unique identifier
+    pub id: String,
+    /// ID of the parent agent, if spawned
+    pub parent_id: Option<String>,
+    /// Generation number (0 = origin)
+    pub generation: u32,
+    /// Alive or buried
+    pub status: MemberStatus,
+    /// Legacy score at burial (dead members only)
+    pub legacy_score: Option<f64>,
+    /// Cause of death (dead members only)
+    pub cause_of_death: Option<String>,
+}
+
+/// Nested JSON form of one subtree
+#[derive(Debug, Serialize)]
+struct FamilyNode<'a> {
+    #[serde(flatten)]
+    member: &'a FamilyMember,
+    children: Vec<FamilyNode<'a>>,
+}
+
+/// JSON form of the whole forest
+#[derive(Debug, Serialize)]
+struct FamilyForest<'a> {
+    members: usize,
+    roots: Vec<FamilyNode<'a>>,
+    orphans: Vec<&'a str>,
+}
+
+/// The family forest across the graveyard and (optionally) living agents
+#[derive(Debug, Clone, Default)]
+pub struct Genealogy {
+    members: BTreeMap<String, FamilyMember>,
+    children: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Genealogy {
+    /// Create an empty forest
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the forest from tombstones
+    pub fn from_tombstones<'a>(tombstones: impl IntoIterator<Item = &'a Tombstone>) -> Self {
+        let mut genealogy = Self::new();
+        for tombstone in tombstones {
+            genealogy.add_tombstone(tombstone);
+        }
+        genealogy
+    }
+
+    /// Build the forest from a graveyard's index (no tombstone reads)
+    pub fn from_graveyard(graveyard: &Graveyard) -> Self {
+        let mut genealogy = Self::new();
+        for summary in graveyard.query().summaries() {
+            genealogy.add_summary(&summary);
+        }
+        genealogy
+    }
+
+    /// Add a buried agent
+    pub fn add_tombstone(&mut self, tombstone: &Tombstone) {
+        self.add_summary(&TombstoneSummary::from_tombstone(tombstone));
+    }
+
+    /// Add a buried agent from its index summary
+    pub fn add_summary(&mut self, summary: &TombstoneSummary) {
+        self.insert(FamilyMember {
+            id: summary.id.clone(),
+            parent_id: summary.parent_id.clone(),
+            generation: summary.generation,
+            status: MemberStatus::Dead,
+            legacy_score: Some(summary.legacy_score),
+            cause_of_death: Some(summary.cause_of_death.clone()),
+        });
+    }
+
+    /// Add a living agent.
+    ///
+    /// Ignored if the ID is already buried: the dead cannot be revived.
+    pub fn add_living(&mut self, id: impl Into<String>, parent_id: Option<String>, generation: u32) {
+        let id = id.into();
+        if self
+            .members
+            .get(&id)
+            .is_some_and(|member| member.status == MemberStatus::Dead)
+        {
+            return;
+        }
+
+        self.insert(FamilyMember {
+            id,
+            parent_id,
+            generation,
+            status: MemberStatus::Alive,
+            legacy_score: None,
+            cause_of_death: None,
+        });
+    }
+
+    /// Add a living task agent with its recorded parentage
+    pub fn add_agent(&mut self, agent: &TaskAgent) {
+        if agent.is_alive() {
+            self.add_living(
+                agent.identity().id(),
+                agent.parent_id().map(str::to_string),
+                agent.generation(),
+            );
+        }
+    }
+
+    fn insert(&mut self, member: FamilyMember) {
+        if let Some(previous) = self.members.get(&member.id)
+            && let Some(parent_id) = &previous.parent_id
+            && let Some(siblings) = self.children.get_mut(parent_id)
+        {
+            siblings.remove(&member.id);
+        }
+        if let Some(parent_id) = &member.parent_id {
+            self.children
+                .entry(parent_id.clone())
+                .or_default()
+                .insert(member.id.clone());
+        }
+        self.members.insert(member.id.clone(), member);
+    }
+
+    /// Number of members
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// True if the forest is empty
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Get one member
+    pub fn member(&self, id: &str) -> Option<&FamilyMember> {
+        self.members.get(id)
+    }
+
+    /// Get a member's parent, if it is part of the forest
+    pub fn parent(&self, id: &str) -> Option<&FamilyMember> {
+        self.members
+            .get(id)?
+            .parent_id
+            .as_deref()
+            .and_then(|parent_id| self.members.get(parent_id))
+    }
+
+    /// Get a member's direct children
+    pub fn children(&self, id: &str) -> Vec<&FamilyMember> {
+        self.children
+            .get(id)
+            .map(|ids| ids.iter().filter_map(|child| self.members.get(child)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Ancestor chain of a member, nearest (parent) first
+    pub fn ancestors(&self, id: &str) -> Vec<&FamilyMember> {
+        let mut chain = Vec::new();
+        let mut seen = HashSet::from([id]);
+        let mut current = id;
+
+        while let Some(parent) = self.parent(current) {
+            if !seen.insert(parent.id.as_str()) {
+                break; // Forged cycle
+            }
+            chain.push(parent);
+            current = &parent.id;
+        }
+
+        chain
+    }
+
+    /// Earliest known ancestor of a member (the member itself if it has none)
+    pub fn founder(&self, id: &str) -> Option<&FamilyMember> {
+        self.ancestors(id).last().copied().or_else(|| self.member(id))
+    }
+
+    /// Every descendant of a member, breadth first
+    pub fn descendants(&self, id: &str) -> Vec<&FamilyMember> {
+        let mut found = Vec::new();
+        let mut seen = HashSet::from([id]);
+        let mut queue = VecDeque::from([id]);
+
+        while let Some(current) = queue.pop_front() {
+            for child in self.children(current) {
+                if seen.insert(child.id.as_str()) {
+                    found.push(child);
+                    queue.push_back(&child.id);
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Size of a member's dynasty: the member plus all its descendants
+    pub fn dynasty_size(&self, id: &str) -> usize {
+        if self.members.contains_key(id) {
+            1 + self.descendants(id).len()
+        } else {
+            0
+        }
+    }
+
+    /// Members with no known parent in the forest (founders and orphans)
+    pub fn roots(&self) -> Vec<&FamilyMember> {
+        self.members
+            .values()
+            .filter(|member| self.parent(&member.id).is_none())
+            .collect()
+    }
+
+    /// Members whose recorded parent is missing from the forest
+    pub fn orphans(&self) -> Vec<&FamilyMember> {
+        self.members
+            .values()
+            .filter(|member| member.parent_id.is_some() && self.parent(&member.id).is_none())
+            .collect()
+    }
+
+    /// The root whose dynasty is largest, with its size (ties go to the lowest ID)
+    pub fn most_prolific(&self) -> Option<(&FamilyMember, usize)> {
+        self.roots()
+            .into_iter()
+            .map(|root| (root, self.dynasty_size(&root.id)))
+            .fold(None, |best, candidate| match best {
+                Some((_, size)) if size >= candidate.1 => best,
+                _ => Some(candidate),
+            })
+    }
+
+    /// Export the forest as Graphviz DOT
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph genealogy {\n    rankdir=TB;\n    node [shape=box, style=filled];\n");
+
+        for member in self.members.values() {
+            let color = match member.status {
+                MemberStatus::Alive => "palegreen",
+                MemberStatus::Dead => "lightgray",
+            };
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\ngen {}\", fillcolor={}];\n",
+                escape_dot(&member.id),
+                escape_dot(short_id(&member.id)),
+                member.generation,
+                color
+            ));
+        }
+
+        for (parent_id, children) in &self.children {
+            if !self.members.contains_key(parent_id) {
+                continue;
+            }
+            for child in children {
+                dot.push_str(&format!(
+                    "    \"{}\" -> \"{}\";\n",
+                    escape_dot(parent_id),
+                    escape_dot(child)
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Export the forest as nested JSON trees
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let mut seen = HashSet::new();
+        let roots = self
+            .roots()
+            .into_iter()
+            .map(|root| self.node(root, &mut seen))
+            .collect();
+
+        let forest = FamilyForest {
+            members: self.members.len(),
+            roots,
+            orphans: self.orphans().into_iter().map(|member| member.id.as_str()).collect(),
+        };
+        serde_json::to_string_pretty(&forest)
+    }
+
+    fn node<'a>(&'a self, member: &'a FamilyMember, seen: &mut HashSet<&'a str>) -> FamilyNode<'a> {
+        seen.insert(&member.id);
+        let mut children = Vec::new();
+        for child in self.children(&member.id) {
+            if !seen.contains(child.id.as_str()) {
+                children.push(self.node(child, seen));
+            }
+        }
+
+        FamilyNode { member, children }
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn buried(id: &str, parent_id: Option<&str>, generation: u32) -> TombstoneSummary {
+        TombstoneSummary {
+            id: id.to_string(),
+            cause_of_death: "Energy depletion".to_string(),
+            cause_severity: None,
+            generation,
+            parent_id: parent_id.map(str::to_string),
+            creation_time: Utc::now(),
+            death_timestamp: Utc::now(),
+            scar_count: 0,
+            efficiency_ratio: 0.0,
+            legacy_score: 0.0,
+        }
+    }
+
+    /// root-a -> child-1 -> grandchild, root-a -> child-2 (alive), root-b
+    fn family() -> Genealogy {
+        let mut genealogy = Genealogy::new();
+        genealogy.add_summary(&buried("root-a", None, 0));
+        genealogy.add_summary(&buried("child-1", Some("root-a"), 1));
+        genealogy.add_summary(&buried("grandchild", Some("child-1"), 2));
+        genealogy.add_living("child-2", Some("root-a".to_string()), 1);
+        genealogy.add_summary(&buried("root-b", None, 0));
+        genealogy
+    }
+
+    #[test]
+    fn walks_ancestors_and_descendants() {
+        let genealogy = family();
+
+        let ancestors: Vec<&str> = genealogy
+            .ancestors("grandchild")
+            .iter()
+            .map(|member| member.id.as_str())
+            .collect();
+        assert_eq!(ancestors, vec!["child-1", "root-a"]);
+        assert_eq!(genealogy.founder("grandchild").unwrap().id, "root-a");
+
+        let descendants: Vec<&str> = genealogy
+            .descendants("root-a")
+            .iter()
+            .map(|member| member.id.as_str())
+            .collect();
+        assert_eq!(descendants, vec!["child-1", "child-2", "grandchild"]);
+
+        assert_eq!(genealogy.dynasty_size("root-a"), 4);
+        assert_eq!(genealogy.dynasty_size("root-b"), 1);
+        assert_eq!(genealogy.dynasty_size("unknown"), 0);
+    }
+
+    #[test]
+    fn finds_most_prolific_lineage() {
+        let genealogy = family();
+        let (founder, size) = genealogy.most_prolific().unwrap();
+
+        assert_eq!(founder.id, "root-a");
+        assert_eq!(size, 4);
+    }
+
+    #[test]
+    fn living_claim_cannot_revive_the_dead() {
+        let mut genealogy = family();
+        genealogy.add_living("child-1", None, 0);
+
+        let member = genealogy.member("child-1").unwrap();
+        assert_eq!(member.status, MemberStatus::Dead);
+        assert_eq!(member.parent_id.as_deref(), Some("root-a"));
+    }
+
+    #[test]
+    fn burial_replaces_living_entry() {
+        let mut genealogy = family();
+        genealogy.add_summary(&buried("child-2", Some("root-a"), 1));
+
+        assert_eq!(genealogy.member("child-2").unwrap().status, MemberStatus::Dead);
+        assert_eq!(genealogy.children("root-a").len(), 2);
+    }
+
+    #[test]
+    fn forged_cycle_terminates() {
+        let mut genealogy = Genealogy::new();
+        genealogy.add_summary(&buried("a", Some("b"), 1));
+        genealogy.add_summary(&buried("b", Some("a"), 1));
+
+        assert_eq!(genealogy.ancestors("a").len(), 1);
+        assert_eq!(genealogy.descendants("a").len(), 1);
+        assert!(genealogy.roots().is_empty());
+    }
+
+    #[test]
+    fn orphans_are_roots() {
+        let mut genealogy = Genealogy::new();
+        genealogy.add_summary(&buried("lost", Some("never-buried"), 3));
+
+        assert_eq!(genealogy.orphans().len(), 1);
+        assert_eq!(genealogy.roots().len(), 1);
+    }
+
+    #[test]
+    fn exports_dot_and_json() {
+        let genealogy = family();
+
+        let dot = genealogy.to_dot();
+        assert!(dot.starts_with("digraph genealogy {"));
+        assert!(dot.contains("\"root-a\" -> \"child-1\";"));
+        assert!(dot.contains("\"child-1\" -> \"grandchild\";"));
+        assert!(dot.contains("fillcolor=palegreen"));
+
+        let json: serde_json::Value = serde_json::from_str(&genealogy.to_json().unwrap()).unwrap();
+        assert_eq!(json["members"], 5);
+        assert_eq!(json["roots"].as_array().unwrap().len(), 2);
+        assert_eq!(json["roots"][0]["id"], "root-a");
+        assert_eq!(json["roots"][0]["children"][0]["children"][0]["id"], "grandchild");
+        assert_eq!(json["roots"][0]["children"][1]["status"], "alive");
+    }
+}
@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
-use chrono::Utc;
-
 use crate::agent::{Task, TaskAgent, TaskOutcome, TaskResult};
+use crate::clock::{self, SharedClock};
 use crate::governance::ledger::{GovernanceEvent, GovernanceLedger};
 use crate::governance::proposal::{Proposal, ProposalId, ProposalOutcome, ProposalRisk, ProposalStatus};
 use crate::governance::vote::{VoteChoice, VoteRecord, VoteReceipt};
+use crate::scar::ScarCategory;
+use crate::store::StoreError;
 
 #[derive(Debug, Clone)]
 pub struct GovernanceConfig {
@@ -31,6 +32,7 @@ pub struct GovernanceCouncil {
     members: HashMap<String, CouncilMember>,
     proposals: HashMap<ProposalId, Proposal>,
     ledger: GovernanceLedger,
+    clock: SharedClock,
 }
 
 struct CouncilMember {
@@ -41,11 +43,26 @@ struct CouncilMember {
 
 impl GovernanceCouncil {
     pub fn new(config: GovernanceConfig) -> Self {
+        Self::with_ledger(config, GovernanceLedger::new())
+    }
+
+    /// Create a council recording into `ledger` (e.g. one opened with a store).
+    ///
+    /// Prior ledger events are kept as history; members and proposals are
+    /// not replayed from them.
+    pub fn with_ledger(config: GovernanceConfig, ledger: GovernanceLedger) -> Self {
+        Self::with_clock(config, ledger, clock::system())
+    }
+
+    /// Create a council whose members, proposals and ledger events read
+    /// time from `clock`.
+    pub fn with_clock(config: GovernanceConfig, ledger: GovernanceLedger, clock: SharedClock) -> Self {
         Self {
             config,
             members: HashMap::new(),
             proposals: HashMap::new(),
-            ledger: GovernanceLedger::new(),
+            ledger,
+            clock,
         }
     }
 
@@ -77,24 +94,37 @@ impl GovernanceCouncil {
         self.members.get(member_id).map(|m| m.agent.is_alive())
     }
 
+    /// Add a member.
+    ///
+    /// Panics if the ledger's store rejects the event; see `try_add_member`.
+    #[deprecated(note = "panics on ledger storage errors; use `try_add_member`")]
     pub fn add_member(&mut self, name: String, initial_energy: u64) -> String {
-        let agent = TaskAgent::create(initial_energy);
+        self.try_add_member(name, initial_energy)
+            .unwrap_or_else(|e| panic!("Governance ledger write failed: {}", e))
+    }
+
+    /// Add a member, failing if the ledger cannot record it.
+    pub fn try_add_member(&mut self, name: String, initial_energy: u64) -> Result<String, GovernanceError> {
+        let agent = TaskAgent::create_with_clock(initial_energy, self.clock.clone());
         let member_id = agent.identity().id().to_string();
+
+        self.ledger
+            .append(GovernanceEvent::MemberAdded {
+                member_id: member_id.clone(),
+                name: name.clone(),
+                energy: initial_energy,
+                timestamp: self.clock.now(),
+            })
+            .map_err(storage_error)?;
+
         let member = CouncilMember {
-            name: name.clone(),
+            name,
             agent,
             buried: false,
         };
-
         self.members.insert(member_id.clone(), member);
-        self.ledger.append(GovernanceEvent::MemberAdded {
-            member_id: member_id.clone(),
-            name,
-            energy: initial_energy,
-            timestamp: Utc::now(),
-        });
 
-        member_id
+        Ok(member_id)
     }
 
     /// Bury any dead members in the graveyard.
@@ -120,24 +150,41 @@ impl GovernanceCouncil {
         buried
     }
 
+    /// Open a proposal.
+    ///
+    /// Panics if the ledger's store rejects the event; see `try_propose`.
+    #[deprecated(note = "panics on ledger storage errors; use `try_propose`")]
     pub fn propose(
         &mut self,
         title: String,
         risk: ProposalRisk,
         voting_window_secs: i64,
     ) -> ProposalId {
-        let proposal = Proposal::new(title.clone(), risk, voting_window_secs);
+        self.try_propose(title, risk, voting_window_secs)
+            .unwrap_or_else(|e| panic!("Governance ledger write failed: {}", e))
+    }
+
+    /// Open a proposal, failing if the ledger cannot record it.
+    pub fn try_propose(
+        &mut self,
+        title: String,
+        risk: ProposalRisk,
+        voting_window_secs: i64,
+    ) -> Result<ProposalId, GovernanceError> {
+        let proposal = Proposal::new_at(title.clone(), risk, voting_window_secs, self.clock.now());
         let proposal_id = proposal.id().clone();
 
+        self.ledger
+            .append(GovernanceEvent::ProposalCreated {
+                proposal_id: proposal_id.clone(),
+                title,
+                risk,
+                timestamp: self.clock.now(),
+            })
+            .map_err(storage_error)?;
         self.proposals.insert(proposal_id.clone(), proposal);
-        self.ledger.append(GovernanceEvent::ProposalCreated {
-            proposal_id: proposal_id.clone(),
-            title,
-            risk,
-            timestamp: Utc::now(),
-        });
 
-        proposal_id
+        Ok(proposal_id)
     }
 
     pub fn vote(
@@ -183,7 +230,7 @@ impl GovernanceCouncil {
                 TaskResult::InsufficientEnergy { required, available } => {
                     return Err(GovernanceError::InsufficientEnergy { required, available });
                 }
-                TaskResult::AgentTerminated => {
+                TaskResult::AgentTerminated | TaskResult::Terminated { .. } => {
                     return Err(GovernanceError::MemberTerminated);
                 }
                 TaskResult::CapacityInsufficient { reason } => {
@@ -195,7 +242,7 @@ impl GovernanceCouncil {
             }
         }
 
-        let timestamp = Utc::now();
+        let timestamp = self.clock.now();
         let record = VoteRecord {
             member_id: member_id.to_string(),
             choice,
@@ -203,18 +250,22 @@ impl GovernanceCouncil {
             timestamp,
         };
 
+        // The vote only counts once the ledger holds it; energy already
+        // burned stays burned if the write fails
+        self.ledger
+            .append(GovernanceEvent::VoteCast {
+                proposal_id: proposal_id.clone(),
+                member_id: member_id.to_string(),
+                choice,
+                energy_cost,
+                timestamp,
+            })
+            .map_err(storage_error)?;
+
         if let Some(proposal) = self.proposals.get_mut(&proposal_id) {
-            proposal.record_vote(record.clone());
+            proposal.record_vote(record);
         }
 
-        self.ledger.append(GovernanceEvent::VoteCast {
-            proposal_id: proposal_id.clone(),
-            member_id: member_id.to_string(),
-            choice,
-            energy_cost,
-            timestamp,
-        });
-
         Ok(VoteReceipt {
             proposal_id: proposal_id.as_str().to_string(),
             member_id: member_id.to_string(),
@@ -298,15 +349,17 @@ impl GovernanceCouncil {
         let risk = proposal.risk();
         let votes_snapshot = proposal.votes().to_vec();
 
-        self.ledger.append(GovernanceEvent::ProposalClosed {
-            proposal_id: proposal_id.clone(),
-            outcome,
-            for_votes,
-            against_votes,
-            abstain_votes,
-            turnout_pct,
-            timestamp: Utc::now(),
-        });
+        self.ledger
+            .append(GovernanceEvent::ProposalClosed {
+                proposal_id: proposal_id.clone(),
+                outcome,
+                for_votes,
+                against_votes,
+                abstain_votes,
+                turnout_pct,
+                timestamp: self.clock.now(),
+            })
+            .map_err(storage_error)?;
 
         self.apply_dissent_penalties(proposal_id.clone(), risk, &votes_snapshot, outcome)?;
 
@@ -342,16 +395,19 @@ impl GovernanceCouncil {
 
         for member_id in dissenters {
             if let Some(member) = self.members.get_mut(&member_id) {
-                let task = Task::new("Dissent penalty".to_string(), 0);
+                let task = Task::new("Dissent penalty".to_string(), 0)
+                    .with_scar_classification(ScarCategory::Governance, "GOV_DISSENT");
                 let result = member.agent.execute_task(task, penalty_outcome.clone());
 
                 if matches!(result, TaskResult::Failed { .. }) {
-                    self.ledger.append(GovernanceEvent::DissentScarred {
-                        proposal_id: proposal_id.clone(),
-                        member_id: member_id.clone(),
-                        reason: "Dissent penalty applied".to_string(),
-                        timestamp: Utc::now(),
-                    });
+                    self.ledger
+                        .append(GovernanceEvent::DissentScarred {
+                            proposal_id: proposal_id.clone(),
+                            member_id: member_id.clone(),
+                            reason: "Dissent penalty applied".to_string(),
+                            timestamp: self.clock.now(),
+                        })
+                        .map_err(storage_error)?;
                 }
             }
         }
@@ -407,17 +463,20 @@ impl GovernanceCouncil {
             },
         };
 
-        self.ledger.append(GovernanceEvent::ProposalExecuted {
-            proposal_id: proposal_id.clone(),
-            success: execution_result.success,
-            error: execution_result.error.clone(),
-            timestamp: Utc::now(),
-        });
-
+        // The effect has run: mark it executed even if the ledger write fails
         if let Some(proposal) = self.proposals.get_mut(&proposal_id) {
             proposal.mark_executed();
         }
 
+        self.ledger
+            .append(GovernanceEvent::ProposalExecuted {
+                proposal_id: proposal_id.clone(),
+                success: execution_result.success,
+                error: execution_result.error.clone(),
+                timestamp: self.clock.now(),
+            })
+            .map_err(storage_error)?;
+
         Ok(execution_result)
     }
 }
@@ -462,6 +521,11 @@ pub enum GovernanceError {
     VoteFailed(String),
     NotExecutable,
     AlreadyExecuted,
+    StorageError(String),
+}
+
+fn storage_error(error: StoreError) -> GovernanceError {
+    GovernanceError::StorageError(error.to_string())
 }
 
 impl std::fmt::Display for GovernanceError {
@@ -484,6 +548,7 @@ impl std::fmt::Display for GovernanceError {
             GovernanceError::VoteFailed(reason) => write!(f, "Vote failed: {}", reason),
             GovernanceError::NotExecutable => write!(f, "Proposal is not executable"),
             GovernanceError::AlreadyExecuted => write!(f, "Proposal already executed"),
+            GovernanceError::StorageError(reason) => write!(f, "Ledger storage failed: {}", reason),
         }
     }
 }
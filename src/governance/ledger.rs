@@ -1,29 +1,85 @@
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::governance::{ProposalId, ProposalOutcome, ProposalRisk, VoteChoice};
+use crate::store::{LineageStore, StoreError};
+
+/// Store namespace holding governance events
+pub const LEDGER_NAMESPACE: &str = "governance";
 
 /// Append-only governance event log.
+///
+/// With a store, every event is written (keyed by its zero-padded
+/// sequence number) before it joins the in-memory log.
 #[derive(Debug, Default)]
 pub struct GovernanceLedger {
     events: Vec<GovernanceEvent>,
+    store: Option<Arc<dyn LineageStore>>,
 }
 
 impl GovernanceLedger {
     pub fn new() -> Self {
-        Self { events: Vec::new() }
+        Self {
+            events: Vec::new(),
+            store: None,
+        }
+    }
+
+    /// Open a ledger persisted in `store`, replaying every stored event.
+    ///
+    /// Fails if an event cannot be decoded or the sequence has a gap.
+    pub fn with_store(store: Arc<dyn LineageStore>) -> Result<Self, StoreError> {
+        let mut events = Vec::new();
+
+        for (sequence, key) in store.keys(LEDGER_NAMESPACE)?.into_iter().enumerate() {
+            if key != Self::sequence_key(sequence) {
+                return Err(StoreError::Corrupted(format!(
+                    "governance ledger expected event {} but found {}",
+                    sequence, key
+                )));
+            }
+            let bytes = store
+                .read(LEDGER_NAMESPACE, &key)?
+                .ok_or_else(|| StoreError::Corrupted(format!("governance event {} vanished", key)))?;
+            let event = serde_json::from_slice(&bytes)
+                .map_err(|e| StoreError::Corrupted(format!("governance event {}: {}", key, e)))?;
+            events.push(event);
+        }
+
+        Ok(Self {
+            events,
+            store: Some(store),
+        })
     }
 
-    pub fn append(&mut self, event: GovernanceEvent) {
+    fn sequence_key(sequence: usize) -> String {
+        format!("{:020}", sequence)
+    }
+
+    /// Append an event, persisting it first if the ledger has a store.
+    pub fn append(&mut self, event: GovernanceEvent) -> Result<(), StoreError> {
+        if let Some(store) = &self.store {
+            let bytes = serde_json::to_vec(&event).map_err(|e| StoreError::IoError(e.to_string()))?;
+            store.append(LEDGER_NAMESPACE, &Self::sequence_key(self.events.len()), &bytes)?;
+        }
+
         self.events.push(event);
+        Ok(())
     }
 
     pub fn events(&self) -> &[GovernanceEvent] {
         &self.events
     }
+
+    pub fn store(&self) -> Option<&Arc<dyn LineageStore>> {
+        self.store.as_ref()
+    }
 }
 
 /// Immutable governance events.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GovernanceEvent {
     MemberAdded {
         member_id: String,
@@ -6,6 +6,6 @@ mod proposal;
 mod vote;
 
 pub use council::{ExecutionContext, ExecutionResult, GovernanceConfig, GovernanceCouncil, GovernanceError};
-pub use ledger::{GovernanceEvent, GovernanceLedger};
+pub use ledger::{GovernanceEvent, GovernanceLedger, LEDGER_NAMESPACE};
 pub use proposal::{Proposal, ProposalId, ProposalOutcome, ProposalRisk, ProposalStatus};
 pub use vote::{VoteChoice, VoteRecord, VoteReceipt};
@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::governance::vote::VoteRecord;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ProposalId(String);
 
 impl ProposalId {
@@ -16,7 +17,7 @@ impl ProposalId {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProposalRisk {
     Low,
     Medium,
@@ -30,7 +31,7 @@ pub enum ProposalStatus {
     Executed,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProposalOutcome {
     Passed,
     Failed,
@@ -51,7 +52,11 @@ pub struct Proposal {
 
 impl Proposal {
     pub fn new(title: String, risk: ProposalRisk, voting_window_secs: i64) -> Self {
-        let now = Utc::now();
+        Self::new_at(title, risk, voting_window_secs, Utc::now())
+    }
+
+    /// Open a proposal created at `now`
+    pub fn new_at(title: String, risk: ProposalRisk, voting_window_secs: i64, now: DateTime<Utc>) -> Self {
         let closes_at = now + chrono::Duration::seconds(voting_window_secs);
 
         Self {
@@ -0,0 +1,329 @@
+//! Graveyard bundles: move dead agents between machines in one file.
+//!
+//! ## Format
+//! A length-prefixed stream:
+//! `LINGRAVE` magic, format version (u32 LE), then frames of
+//! `length (u64 LE) || bytes`. The first frame is the JSON manifest, each
+//! following frame is one tombstone exactly as it was stored on disk.
+//!
+//! The manifest lists every entry's ID and SHA-256, a Merkle root over
+//! those hashes, and the public keys of every signer. Those keys come from
+//! the bundle itself, so they are listed for inspection only: a receiving
+//! host imports against keys it already trusts.
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::graveyard::{Graveyard, GraveyardError, GraveyardPublicKey, GraveyardQuery, Tombstone};
+use crate::merkle::MerkleTree;
+
+/// Magic bytes opening every bundle
+pub const BUNDLE_MAGIC: &[u8; 8] = b"LINGRAVE";
+/// Current bundle format version
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// One tombstone listed in a bundle manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// ID of the buried agent
+    pub id: String,
+    /// SHA-256 (hex) of the tombstone frame
+    pub sha256: String,
+    /// Key ID of the graveyard keypair that signed the tombstone
+    pub signer_key_id: String,
+}
+
+/// Bundle manifest: what the bundle holds and how to verify it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// Bundle format version
+    pub format_version: u32,
+    /// When the bundle was exported
+    pub created_at: DateTime<Utc>,
+    /// Entries, in frame order
+    pub entries: Vec<BundleEntry>,
+    /// Merkle root over the entry hashes
+    pub root_hash: String,
+    /// Hex-encoded public keys of every signer in the bundle (never trusted)
+    pub public_keys: Vec<String>,
+}
+
+impl BundleManifest {
+    fn compute_root(entries: &[BundleEntry]) -> String {
+        MerkleTree::from_leaves(entries.iter().map(|entry| entry.sha256.as_bytes())).root()
+    }
+}
+
+/// A set of tombstones packaged for transport
+#[derive(Debug, Clone)]
+pub struct GraveyardBundle {
+    manifest: BundleManifest,
+    tombstones: Vec<Vec<u8>>,
+}
+
+/// Result of importing one bundle entry
+#[derive(Debug, Clone)]
+pub struct ImportEntryResult {
+    /// ID listed in the manifest
+    pub id: String,
+    /// `Ok` if buried, otherwise why it was rejected
+    pub result: Result<(), GraveyardError>,
+}
+
+/// Per-entry results of a bundle import
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub entries: Vec<ImportEntryResult>,
+}
+
+impl ImportReport {
+    /// IDs that were buried
+    pub fn imported(&self) -> Vec<&str> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.result.is_ok())
+            .map(|entry| entry.id.as_str())
+            .collect()
+    }
+
+    /// Entries that were rejected
+    pub fn rejected(&self) -> Vec<&ImportEntryResult> {
+        self.entries.iter().filter(|entry| entry.result.is_err()).collect()
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn invalid(reason: impl Into<String>) -> GraveyardError {
+    GraveyardError::InvalidBundle(reason.into())
+}
+
+impl GraveyardBundle {
+    /// Package tombstones (as stored bytes) with a fresh manifest
+    fn assemble(tombstones: Vec<(Tombstone, Vec<u8>)>, created_at: DateTime<Utc>) -> Self {
+        let mut entries = Vec::new();
+        let mut public_keys: Vec<String> = Vec::new();
+        let mut frames = Vec::new();
+
+        for (tombstone, bytes) in tombstones {
+            entries.push(BundleEntry {
+                id: tombstone.identity.id.clone(),
+                sha256: sha256_hex(&bytes),
+                signer_key_id: tombstone.signer_key_id.clone(),
+            });
+            if !tombstone.signer_public_key.is_empty()
+                && !public_keys.contains(&tombstone.signer_public_key)
+            {
+                public_keys.push(tombstone.signer_public_key.clone());
+            }
+            frames.push(bytes);
+        }
+
+        GraveyardBundle {
+            manifest: BundleManifest {
+                format_version: BUNDLE_FORMAT_VERSION,
+                created_at,
+                root_hash: BundleManifest::compute_root(&entries),
+                entries,
+                public_keys,
+            },
+            tombstones: frames,
+        }
+    }
+
+    /// Get the manifest
+    pub fn manifest(&self) -> &BundleManifest {
+        &self.manifest
+    }
+
+    /// Number of tombstones in the bundle
+    pub fn len(&self) -> usize {
+        self.tombstones.len()
+    }
+
+    /// True if the bundle holds no tombstones
+    pub fn is_empty(&self) -> bool {
+        self.tombstones.is_empty()
+    }
+
+    /// Check the manifest against the frames: counts and root hash.
+    ///
+    /// Per-entry hashes are checked during import so that one bad entry
+    /// does not hide the results of the others.
+    pub fn verify_manifest(&self) -> Result<(), GraveyardError> {
+        if self.manifest.format_version != BUNDLE_FORMAT_VERSION {
+            return Err(invalid(format!(
+                "unsupported format version {}",
+                self.manifest.format_version
+            )));
+        }
+        if self.manifest.entries.len() != self.tombstones.len() {
+            return Err(invalid("manifest entry count does not match frames"));
+        }
+        if BundleManifest::compute_root(&self.manifest.entries) != self.manifest.root_hash {
+            return Err(invalid("manifest root hash mismatch"));
+        }
+        Ok(())
+    }
+
+    /// Encode as a single length-prefixed stream
+    pub fn to_bytes(&self) -> Result<Vec<u8>, GraveyardError> {
+        let manifest = serde_json::to_vec(&self.manifest)
+            .map_err(|e| GraveyardError::SerializationError(e.to_string()))?;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(BUNDLE_MAGIC);
+        bytes.extend_from_slice(&BUNDLE_FORMAT_VERSION.to_le_bytes());
+        for frame in std::iter::once(&manifest).chain(&self.tombstones) {
+            bytes.extend_from_slice(&(frame.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(frame);
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a stream produced by `to_bytes` and check its manifest
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, GraveyardError> {
+        let rest = bytes
+            .strip_prefix(BUNDLE_MAGIC.as_slice())
+            .ok_or_else(|| invalid("missing bundle magic"))?;
+        let (version, mut rest) = rest
+            .split_first_chunk::<4>()
+            .ok_or_else(|| invalid("truncated header"))?;
+        if u32::from_le_bytes(*version) != BUNDLE_FORMAT_VERSION {
+            return Err(invalid(format!(
+                "unsupported format version {}",
+                u32::from_le_bytes(*version)
+            )));
+        }
+
+        let mut frames = Vec::new();
+        while !rest.is_empty() {
+            let (length, tail) = rest
+                .split_first_chunk::<8>()
+                .ok_or_else(|| invalid("truncated frame length"))?;
+            let length = usize::try_from(u64::from_le_bytes(*length))
+                .map_err(|_| invalid("frame too large"))?;
+            if tail.len() < length {
+                return Err(invalid("truncated frame"));
+            }
+            let (frame, tail) = tail.split_at(length);
+            frames.push(frame.to_vec());
+            rest = tail;
+        }
+
+        let mut frames = frames.into_iter();
+        let manifest_frame = frames.next().ok_or_else(|| invalid("missing manifest"))?;
+        let manifest: BundleManifest = serde_json::from_slice(&manifest_frame)
+            .map_err(|e| GraveyardError::SerializationError(e.to_string()))?;
+
+        let bundle = GraveyardBundle {
+            manifest,
+            tombstones: frames.collect(),
+        };
+        bundle.verify_manifest()?;
+        Ok(bundle)
+    }
+
+    /// Write the bundle to a single file
+    pub fn write_to(&self, path: impl AsRef<Path>) -> Result<(), GraveyardError> {
+        fs::write(path, self.to_bytes()?).map_err(|e| GraveyardError::IoError(e.to_string()))
+    }
+
+    /// Read a bundle from a file and check its manifest
+    pub fn read_from(path: impl AsRef<Path>) -> Result<Self, GraveyardError> {
+        let bytes = fs::read(path).map_err(|e| GraveyardError::IoError(e.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Decode and verify one entry against the manifest
+    fn open_entry(
+        &self,
+        entry: &BundleEntry,
+        frame: &[u8],
+        trusted_keys: &[GraveyardPublicKey],
+    ) -> Result<Tombstone, GraveyardError> {
+        let tampered = || GraveyardError::TamperingDetected {
+            id: entry.id.clone(),
+        };
+
+        if sha256_hex(frame) != entry.sha256 {
+            return Err(tampered());
+        }
+
+        let tombstone: Tombstone = serde_json::from_slice(frame)
+            .map_err(|e| GraveyardError::SerializationError(e.to_string()))?;
+        if tombstone.identity.id != entry.id || tombstone.signer_key_id != entry.signer_key_id {
+            return Err(tampered());
+        }
+
+        let key = trusted_keys
+            .iter()
+            .find(|key| key.key_id() == tombstone.signer_key_id)
+            .ok_or_else(|| {
+                GraveyardError::KeyError(format!(
+                    "tombstone {} is signed by untrusted key {}",
+                    entry.id, tombstone.signer_key_id
+                ))
+            })?;
+        tombstone.verify_with_key(key)?;
+
+        Ok(tombstone)
+    }
+}
+
+impl Graveyard {
+    /// Export the tombstones matching `filter` as a bundle
+    ///
+    /// Tombstones are packaged exactly as stored. Pagination on the filter
+    /// applies, so large graveyards can be exported in several bundles.
+    /// Only the filter's criteria are used: they are always run against this
+    /// graveyard's index, whichever graveyard built the query.
+    pub fn export(&self, filter: &GraveyardQuery<'_>) -> Result<GraveyardBundle, GraveyardError> {
+        let mut tombstones = Vec::new();
+
+        for id in filter.against(self).ids() {
+            let bytes = self.load_bytes(&id)?;
+            let tombstone: Tombstone = serde_json::from_slice(&bytes)
+                .map_err(|e| GraveyardError::SerializationError(e.to_string()))?;
+            tombstones.push((tombstone, bytes));
+        }
+
+        Ok(GraveyardBundle::assemble(tombstones, self.clock().now()))
+    }
+
+    /// Import a bundle, accepting only tombstones signed by `trusted_keys`
+    ///
+    /// The keys listed in the manifest are never trusted: anyone can build a
+    /// bundle of self-signed tombstones. Fails only if the manifest itself
+    /// is inconsistent. Otherwise every entry is attempted and its outcome
+    /// reported: tampered, unsigned or untrusted entries and IDs that are
+    /// already buried (`TombstoneExists`) are rejected.
+    pub fn import(
+        &self,
+        bundle: &GraveyardBundle,
+        trusted_keys: &[GraveyardPublicKey],
+    ) -> Result<ImportReport, GraveyardError> {
+        bundle.verify_manifest()?;
+
+        let entries = bundle
+            .manifest
+            .entries
+            .iter()
+            .zip(&bundle.tombstones)
+            .map(|(entry, frame)| ImportEntryResult {
+                id: entry.id.clone(),
+                result: bundle
+                    .open_entry(entry, frame, trusted_keys)
+                    .and_then(|tombstone| self.bury(&tombstone)),
+            })
+            .collect();
+
+        Ok(ImportReport { entries })
+    }
+}
@@ -0,0 +1,1925 @@
+//! # Graveyard System - The Eternal Archive
+//!
+//! Persistent storage for deceased agents.
+//!
+//! ## What This Enforces
+//! - Cryptographic sealing of agent tombstones
+//! - Immutable historical records of all dead agents
+//! - Prevention of identity resurrection ("Lazarus Prevention")
+//! - Fast O(1) lookups via in-memory registry
+//! - Indexed queries by cause, generation, parent, death time, scars and legacy
+//! - Independent graveyard instances, each with its own root directory
+//! - Tamper-detection via a Merkle tree over every scar and event
+//! - Inclusion proofs for a single scar or event, without the whole tombstone
+//! - The complete memory event history sealed into every tombstone
+//! - Ed25519 signature verification to detect fraudulent edits
+//! - Public-key verification: auditors never need the signing secret
+//! - Genealogical tracking via parent agent IDs
+//! - Verified export/import of single-file bundles between graveyards
+//! - Every write goes through a `LineageStore` (write-once, fsynced)
+//!
+//! ## What This Forbids
+//! - Overwriting existing tombstones
+//! - Reusing a dead agent's identity
+//! - Operating on dead agents
+//! - Modifying sealed records
+//! - Tampering with Legacy Scores or metadata
+//! - Creating agents without proper genealogy
+//!
+//! ## Storage Format
+//! Each tombstone is one JSON record under the `tomb` namespace of the
+//! graveyard's store. `Graveyard::open` uses a `FileStore`, so tombstones
+//! live in `<root>/<ID>.tomb` (the default instance uses `.lineage/graveyard`);
+//! `Graveyard::with_store` accepts any other `LineageStore`.
+//! The Ed25519 signature, signer key ID and signer public key are stored inside the tombstone.
+//! Each graveyard signs with its own keypair, kept in `<location>.keys` unless
+//! set with `with_keys_dir`; the default instance keeps it in
+//! `.lineage/keys/graveyard.ed25519` (secret) and `graveyard.pub`.
+//! The `FileStore` marks files read-only at OS level to prevent accidental mutation.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use chrono::{DateTime, Utc};
+
+use crate::clock::{self, Clock, SharedClock, SystemClock};
+use crate::lazarus::{self, DeathRegistry};
+use crate::memory::{Event, Memory};
+use crate::merkle::MerkleTree;
+use crate::scar::ScarCategory;
+use crate::store::{FileStore, LineageStore, StoreError};
+
+mod bundle;
+mod proof;
+mod query;
+mod signing;
+
+pub use bundle::{
+    BundleEntry, BundleManifest, GraveyardBundle, ImportEntryResult, ImportReport,
+    BUNDLE_FORMAT_VERSION, BUNDLE_MAGIC,
+};
+pub use proof::{ProvenRecord, TombstoneProof};
+pub use query::{GraveyardIndex, GraveyardQuery, QueryOrder, QueryPage, TombstoneSummary};
+pub use signing::{GraveyardKeypair, GraveyardPublicKey};
+
+/// Current tombstone schema version
+///
+/// - 4: binary Merkle tree over scars and events
+/// - 5: the signature covers a length-prefixed encoding of every payload
+///   field, now including the inherited capacity and knowledge, and scar
+///   leaves seal each scar's category, code and details (records buried
+///   before categories read as `General` and hash exactly as they did)
+pub const TOMBSTONE_SCHEMA_VERSION: u32 = 5;
+
+/// Store namespace holding tombstones
+pub const TOMB_NAMESPACE: &str = "tomb";
+
+/// Process-wide default graveyard (see `Graveyard::initialize`)
+static DEFAULT_GRAVEYARD: Mutex<Option<Arc<Graveyard>>> = Mutex::new(None);
+
+/// In-memory index of all buried agents (lightning-fast Lazarus checks)
+#[derive(Debug, Clone)]
+pub struct GraveyardRegistry {
+    /// IDs with a tombstone in the store
+    dead_ids: HashSet<String>,
+}
+
+impl GraveyardRegistry {
+    /// Initialize the registry from a store
+    pub fn initialize(store: &dyn LineageStore) -> Result<Self, GraveyardError> {
+        let dead_ids = store
+            .keys(TOMB_NAMESPACE)
+            .map_err(store_error)?
+            .into_iter()
+            .collect();
+
+        Ok(GraveyardRegistry { dead_ids })
+    }
+
+    /// Check if an identity has already died (Lazarus prevention)
+    pub fn is_dead(&self, id: &str) -> bool {
+        self.dead_ids.contains(id)
+    }
+
+    /// Register a newly buried agent
+    pub fn bury(&mut self, id: String) {
+        self.dead_ids.insert(id);
+    }
+
+    /// Get all dead agents
+    pub fn list_all(&self) -> Vec<String> {
+        self.dead_ids.iter().cloned().collect()
+    }
+}
+
+/// Errors that can occur in the graveyard system
+#[derive(Debug, Clone)]
+pub enum GraveyardError {
+    /// IO operation failed
+    IoError(String),
+    /// Tombstone already exists (no overwrites allowed)
+    TombstoneExists { id: String },
+    /// ID not found in graveyard
+    NotFound { id: String },
+    /// Serialization/deserialization failed
+    SerializationError(String),
+    /// Hash verification failed (tampering detected)
+    TamperingDetected { id: String },
+    /// Directory initialization failed
+    DirectoryError(String),
+    /// Signing key could not be loaded or parsed
+    KeyError(String),
+    /// Tombstone carries no signature (or a legacy one)
+    Unsigned { id: String },
+    /// Bundle is malformed or its manifest is inconsistent
+    InvalidBundle(String),
+}
+
+impl std::fmt::Display for GraveyardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraveyardError::IoError(e) => write!(f, "IO Error: {}", e),
+            GraveyardError::TombstoneExists { id } => {
+                write!(f, "Tombstone already exists for ID: {}", id)
+            }
+            GraveyardError::NotFound { id } => write!(f, "No tombstone found for ID: {}", id),
+            GraveyardError::SerializationError(e) => write!(f, "Serialization error: {}", e),
+            GraveyardError::TamperingDetected { id } => {
+                write!(f, "Tampering detected in tombstone: {}", id)
+            }
+            GraveyardError::DirectoryError(e) => write!(f, "Directory error: {}", e),
+            GraveyardError::KeyError(e) => write!(f, "Key error: {}", e),
+            GraveyardError::Unsigned { id } => write!(f, "Tombstone is not signed: {}", id),
+            GraveyardError::InvalidBundle(e) => write!(f, "Invalid graveyard bundle: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for GraveyardError {}
+
+/// Map a store failure onto the graveyard's errors
+fn store_error(error: StoreError) -> GraveyardError {
+    match error {
+        StoreError::RecordExists { key, .. } => GraveyardError::TombstoneExists { id: key },
+        StoreError::Corrupted(e) => GraveyardError::IoError(format!("corrupted store: {}", e)),
+        other => GraveyardError::IoError(other.to_string()),
+    }
+}
+
+/// Identity block in tombstone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityBlock {
+    /// Agent's unique identifier
+    pub id: String,
+    /// Creation timestamp
+    pub creation_time: DateTime<Utc>,
+    /// Cryptographic hash of identity proof
+    pub identity_hash: String,
+}
+
+/// Metabolic record in tombstone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetabolicRecord {
+    /// Final energy level when agent died
+    pub final_energy: u64,
+    /// Peak energy ever achieved
+    pub peak_energy: u64,
+    /// Initial energy at birth
+    pub initial_energy: u64,
+    /// Efficiency rating: tasks_completed / energy_burned
+    pub efficiency_ratio: f64,
+    /// Total tasks completed
+    pub tasks_completed: u32,
+}
+
+impl MetabolicRecord {
+    /// Calculate efficiency ratio
+    pub fn calculate_efficiency(tasks: u32, energy_burned: u64) -> f64 {
+        if energy_burned == 0 {
+            0.0
+        } else {
+            tasks as f64 / energy_burned as f64
+        }
+    }
+}
+
+/// Pathology report in tombstone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathologyReport {
+    /// List of scars inflicted during lifetime
+    pub scars: Vec<ScarRecord>,
+    /// Total number of scars
+    pub scar_count: usize,
+    /// Cause of death (the final scar)
+    pub cause_of_death: String,
+    /// Time of death
+    pub death_timestamp: DateTime<Utc>,
+}
+
+/// Individual scar record with metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScarRecord {
+    /// When scar was inflicted
+    pub timestamp: DateTime<Utc>,
+    /// Severity level
+    pub severity: String,
+    /// Description of the injury
+    pub description: String,
+    /// Context/stack trace
+    pub context: Option<String>,
+    /// Kind of damage (absent in tombstones buried before categories)
+    #[serde(default, skip_serializing_if = "ScarCategory::is_general")]
+    pub category: ScarCategory,
+    /// Machine-readable code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Structured key/value context
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, String>,
+}
+
+/// Causal chain - cryptographic proof of unaltered history
+///
+/// Holds the agent's complete memory at death. Each event carries its own
+/// digest chained to its predecessor, and `merkle_root` is the root of a
+/// binary Merkle tree whose leaves are every scar followed by every event
+/// digest, so editing any single event is detectable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausalChain {
+    /// Merkle root over the scar and event sequence
+    pub merkle_root: String,
+    /// Ordered list of memory event digests
+    pub event_hashes: Vec<String>,
+    /// Total events in chain
+    pub total_events: usize,
+    /// Complete memory event history, genesis first
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+
+/// Genealogical record for descendancy tracking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParentageRecord {
+    /// ID of parent agent (if spawned from another agent)
+    pub parent_id: Option<String>,
+    /// Capacity inherited from parent
+    pub inherited_capacity: Option<u64>,
+    /// Knowledge transferred from parent (description)
+    pub inherited_knowledge: Option<String>,
+    /// Generation number (0 = origin, 1 = spawned from origin, etc.)
+    pub generation: u32,
+}
+
+/// Every tombstone field covered by the signature, in signing order.
+///
+/// Floating point fields are excluded (they are derived and re-checked
+/// in `verify`), so the payload survives a JSON round trip exactly.
+/// Inclusion proofs carry this structure, so verifiers compare fields
+/// exactly instead of searching the encoded bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TombstonePayload {
+    pub schema_version: u32,
+    pub id: String,
+    pub identity_hash: String,
+    pub creation_time: DateTime<Utc>,
+    pub initial_energy: u64,
+    pub peak_energy: u64,
+    pub final_energy: u64,
+    pub tasks_completed: u32,
+    pub scar_count: usize,
+    pub cause_of_death: String,
+    pub death_timestamp: DateTime<Utc>,
+    pub merkle_root: String,
+    pub parent_id: Option<String>,
+    pub inherited_capacity: Option<u64>,
+    pub inherited_knowledge: Option<String>,
+    pub generation: u32,
+    pub burial_timestamp: DateTime<Utc>,
+    pub signer_key_id: String,
+}
+
+impl TombstonePayload {
+    /// Canonical byte string the signature covers.
+    ///
+    /// Every field is length-prefixed and optional fields are tagged, so no
+    /// free text (such as `cause_of_death`) can shift bytes into another
+    /// field: distinct payloads always encode differently.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = b"lineage-tombstone".to_vec();
+        put(&mut out, &self.schema_version.to_string());
+        put(&mut out, &self.id);
+        put(&mut out, &self.identity_hash);
+        put(&mut out, &self.creation_time.to_rfc3339());
+        put(&mut out, &self.initial_energy.to_string());
+        put(&mut out, &self.peak_energy.to_string());
+        put(&mut out, &self.final_energy.to_string());
+        put(&mut out, &self.tasks_completed.to_string());
+        put(&mut out, &self.scar_count.to_string());
+        put(&mut out, &self.cause_of_death);
+        put(&mut out, &self.death_timestamp.to_rfc3339());
+        put(&mut out, &self.merkle_root);
+        put_opt(&mut out, self.parent_id.as_deref());
+        put_opt(&mut out, self.inherited_capacity.map(|c| c.to_string()).as_deref());
+        put_opt(&mut out, self.inherited_knowledge.as_deref());
+        put(&mut out, &self.generation.to_string());
+        put(&mut out, &self.burial_timestamp.to_rfc3339());
+        put(&mut out, &self.signer_key_id);
+        out
+    }
+}
+
+/// Appends one length-prefixed field
+fn put(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u64).to_be_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// Appends an optional field, tagged so `None` and `Some("")` differ
+fn put_opt(out: &mut Vec<u8>, value: Option<&str>) {
+    match value {
+        Some(value) => {
+            out.push(1);
+            put(out, value);
+        }
+        None => out.push(0),
+    }
+}
+
+/// A complete tombstone record for a deceased agent
+///
+/// Contains all information needed to:
+/// - Reconstruct an agent's lifetime
+/// - Verify no tampering has occurred
+/// - Prevent resurrection via Lazarus check
+/// - Query historical data
+/// - Track genealogical relationships
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tombstone {
+    /// Identity information block
+    pub identity: IdentityBlock,
+    /// Metabolic records from lifetime
+    pub metabolism: MetabolicRecord,
+    /// Pathology report with scars and cause of death
+    pub pathology: PathologyReport,
+    /// Causal chain for tamper detection
+    pub causal_chain: CausalChain,
+    /// Genealogical information (parentage and generation)
+    pub parentage: ParentageRecord,
+    /// Ed25519 signature (hex) over the canonical tombstone payload
+    pub signature: String,
+    /// Key ID of the graveyard keypair that signed this tombstone
+    #[serde(default)]
+    pub signer_key_id: String,
+    /// Hex-encoded public key of the signer
+    #[serde(default)]
+    pub signer_public_key: String,
+    /// Signature timestamp of burial
+    pub burial_timestamp: DateTime<Utc>,
+    /// Version of graveyard schema
+    pub schema_version: u32,
+}
+
+impl Tombstone {
+    /// Create a new tombstone from final agent state
+    ///
+    /// Fails if the local graveyard keypair cannot be loaded.
+    pub fn create(
+        id: String,
+        identity_hash: String,
+        creation_time: DateTime<Utc>,
+        final_energy: u64,
+        peak_energy: u64,
+        initial_energy: u64,
+        tasks_completed: u32,
+        scars: Vec<ScarRecord>,
+        cause_of_death: String,
+    ) -> Result<Self, GraveyardError> {
+        Self::create_with_parentage(
+            id, identity_hash, creation_time, final_energy, peak_energy, 
+            initial_energy, tasks_completed, scars, cause_of_death,
+            None, None, None, 0
+        )
+    }
+
+    /// Create a new tombstone with genealogical information
+    ///
+    /// No memory history is recorded; prefer `create_with_history`.
+    /// The tombstone is signed with the local graveyard keypair
+    /// (see `GraveyardKeypair::load_or_create`). If that key cannot be
+    /// loaded, the key error is returned and no tombstone is produced.
+    pub fn create_with_parentage(
+        id: String,
+        identity_hash: String,
+        creation_time: DateTime<Utc>,
+        final_energy: u64,
+        peak_energy: u64,
+        initial_energy: u64,
+        tasks_completed: u32,
+        scars: Vec<ScarRecord>,
+        cause_of_death: String,
+        parent_id: Option<String>,
+        inherited_capacity: Option<u64>,
+        inherited_knowledge: Option<String>,
+        generation: u32,
+    ) -> Result<Self, GraveyardError> {
+        Self::create_with_history(
+            id, identity_hash, creation_time, final_energy, peak_energy,
+            initial_energy, tasks_completed, scars, cause_of_death,
+            parent_id, inherited_capacity, inherited_knowledge, generation,
+            &[],
+        )
+    }
+
+    /// Create a new tombstone sealing the agent's complete memory history
+    ///
+    /// `history` is the lineage's event log (`Memory::history`), including
+    /// the termination event. Signed like `create_with_parentage`; to sign
+    /// for a particular graveyard, use `create_with_clock` with its keypair.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_with_history(
+        id: String,
+        identity_hash: String,
+        creation_time: DateTime<Utc>,
+        final_energy: u64,
+        peak_energy: u64,
+        initial_energy: u64,
+        tasks_completed: u32,
+        scars: Vec<ScarRecord>,
+        cause_of_death: String,
+        parent_id: Option<String>,
+        inherited_capacity: Option<u64>,
+        inherited_knowledge: Option<String>,
+        generation: u32,
+        history: &[Event],
+    ) -> Result<Self, GraveyardError> {
+        // No silent fallback: a tombstone that cannot be signed is not produced
+        let keypair = GraveyardKeypair::load_or_create(&GraveyardKeypair::default_dir())?;
+
+        Ok(Self::create_with_clock(
+            id, identity_hash, creation_time, final_energy, peak_energy,
+            initial_energy, tasks_completed, scars, cause_of_death,
+            parent_id, inherited_capacity, inherited_knowledge, generation,
+            history, &SystemClock, &keypair,
+        ))
+    }
+
+    /// Create a tombstone like `create_with_history`, stamping death and
+    /// burial times from `clock` and signing with `keypair`
+    /// (usually `Graveyard::keypair` of the graveyard it will be buried in)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_with_clock(
+        id: String,
+        identity_hash: String,
+        creation_time: DateTime<Utc>,
+        final_energy: u64,
+        peak_energy: u64,
+        initial_energy: u64,
+        tasks_completed: u32,
+        scars: Vec<ScarRecord>,
+        cause_of_death: String,
+        parent_id: Option<String>,
+        inherited_capacity: Option<u64>,
+        inherited_knowledge: Option<String>,
+        generation: u32,
+        history: &[Event],
+        clock: &dyn Clock,
+        keypair: &GraveyardKeypair,
+    ) -> Self {
+        let mut tombstone = Self::unsigned(
+            id, identity_hash, creation_time, final_energy, peak_energy,
+            initial_energy, tasks_completed, scars, cause_of_death,
+            parent_id, inherited_capacity, inherited_knowledge, generation,
+            history, clock.now(),
+        );
+
+        tombstone.sign(keypair);
+        tombstone
+    }
+
+    /// Assemble a tombstone without signing it
+    #[allow(clippy::too_many_arguments)]
+    fn unsigned(
+        id: String,
+        identity_hash: String,
+        creation_time: DateTime<Utc>,
+        final_energy: u64,
+        peak_energy: u64,
+        initial_energy: u64,
+        tasks_completed: u32,
+        scars: Vec<ScarRecord>,
+        cause_of_death: String,
+        parent_id: Option<String>,
+        inherited_capacity: Option<u64>,
+        inherited_knowledge: Option<String>,
+        generation: u32,
+        history: &[Event],
+        buried_at: DateTime<Utc>,
+    ) -> Self {
+        let efficiency_ratio =
+            MetabolicRecord::calculate_efficiency(tasks_completed, initial_energy - final_energy);
+
+        let pathology = PathologyReport {
+            scar_count: scars.len(),
+            scars: scars.clone(),
+            cause_of_death,
+            death_timestamp: buried_at,
+        };
+
+        let causal_chain = Self::create_causal_chain(&scars, history);
+        
+        let parentage = ParentageRecord {
+            parent_id,
+            inherited_capacity,
+            inherited_knowledge,
+            generation,
+        };
+
+        Tombstone {
+            identity: IdentityBlock {
+                id,
+                creation_time,
+                identity_hash,
+            },
+            metabolism: MetabolicRecord {
+                final_energy,
+                peak_energy,
+                initial_energy,
+                efficiency_ratio,
+                tasks_completed,
+            },
+            pathology,
+            causal_chain,
+            parentage,
+            signature: String::new(), // Will be calculated next
+            signer_key_id: String::new(),
+            signer_public_key: String::new(),
+            burial_timestamp: buried_at,
+            schema_version: TOMBSTONE_SCHEMA_VERSION,
+        }
+    }
+
+    /// Create causal chain from scar sequence and memory history
+    fn create_causal_chain(scars: &[ScarRecord], history: &[Event]) -> CausalChain {
+        let event_hashes: Vec<String> = history
+            .iter()
+            .map(|event| event.hash().to_string())
+            .collect();
+
+        CausalChain {
+            merkle_root: Self::chain_root(scars, &event_hashes),
+            event_hashes,
+            total_events: history.len(),
+            events: history.to_vec(),
+        }
+    }
+
+    /// Merkle root over every scar record followed by every event digest
+    fn chain_root(scars: &[ScarRecord], event_hashes: &[String]) -> String {
+        Self::causal_tree(scars, event_hashes).root()
+    }
+
+    /// Merkle tree whose leaves are the scar leaves, then the event leaves
+    fn causal_tree(scars: &[ScarRecord], event_hashes: &[String]) -> MerkleTree {
+        let leaves = scars
+            .iter()
+            .map(Self::scar_leaf)
+            .chain(event_hashes.iter().map(|hash| Self::event_leaf(hash)));
+        MerkleTree::from_leaves(leaves)
+    }
+
+    /// Leaf data for a scar (tagged so it can never pass as an event)
+    fn scar_leaf(scar: &ScarRecord) -> String {
+        let scar_json = serde_json::to_string(scar)
+            .unwrap_or_else(|_| format!("{:?}", scar));
+        format!("scar|{}", scar_json)
+    }
+
+    /// Leaf data for an event digest
+    fn event_leaf(hash: &str) -> String {
+        format!("event|{}", hash)
+    }
+
+    /// The fields covered by the signature
+    pub(crate) fn payload(&self) -> TombstonePayload {
+        TombstonePayload {
+            schema_version: self.schema_version,
+            id: self.identity.id.clone(),
+            identity_hash: self.identity.identity_hash.clone(),
+            creation_time: self.identity.creation_time,
+            initial_energy: self.metabolism.initial_energy,
+            peak_energy: self.metabolism.peak_energy,
+            final_energy: self.metabolism.final_energy,
+            tasks_completed: self.metabolism.tasks_completed,
+            scar_count: self.pathology.scar_count,
+            cause_of_death: self.pathology.cause_of_death.clone(),
+            death_timestamp: self.pathology.death_timestamp,
+            merkle_root: self.causal_chain.merkle_root.clone(),
+            parent_id: self.parentage.parent_id.clone(),
+            inherited_capacity: self.parentage.inherited_capacity,
+            inherited_knowledge: self.parentage.inherited_knowledge.clone(),
+            generation: self.parentage.generation,
+            burial_timestamp: self.burial_timestamp,
+            signer_key_id: self.signer_key_id.clone(),
+        }
+    }
+
+    /// Canonical byte string covered by the signature
+    fn signing_payload(&self) -> Vec<u8> {
+        self.payload().to_bytes()
+    }
+
+    /// Sign this tombstone with a graveyard keypair.
+    ///
+    /// Records the signer's key ID and public key alongside the signature.
+    pub fn sign(&mut self, keypair: &GraveyardKeypair) {
+        self.signer_key_id = keypair.key_id();
+        self.signer_public_key = keypair.public_key().to_hex();
+        self.signature = keypair.sign(&self.signing_payload());
+    }
+
+    /// Verify the signature against the public key recorded in the tombstone.
+    ///
+    /// This proves the tombstone was not edited after signing. To prove
+    /// *who* signed it, use `verify_with_key` with a trusted public key.
+    pub fn verify_signature(&self) -> Result<(), GraveyardError> {
+        if self.signature.is_empty() || self.signer_public_key.is_empty() {
+            return Err(GraveyardError::Unsigned {
+                id: self.identity.id.clone(),
+            });
+        }
+
+        let public_key = GraveyardPublicKey::from_hex(&self.signer_public_key)?;
+        self.verify_signature_with(&public_key)
+    }
+
+    /// Verify the signature against a trusted public key.
+    fn verify_signature_with(&self, public_key: &GraveyardPublicKey) -> Result<(), GraveyardError> {
+        if public_key.key_id() != self.signer_key_id
+            || !public_key.verify(&self.signing_payload(), &self.signature)
+        {
+            return Err(GraveyardError::TamperingDetected {
+                id: self.identity.id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verify the integrity of this tombstone (no tampering)
+    ///
+    /// Uses the signer public key recorded in the tombstone.
+    pub fn verify(&self) -> Result<(), GraveyardError> {
+        self.verify_contents()?;
+        self.verify_signature()
+    }
+
+    /// Verify the integrity of this tombstone against a trusted public key.
+    ///
+    /// This is the auditor's mode: it needs only the graveyard's public key
+    /// and fails if the tombstone was signed by any other key.
+    pub fn verify_with_key(&self, public_key: &GraveyardPublicKey) -> Result<(), GraveyardError> {
+        self.verify_contents()?;
+        if self.signature.is_empty() {
+            return Err(GraveyardError::Unsigned {
+                id: self.identity.id.clone(),
+            });
+        }
+        self.verify_signature_with(public_key)
+    }
+
+    /// Verify the unsigned parts: causal chain and derived fields
+    fn verify_contents(&self) -> Result<(), GraveyardError> {
+        // First, verify the causal chain integrity
+        let chain = &self.causal_chain;
+        let tampered = || GraveyardError::TamperingDetected {
+            id: self.identity.id.clone(),
+        };
+
+        // Every sealed event must be present, self-consistent and listed
+        if chain.total_events != chain.events.len()
+            || chain.event_hashes.len() != chain.events.len()
+            || chain
+                .events
+                .iter()
+                .zip(&chain.event_hashes)
+                .any(|(event, hash)| event.hash() != hash)
+        {
+            return Err(tampered());
+        }
+
+        if !chain.events.is_empty() && !Memory::verify_chain(&chain.events) {
+            return Err(tampered());
+        }
+
+        if Self::chain_root(&self.pathology.scars, &chain.event_hashes) != chain.merkle_root {
+            return Err(tampered());
+        }
+
+        // Second, derived fields must match the signed counts
+        let expected_efficiency = MetabolicRecord::calculate_efficiency(
+            self.metabolism.tasks_completed,
+            self.metabolism.initial_energy.saturating_sub(self.metabolism.final_energy),
+        );
+        if self.pathology.scar_count != self.pathology.scars.len()
+            || (expected_efficiency - self.metabolism.efficiency_ratio).abs() > f64::EPSILON
+        {
+            return Err(tampered());
+        }
+
+        Ok(())
+    }
+
+    /// Calculate legacy score (Success-to-Scar ratio with efficiency bonus)
+    pub fn legacy_score(&self) -> f64 {
+        let base_score = if self.pathology.scar_count > 0 {
+            self.metabolism.tasks_completed as f64 / self.pathology.scar_count as f64
+        } else {
+            self.metabolism.tasks_completed as f64 + 1.0
+        };
+
+        base_score * self.metabolism.efficiency_ratio
+    }
+
+    /// Get lifespan in seconds
+    pub fn lifespan_seconds(&self) -> i64 {
+        (self.pathology.death_timestamp - self.identity.creation_time).num_seconds()
+    }
+}
+
+/// The Graveyard manager - handles burial, loading, and queries
+///
+/// Each instance owns one store and its own in-memory registry, so several
+/// independent graveyards can live side by side in one process.
+/// The static `initialize`/`global`/`default_instance` functions manage a
+/// process-wide default instance rooted at `Graveyard::path()`.
+///
+/// Every graveyard is registered with the Lazarus guard when it is opened
+/// and stays registered until it is dropped, so nothing it has buried can
+/// re-enter the process meanwhile. `unguard` opts out explicitly.
+#[derive(Debug)]
+pub struct Graveyard {
+    /// Where tombstones are persisted
+    store: Arc<dyn LineageStore>,
+    /// In-memory index of buried IDs, shared with the Lazarus guard
+    registry: Arc<Mutex<GraveyardRegistry>>,
+    /// Key this instance's registry is registered under with the Lazarus guard
+    guard_key: String,
+    /// Directory holding the keypair this graveyard signs tombstones with
+    keys_dir: PathBuf,
+    /// Query index over tombstone metadata
+    index: Mutex<GraveyardIndex>,
+    /// Source of bundle timestamps
+    clock: SharedClock,
+}
+
+impl Graveyard {
+    /// Open (creating if needed) a file-backed graveyard rooted at `root`
+    ///
+    /// The graveyard guards the Lazarus check while it is open.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, GraveyardError> {
+        let store = FileStore::open(root)
+            .map_err(|e| GraveyardError::DirectoryError(e.to_string()))?;
+
+        Graveyard::with_store(Arc::new(store))
+    }
+
+    /// Open a graveyard persisting through any `LineageStore`
+    ///
+    /// The store may be shared with other components; tombstones use
+    /// the `tomb` namespace. Like `open`, this guards the graveyard.
+    pub fn with_store(store: Arc<dyn LineageStore>) -> Result<Self, GraveyardError> {
+        Graveyard::with_store_and_clock(store, clock::system())
+    }
+
+    /// Open a graveyard through `store` that reads time from `clock`
+    ///
+    /// Registers the graveyard with the Lazarus guard (see `guard`).
+    pub fn with_store_and_clock(
+        store: Arc<dyn LineageStore>,
+        clock: SharedClock,
+    ) -> Result<Self, GraveyardError> {
+        let registry = GraveyardRegistry::initialize(store.as_ref())?;
+        let guard_key = format!("{}#{}", store.location().display(), uuid::Uuid::new_v4());
+        let keys_dir = store.location().with_extension("keys");
+
+        let graveyard = Graveyard {
+            store,
+            registry: Arc::new(Mutex::new(registry)),
+            guard_key,
+            keys_dir,
+            index: Mutex::new(GraveyardIndex::default()),
+            clock,
+        };
+        graveyard.rebuild_index();
+        graveyard.guard();
+
+        Ok(graveyard)
+    }
+
+    /// Rebuild the query index from every readable tombstone on disk
+    ///
+    /// Unreadable tombstones stay in the registry (they are still dead)
+    /// but cannot be found by queries.
+    fn rebuild_index(&self) {
+        let mut index = GraveyardIndex::default();
+        for id in self.list_all() {
+            if let Ok(tombstone) = self.load(&id) {
+                index.insert(&tombstone);
+            }
+        }
+        *self.index.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = index;
+    }
+
+    /// Run `f` against the query index
+    fn with_index<R>(&self, f: impl FnOnce(&GraveyardIndex) -> R) -> R {
+        let index = self.index.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&index)
+    }
+
+    /// Start a query over this graveyard's index
+    pub fn query(&self) -> GraveyardQuery<'_> {
+        GraveyardQuery::new(self)
+    }
+
+    /// Get where this graveyard's store lives (its root directory for `open`)
+    pub fn root(&self) -> &Path {
+        self.store.location()
+    }
+
+    /// Get the store this graveyard persists through
+    pub fn store(&self) -> &Arc<dyn LineageStore> {
+        &self.store
+    }
+
+    /// Get the clock this graveyard reads time from
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
+    /// Sign this graveyard's tombstones with the keypair kept in `dir`
+    ///
+    /// By default the keys live beside the store, in `<location>.keys`.
+    pub fn with_keys_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.keys_dir = dir.into();
+        self
+    }
+
+    /// Get the directory holding this graveyard's signing keypair
+    pub fn keys_dir(&self) -> &Path {
+        &self.keys_dir
+    }
+
+    /// Load this graveyard's signing keypair, generating it on first use
+    pub fn keypair(&self) -> Result<GraveyardKeypair, GraveyardError> {
+        GraveyardKeypair::load_or_create(&self.keys_dir)
+    }
+
+    /// Initialize the default graveyard at `Graveyard::path()`
+    ///
+    /// Replaces any previously installed default instance; like every open
+    /// graveyard, it guards the Lazarus check.
+    pub fn initialize() -> Result<(), GraveyardError> {
+        let graveyard = Arc::new(
+            Graveyard::open(Graveyard::path())?.with_keys_dir(GraveyardKeypair::default_dir()),
+        );
+
+        let mut default = DEFAULT_GRAVEYARD
+            .lock()
+            .expect("Graveyard registry poisoned");
+        *default = Some(graveyard);
+
+        Ok(())
+    }
+
+    /// Register this graveyard with the Lazarus guard
+    ///
+    /// Done on open. While registered, no identity buried here can be
+    /// created, restored, imported or reopened anywhere in the process.
+    pub fn guard(&self) {
+        lazarus::register(
+            self.guard_key.clone(),
+            Arc::clone(&self.registry) as Arc<dyn DeathRegistry>,
+        );
+    }
+
+    /// Withdraw this graveyard from the Lazarus guard (explicit opt-out)
+    ///
+    /// Its dead stay buried here but no longer veto other subsystems.
+    pub fn unguard(&self) {
+        lazarus::unregister(&self.guard_key);
+    }
+
+    /// Get the default graveyard path
+    pub fn path() -> PathBuf {
+        PathBuf::from(".lineage/graveyard")
+    }
+
+    /// Get the default graveyard, if it has been initialized
+    pub fn global() -> Option<Arc<Graveyard>> {
+        DEFAULT_GRAVEYARD
+            .lock()
+            .ok()
+            .and_then(|default| default.clone())
+    }
+
+    /// Get the default graveyard, initializing it on first use
+    pub fn default_instance() -> Result<Arc<Graveyard>, GraveyardError> {
+        if let Some(graveyard) = Graveyard::global() {
+            return Ok(graveyard);
+        }
+
+        Graveyard::initialize()?;
+        Graveyard::global().ok_or_else(|| {
+            GraveyardError::DirectoryError("Default graveyard unavailable".to_string())
+        })
+    }
+
+    /// Bury an agent (write-once record in the store)
+    ///
+    /// Only tombstones that verify (intact chain and valid signature) are accepted.
+    pub fn bury(&self, tombstone: &Tombstone) -> Result<(), GraveyardError> {
+        tombstone.verify()?;
+
+        // Check if already buried (no overwrites)
+        if self.is_dead(&tombstone.identity.id) {
+            return Err(GraveyardError::TombstoneExists {
+                id: tombstone.identity.id.clone(),
+            });
+        }
+
+        // Serialize tombstone
+        let tombstone_json = serde_json::to_string_pretty(tombstone)
+            .map_err(|e| GraveyardError::SerializationError(e.to_string()))?;
+
+        // The store refuses existing keys and fsyncs before returning
+        self.store
+            .append(TOMB_NAMESPACE, &tombstone.identity.id, tombstone_json.as_bytes())
+            .map_err(store_error)?;
+
+        // Register in this graveyard's registry
+        self.registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .bury(tombstone.identity.id.clone());
+        self.index
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(tombstone);
+
+        Ok(())
+    }
+
+    /// Read a tombstone's stored bytes
+    fn load_bytes(&self, id: &str) -> Result<Vec<u8>, GraveyardError> {
+        self.store
+            .read(TOMB_NAMESPACE, id)
+            .map_err(store_error)?
+            .ok_or_else(|| GraveyardError::NotFound { id: id.to_string() })
+    }
+
+    /// Load a tombstone from the store
+    pub fn load(&self, id: &str) -> Result<Tombstone, GraveyardError> {
+        let content = self.load_bytes(id)?;
+
+        let tombstone: Tombstone = serde_json::from_slice(&content)
+            .map_err(|e| GraveyardError::SerializationError(e.to_string()))?;
+
+        Ok(tombstone)
+    }
+
+    /// Check if an identity has already died (fast O(1) check)
+    pub fn is_dead(&self, id: &str) -> bool {
+        self.registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_dead(id)
+    }
+
+    /// List all dead agents
+    pub fn list_all(&self) -> Vec<String> {
+        self.registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .list_all()
+    }
+
+    /// Load all tombstones (expensive operation)
+    pub fn load_all(&self) -> Result<Vec<Tombstone>, GraveyardError> {
+        let mut tombstones = Vec::new();
+
+        for id in self.list_all() {
+            if let Ok(tombstone) = self.load(&id) {
+                tombstones.push(tombstone);
+            }
+        }
+
+        Ok(tombstones)
+    }
+
+    /// Get summary statistics (computed from the index, no disk reads)
+    pub fn statistics(&self) -> Result<GraveyardStats, GraveyardError> {
+        let tombstones: Vec<TombstoneSummary> =
+            self.with_index(|index| index.summaries().cloned().collect());
+        let mut total_lifespan = 0i64;
+        let mut total_efficiency = 0.0f64;
+        let mut scar_counts = Vec::new();
+        let mut legacy_scores = Vec::new();
+
+        for tombstone in &tombstones {
+            total_lifespan += tombstone.lifespan_seconds();
+            total_efficiency += tombstone.efficiency_ratio;
+            scar_counts.push(tombstone.scar_count);
+            legacy_scores.push(tombstone.legacy_score);
+        }
+
+        let count = tombstones.len() as f64;
+        let avg_lifespan = if tombstones.is_empty() {
+            0
+        } else {
+            total_lifespan / tombstones.len() as i64
+        };
+        let avg_efficiency = total_efficiency / count.max(1.0);
+
+        Ok(GraveyardStats {
+            total_agents: tombstones.len(),
+            average_lifespan_seconds: avg_lifespan,
+            average_efficiency: avg_efficiency,
+            total_scars: scar_counts.iter().sum(),
+            most_common_scar_count: scar_counts.iter().max().cloned().unwrap_or(0),
+            highest_legacy_score: legacy_scores
+                .iter()
+                .cloned()
+                .fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+}
+
+/// Static shims over the default graveyard, kept for callers of the former
+/// static API.
+///
+/// The instance methods now own the original names, so each shim carries a
+/// `_default` suffix; every one delegates to `Graveyard::default_instance()`.
+impl Graveyard {
+    /// Bury a tombstone in the default graveyard
+    #[deprecated(note = "use `Graveyard::default_instance()?.bury(tombstone)`")]
+    pub fn bury_default(tombstone: &Tombstone) -> Result<(), GraveyardError> {
+        Graveyard::default_instance()?.bury(tombstone)
+    }
+
+    /// Load a tombstone from the default graveyard
+    #[deprecated(note = "use `Graveyard::default_instance()?.load(id)`")]
+    pub fn load_default(id: &str) -> Result<Tombstone, GraveyardError> {
+        Graveyard::default_instance()?.load(id)
+    }
+
+    /// Check the default graveyard for an identity
+    #[deprecated(note = "use `Graveyard::default_instance()?.is_dead(id)`")]
+    pub fn is_dead_default(id: &str) -> bool {
+        Graveyard::default_instance()
+            .map(|graveyard| graveyard.is_dead(id))
+            .unwrap_or(false)
+    }
+
+    /// List every agent buried in the default graveyard
+    #[deprecated(note = "use `Graveyard::default_instance()?.list_all()`")]
+    pub fn list_all_default() -> Vec<String> {
+        Graveyard::default_instance()
+            .map(|graveyard| graveyard.list_all())
+            .unwrap_or_default()
+    }
+
+    /// Load every tombstone in the default graveyard
+    #[deprecated(note = "use `Graveyard::default_instance()?.load_all()`")]
+    pub fn load_all_default() -> Result<Vec<Tombstone>, GraveyardError> {
+        Graveyard::default_instance()?.load_all()
+    }
+
+    /// Summary statistics of the default graveyard
+    #[deprecated(note = "use `Graveyard::default_instance()?.statistics()`")]
+    pub fn statistics_default() -> Result<GraveyardStats, GraveyardError> {
+        Graveyard::default_instance()?.statistics()
+    }
+}
+
+impl DeathRegistry for Graveyard {
+    fn is_dead(&self, id: &str) -> bool {
+        Graveyard::is_dead(self, id)
+    }
+}
+
+impl DeathRegistry for Mutex<GraveyardRegistry> {
+    fn is_dead(&self, id: &str) -> bool {
+        self.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_dead(id)
+    }
+}
+
+impl Drop for Graveyard {
+    fn drop(&mut self) {
+        self.unguard();
+    }
+}
+
+/// Statistics about the graveyard
+#[derive(Debug, Clone)]
+pub struct GraveyardStats {
+    pub total_agents: usize,
+    pub average_lifespan_seconds: i64,
+    pub average_efficiency: f64,
+    pub total_scars: usize,
+    pub most_common_scar_count: usize,
+    pub highest_legacy_score: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_legacy_score_calculation() {
+        let metabolic = MetabolicRecord {
+            final_energy: 100,
+            peak_energy: 1000,
+            initial_energy: 1000,
+            efficiency_ratio: 0.5,
+            tasks_completed: 10,
+        };
+
+        assert_eq!(metabolic.efficiency_ratio, 0.5);
+    }
+
+    fn sample_history() -> Vec<Event> {
+        let mut memory = Memory::new();
+        memory.append("Wrote report".to_string());
+        memory.append("Disk failure".to_string());
+        memory.terminate("Disk failure".to_string());
+        memory.history().to_vec()
+    }
+
+    fn sample_tombstone() -> Tombstone {
+        Tombstone::unsigned(
+            "agent-under-test".to_string(),
+            "identity-hash".to_string(),
+            Utc::now(),
+            0,
+            1000,
+            1000,
+            7,
+            vec![ScarRecord {
+                timestamp: Utc::now(),
+                severity: "Fatal".to_string(),
+                description: "Disk failure".to_string(),
+                context: None,
+                category: ScarCategory::Integrity,
+                code: Some("DISK_FAILURE".to_string()),
+                details: BTreeMap::from([("device".to_string(), "sda".to_string())]),
+            }],
+            "Disk failure".to_string(),
+            None,
+            None,
+            None,
+            0,
+            &sample_history(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn signed_tombstone_verifies_with_public_key_alone() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+
+        assert_eq!(tombstone.signer_key_id, keypair.key_id());
+        assert!(tombstone.verify().is_ok());
+
+        // An auditor only holds the hex public key
+        let auditor_key = GraveyardPublicKey::from_hex(&keypair.public_key().to_hex()).unwrap();
+        assert!(tombstone.verify_with_key(&auditor_key).is_ok());
+    }
+
+    #[test]
+    fn signature_survives_json_roundtrip() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+
+        let json = serde_json::to_string_pretty(&tombstone).unwrap();
+        let loaded: Tombstone = serde_json::from_str(&json).unwrap();
+
+        assert!(loaded.verify_with_key(&keypair.public_key()).is_ok());
+    }
+
+    #[test]
+    fn every_parentage_field_and_field_boundary_is_signed() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.parentage.inherited_capacity = Some(400);
+        tombstone.parentage.inherited_knowledge = Some("Avoid disk writes".to_string());
+        tombstone.sign(&keypair);
+        assert!(tombstone.verify().is_ok());
+
+        let mut edited = tombstone.clone();
+        edited.parentage.inherited_capacity = Some(4000);
+        assert!(matches!(edited.verify(), Err(GraveyardError::TamperingDetected { .. })));
+
+        let mut edited = tombstone.clone();
+        edited.parentage.inherited_knowledge = None;
+        assert!(matches!(edited.verify(), Err(GraveyardError::TamperingDetected { .. })));
+
+        // Moving text across a field boundary changes the encoding
+        let payload = tombstone.payload();
+        let mut shifted = payload.clone();
+        shifted.cause_of_death = format!("{}|{}", payload.identity_hash, payload.cause_of_death);
+        shifted.identity_hash = String::new();
+        assert_ne!(shifted.to_bytes(), payload.to_bytes());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn generated_secret_key_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = temp_root();
+        GraveyardKeypair::load_or_create(&dir).unwrap();
+        let mode = fs::metadata(dir.join("graveyard.ed25519")).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn concurrent_first_use_agrees_on_one_keypair() {
+        let dir = temp_root();
+        let key_ids: Vec<String> = (0..8)
+            .map(|_| {
+                let dir = dir.clone();
+                std::thread::spawn(move || GraveyardKeypair::load_or_create(&dir).unwrap().key_id())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        assert!(key_ids.iter().all(|key_id| *key_id == key_ids[0]));
+        assert_eq!(GraveyardPublicKey::load(&dir).unwrap().key_id(), key_ids[0]);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn each_graveyard_signs_with_its_own_keys() {
+        let root = temp_root();
+        let graveyard = Graveyard::open(&root).unwrap();
+        assert_eq!(graveyard.keys_dir(), root.with_extension("keys"));
+
+        let keys = temp_root();
+        let graveyard = graveyard.with_keys_dir(&keys);
+        let keypair = graveyard.keypair().unwrap();
+        assert_eq!(graveyard.keypair().unwrap().key_id(), keypair.key_id());
+        assert!(keys.join("graveyard.pub").exists());
+
+        let _ = fs::remove_dir_all(&root);
+        let _ = fs::remove_dir_all(&keys);
+    }
+
+    #[test]
+    fn edited_tombstone_fails_verification() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+
+        tombstone.metabolism.tasks_completed = 700;
+
+        assert!(matches!(
+            tombstone.verify(),
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn scar_classification_is_sealed_in_the_merkle_root() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+        assert!(tombstone.verify().is_ok());
+
+        let mut edited = tombstone.clone();
+        edited.pathology.scars[0].category = ScarCategory::General;
+        assert!(matches!(edited.verify(), Err(GraveyardError::TamperingDetected { .. })));
+
+        let mut edited = tombstone.clone();
+        edited.pathology.scars[0].code = Some("NET_TIMEOUT".to_string());
+        assert!(matches!(edited.verify(), Err(GraveyardError::TamperingDetected { .. })));
+
+        let mut edited = tombstone.clone();
+        edited.pathology.scars[0].details.insert("device".to_string(), "sdb".to_string());
+        assert!(matches!(edited.verify(), Err(GraveyardError::TamperingDetected { .. })));
+    }
+
+    #[test]
+    fn legacy_scar_records_read_as_general_with_unchanged_leaves() {
+        let legacy = r#"{"timestamp":"2024-01-01T00:00:00Z","severity":"Minor","description":"Old","context":null}"#;
+        let scar: ScarRecord = serde_json::from_str(legacy).unwrap();
+
+        assert_eq!(scar.category, ScarCategory::General);
+        assert_eq!(scar.code, None);
+        assert!(scar.details.is_empty());
+        assert_eq!(Tombstone::scar_leaf(&scar), format!("scar|{}", legacy));
+    }
+
+    #[test]
+    fn tombstone_seals_full_memory_history() {
+        let chain = sample_tombstone().causal_chain;
+
+        assert_eq!(chain.total_events, sample_history().len());
+        assert_eq!(chain.events.len(), chain.total_events);
+        assert!(Memory::verify_chain(&chain.events));
+        assert_eq!(chain.events[1].description(), "Wrote report");
+        assert!(chain.events.last().unwrap().description().starts_with("TERMINATION"));
+        assert_eq!(
+            chain.event_hashes.last().map(String::as_str),
+            chain.events.last().map(Event::hash)
+        );
+    }
+
+    #[test]
+    fn edited_event_fails_verification() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+
+        let json = serde_json::to_string(&tombstone).unwrap();
+        let tampered: Tombstone =
+            serde_json::from_str(&json.replace("Wrote report", "Wrote nothing")).unwrap();
+
+        assert!(matches!(
+            tampered.verify(),
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn dropped_event_fails_verification() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+
+        tombstone.causal_chain.events.remove(1);
+        assert!(tombstone.verify().is_err());
+
+        // Dropping the digest as well changes the signed root
+        tombstone.causal_chain.event_hashes.remove(1);
+        tombstone.causal_chain.total_events -= 1;
+        assert!(matches!(
+            tombstone.verify(),
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn single_scar_proves_without_the_tombstone() {
+        let keypair = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&keypair);
+
+        // Only the proof travels to the third party
+        let json = serde_json::to_string(&tombstone.scar_proof(0).unwrap()).unwrap();
+        let proof: TombstoneProof = serde_json::from_str(&json).unwrap();
+
+        assert!(matches!(&proof.record, ProvenRecord::Scar(scar) if scar.severity == "Fatal"));
+        assert!(proof.verify().is_ok());
+        assert!(proof.verify_with_key(&keypair.public_key()).is_ok());
+        assert!(proof.verify_with_key(&GraveyardKeypair::generate().public_key()).is_err());
+        assert!(tombstone.scar_proof(1).is_none());
+    }
+
+    #[test]
+    fn fake_root_in_cause_of_death_does_not_forge_proofs() {
+        let forged_scar = ScarRecord {
+            timestamp: Utc::now(),
+            severity: "Minor".to_string(),
+            description: "Never happened".to_string(),
+            context: None,
+            category: ScarCategory::General,
+            code: None,
+            details: BTreeMap::new(),
+        };
+        let forged_tree = MerkleTree::from_leaves(std::iter::once(Tombstone::scar_leaf(&forged_scar)));
+
+        // A genuinely signed tombstone whose free text embeds the forged root
+        let mut tombstone = sample_tombstone();
+        tombstone.pathology.cause_of_death = format!("Disk failure|{}|", forged_tree.root());
+        tombstone.sign(&GraveyardKeypair::generate());
+
+        let mut proof = tombstone.scar_proof(0).unwrap();
+        proof.record = ProvenRecord::Scar(forged_scar);
+        proof.proof = forged_tree.proof(0).unwrap();
+        proof.merkle_root = forged_tree.root();
+
+        assert!(matches!(
+            proof.verify(),
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn single_event_proves_without_the_tombstone() {
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&GraveyardKeypair::generate());
+
+        let proof = tombstone.event_proof(1).unwrap();
+        assert!(proof.verify().is_ok());
+
+        let mut forged = proof.clone();
+        if let ProvenRecord::Event(event) = &mut forged.record {
+            *event = tombstone.causal_chain.events[2].clone();
+        }
+        assert!(matches!(
+            forged.verify(),
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn forged_scar_proof_fails() {
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&GraveyardKeypair::generate());
+
+        let mut proof = tombstone.scar_proof(0).unwrap();
+        if let ProvenRecord::Scar(scar) = &mut proof.record {
+            scar.severity = "Minor".to_string();
+        }
+
+        assert!(matches!(
+            proof.verify(),
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn forged_signature_fails_against_trusted_key() {
+        let trusted = GraveyardKeypair::generate();
+        let forger = GraveyardKeypair::generate();
+        let mut tombstone = sample_tombstone();
+        tombstone.sign(&forger);
+
+        // Self-consistent, but not signed by the graveyard the auditor trusts
+        assert!(tombstone.verify().is_ok());
+        assert!(tombstone.verify_with_key(&trusted.public_key()).is_err());
+    }
+
+    #[test]
+    fn unsigned_tombstone_never_verifies() {
+        let tombstone = sample_tombstone();
+
+        assert!(matches!(
+            tombstone.verify(),
+            Err(GraveyardError::Unsigned { .. })
+        ));
+    }
+
+    fn temp_root() -> PathBuf {
+        std::env::temp_dir().join(format!("lineage-graveyard-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn signed_tombstone(id: &str) -> Tombstone {
+        let mut tombstone = sample_tombstone();
+        tombstone.identity.id = id.to_string();
+        tombstone.sign(&GraveyardKeypair::generate());
+        tombstone
+    }
+
+    #[test]
+    fn independent_graveyards_do_not_share_the_dead() {
+        let root_a = temp_root();
+        let root_b = temp_root();
+        let graveyard_a = Graveyard::open(&root_a).unwrap();
+        let graveyard_b = Graveyard::open(&root_b).unwrap();
+
+        graveyard_a.bury(&signed_tombstone("agent-a")).unwrap();
+
+        assert!(graveyard_a.is_dead("agent-a"));
+        assert!(!graveyard_b.is_dead("agent-a"));
+        assert_eq!(graveyard_a.list_all(), vec!["agent-a".to_string()]);
+        assert!(graveyard_b.list_all().is_empty());
+        assert_eq!(graveyard_a.statistics().unwrap().total_agents, 1);
+        assert_eq!(graveyard_b.statistics().unwrap().total_agents, 0);
+
+        let _ = fs::remove_dir_all(&root_a);
+        let _ = fs::remove_dir_all(&root_b);
+    }
+
+    #[test]
+    fn reopened_graveyard_remembers_its_dead() {
+        let root = temp_root();
+        Graveyard::open(&root)
+            .unwrap()
+            .bury(&signed_tombstone("agent-x"))
+            .unwrap();
+
+        let reopened = Graveyard::open(&root).unwrap();
+        assert!(reopened.is_dead("agent-x"));
+        assert_eq!(reopened.load("agent-x").unwrap().identity.id, "agent-x");
+
+        let result = reopened.bury(&signed_tombstone("agent-x"));
+        assert!(matches!(result, Err(GraveyardError::TombstoneExists { .. })));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn static_shims_delegate_to_the_default_graveyard() {
+        let graveyard = Graveyard::default_instance().unwrap();
+
+        assert_eq!(Graveyard::list_all_default(), graveyard.list_all());
+        assert!(!Graveyard::is_dead_default("never-buried-static-shim"));
+        assert!(matches!(
+            Graveyard::load_default("never-buried-static-shim"),
+            Err(GraveyardError::NotFound { .. })
+        ));
+        assert_eq!(
+            Graveyard::statistics_default().unwrap().total_agents,
+            graveyard.statistics().unwrap().total_agents
+        );
+    }
+
+    /// Unregisters a Lazarus registry when dropped, even if the test panics
+    struct Unguard(String);
+
+    impl Drop for Unguard {
+        fn drop(&mut self) {
+            lazarus::unregister(&self.0);
+        }
+    }
+
+    #[test]
+    fn opened_graveyard_blocks_every_route_back() {
+        use crate::archive::ArchiveError;
+        use crate::lineage::Lineage;
+        use crate::persistence::{PersistenceError, PersistentLineage};
+
+        let root = temp_root();
+        let journals = temp_root();
+        let graveyard = Graveyard::open(&root).unwrap();
+
+        let archived = Lineage::create(100);
+        let archive = archived.export().unwrap();
+        let journaled = PersistentLineage::create(&journals, 100).unwrap();
+        let journaled_id = journaled.lineage().identity().id().to_string();
+        drop(journaled);
+
+        graveyard.bury(&signed_tombstone(archived.identity().id())).unwrap();
+        graveyard.bury(&signed_tombstone(&journaled_id)).unwrap();
+
+        // Opening a graveyard guards it: no registration step required
+        assert!(lazarus::check(archived.identity().id()).is_err());
+        assert!(matches!(
+            Lineage::import(&archive),
+            Err(ArchiveError::IdentityDead { .. })
+        ));
+        assert!(matches!(
+            PersistentLineage::open(&journals, &journaled_id),
+            Err(PersistenceError::IdentityDead { .. })
+        ));
+
+        // Opting out withdraws the veto; re-guarding restores it
+        graveyard.unguard();
+        assert!(lazarus::check(&journaled_id).is_ok());
+        graveyard.guard();
+        assert!(lazarus::check(&journaled_id).is_err());
+
+        drop(graveyard);
+        drop(archived);
+        assert!(Lineage::import(&archive).is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+        let _ = fs::remove_dir_all(&journals);
+    }
+
+    #[test]
+    fn graveyard_over_any_store_guards_its_dead() {
+        use crate::lineage::Lineage;
+        use crate::store::LogStore;
+
+        let path = temp_root().with_extension("log");
+        let graveyard = Graveyard::with_store(Arc::new(LogStore::open(&path).unwrap())).unwrap();
+
+        let lineage = Lineage::create(100);
+        let archive = lineage.export().unwrap();
+        graveyard.bury(&signed_tombstone(lineage.identity().id())).unwrap();
+        drop(lineage);
+
+        assert!(Lineage::import(&archive).is_err());
+
+        drop(graveyard);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn guard_vetoes_agents_and_actors_born_buried() {
+        use crate::agent::TaskAgent;
+        use crate::lineage::Lineage;
+        use crate::trust::TrustedActor;
+
+        /// Treats every identity checked on one thread as buried, standing
+        /// in for a graveyard that already holds the id about to be minted
+        struct BuriedOnThread(std::thread::ThreadId);
+
+        impl DeathRegistry for BuriedOnThread {
+            fn is_dead(&self, _id: &str) -> bool {
+                std::thread::current().id() == self.0
+            }
+        }
+
+        let key = format!("graveyard-test-{}", uuid::Uuid::new_v4());
+        lazarus::register(
+            key.clone(),
+            Arc::new(BuriedOnThread(std::thread::current().id())),
+        );
+        let unguard = Unguard(key);
+
+        assert!(Lineage::try_create(100).is_err());
+        assert!(TaskAgent::try_create(100).is_err());
+        assert!(TrustedActor::try_create(100).is_err());
+        assert!(std::panic::catch_unwind(|| TaskAgent::create(100)).is_err());
+        assert!(std::panic::catch_unwind(|| TrustedActor::create(100)).is_err());
+        assert!(std::panic::catch_unwind(|| TrustedActor::service_account(100)).is_err());
+
+        drop(unguard);
+        assert!(TaskAgent::try_create(100).is_ok());
+        assert!(TrustedActor::try_create(100).is_ok());
+    }
+
+    #[test]
+    fn poisoned_registry_still_reports_the_dead() {
+        let root = temp_root();
+        let graveyard = Graveyard::open(&root).unwrap();
+        graveyard.bury(&signed_tombstone("agent-poisoned")).unwrap();
+
+        let registry = Arc::clone(&graveyard.registry);
+        let _ = std::thread::spawn(move || {
+            let _held = registry.lock().unwrap();
+            panic!("poison the registry");
+        })
+        .join();
+        assert!(graveyard.registry.is_poisoned());
+
+        assert!(graveyard.is_dead("agent-poisoned"));
+        assert!(lazarus::check("agent-poisoned").is_err());
+        assert_eq!(graveyard.list_all(), vec!["agent-poisoned".to_string()]);
+
+        drop(graveyard);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn graveyard_persists_through_an_embedded_log() {
+        use crate::store::LogStore;
+
+        let path = temp_root().with_extension("log");
+        let open = || Graveyard::with_store(Arc::new(LogStore::open(&path).unwrap())).unwrap();
+
+        let graveyard = open();
+        graveyard.bury(&signed_tombstone("agent-log")).unwrap();
+        assert!(matches!(
+            graveyard.bury(&signed_tombstone("agent-log")),
+            Err(GraveyardError::TombstoneExists { .. })
+        ));
+        drop(graveyard);
+
+        let reopened = open();
+        assert!(reopened.is_dead("agent-log"));
+        assert_eq!(reopened.load("agent-log").unwrap().identity.id, "agent-log");
+        assert_eq!(reopened.query().count(), 1);
+
+        drop(reopened);
+        let _ = fs::remove_file(&path);
+    }
+
+    fn indexed_tombstone(id: &str, generation: u32, cause: &str, days_ago: i64) -> Tombstone {
+        let mut tombstone = sample_tombstone();
+        tombstone.identity.id = id.to_string();
+        tombstone.parentage.generation = generation;
+        tombstone.parentage.parent_id = (generation > 0).then(|| "origin".to_string());
+        tombstone.pathology.cause_of_death = cause.to_string();
+        tombstone.pathology.death_timestamp = Utc::now() - chrono::Duration::days(days_ago);
+        tombstone.sign(source_key());
+        tombstone
+    }
+
+    /// Key of the graveyard that bundles in these tests come from
+    fn source_key() -> &'static GraveyardKeypair {
+        static KEY: std::sync::OnceLock<GraveyardKeypair> = std::sync::OnceLock::new();
+        KEY.get_or_init(GraveyardKeypair::generate)
+    }
+
+    #[test]
+    fn query_filters_by_indexed_fields() {
+        let root = temp_root();
+        let graveyard = Graveyard::open(&root).unwrap();
+        graveyard.bury(&indexed_tombstone("gen3-recent", 3, "Disk failure", 1)).unwrap();
+        graveyard.bury(&indexed_tombstone("gen3-old", 3, "Disk failure", 30)).unwrap();
+        graveyard.bury(&indexed_tombstone("gen2-recent", 2, "Disk failure", 2)).unwrap();
+        graveyard.bury(&indexed_tombstone("gen3-other", 3, "Energy depletion", 3)).unwrap();
+
+        let ids = graveyard
+            .query()
+            .generation(3)
+            .cause_severity("Fatal")
+            .cause_of_death("Disk failure")
+            .died_after(Utc::now() - chrono::Duration::days(7))
+            .ids();
+        assert_eq!(ids, vec!["gen3-recent".to_string()]);
+
+        assert_eq!(graveyard.query().parent("origin").count(), 4);
+        assert_eq!(graveyard.query().generation(3).count(), 3);
+        assert_eq!(graveyard.query().cause_severity("Minor").count(), 0);
+        assert_eq!(graveyard.query().min_scars(2).count(), 0);
+
+        // The index survives a reopen
+        let reopened = Graveyard::open(&root).unwrap();
+        let tombstones = reopened
+            .query()
+            .died_before(Utc::now() - chrono::Duration::days(7))
+            .tombstones()
+            .unwrap();
+        assert_eq!(tombstones.len(), 1);
+        assert_eq!(tombstones[0].identity.id, "gen3-old");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn query_paginates_in_stable_order() {
+        let root = temp_root();
+        let graveyard = Graveyard::open(&root).unwrap();
+        for day in 0..5 {
+            let id = format!("agent-{}", day);
+            graveyard.bury(&indexed_tombstone(&id, 0, "Disk failure", day)).unwrap();
+        }
+
+        let first = graveyard.query().limit(2).page();
+        assert_eq!(first.total, 5);
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.items[0].id, "agent-0");
+        assert_eq!(first.next_offset(), Some(2));
+
+        let last = graveyard
+            .query()
+            .order_by(QueryOrder::DeathTimeAsc)
+            .offset(4)
+            .limit(2)
+            .page();
+        assert_eq!(last.items.len(), 1);
+        assert_eq!(last.items[0].id, "agent-0");
+        assert_eq!(last.next_offset(), None);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn bundle_moves_tombstones_between_graveyards() {
+        let source_root = temp_root();
+        let target_root = temp_root();
+        let source = Graveyard::open(&source_root).unwrap();
+        let target = Graveyard::open(&target_root).unwrap();
+        source.bury(&indexed_tombstone("ci-1", 1, "Disk failure", 1)).unwrap();
+        source.bury(&indexed_tombstone("ci-2", 2, "Disk failure", 1)).unwrap();
+        source.bury(&indexed_tombstone("ci-3", 2, "Energy depletion", 1)).unwrap();
+        target.bury(&indexed_tombstone("ci-2", 2, "Disk failure", 1)).unwrap();
+
+        let bundle = source.export(&source.query().cause_of_death("Disk failure")).unwrap();
+        let path = source_root.join("export.bundle");
+        bundle.write_to(&path).unwrap();
+
+        let received = GraveyardBundle::read_from(&path).unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received.manifest().public_keys.len(), 1);
+
+        let report = target.import(&received, &[source_key().public_key()]).unwrap();
+        assert_eq!(report.imported(), vec!["ci-1"]);
+        assert!(matches!(
+            report.rejected()[0].result,
+            Err(GraveyardError::TombstoneExists { .. })
+        ));
+        assert!(target.is_dead("ci-1"));
+        assert!(!target.is_dead("ci-3"));
+
+        let _ = fs::remove_dir_all(&source_root);
+        let _ = fs::remove_dir_all(&target_root);
+    }
+
+    #[test]
+    fn export_runs_a_foreign_query_against_its_own_index() {
+        let source_root = temp_root();
+        let other_root = temp_root();
+        let source = Graveyard::open(&source_root).unwrap();
+        let other = Graveyard::open(&other_root).unwrap();
+        source.bury(&indexed_tombstone("src-1", 1, "Disk failure", 1)).unwrap();
+        source.bury(&indexed_tombstone("src-2", 1, "Energy depletion", 1)).unwrap();
+        other.bury(&indexed_tombstone("other-1", 1, "Disk failure", 1)).unwrap();
+
+        let bundle = source.export(&other.query().cause_of_death("Disk failure")).unwrap();
+        let ids: Vec<_> = bundle.manifest().entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["src-1"]);
+
+        let _ = fs::remove_dir_all(&source_root);
+        let _ = fs::remove_dir_all(&other_root);
+    }
+
+    #[test]
+    fn tampered_bundle_entry_is_rejected() {
+        let source_root = temp_root();
+        let target_root = temp_root();
+        let source = Graveyard::open(&source_root).unwrap();
+        source.bury(&indexed_tombstone("victim", 0, "Disk failure", 1)).unwrap();
+        source.bury(&indexed_tombstone("bystander", 0, "Disk failure", 1)).unwrap();
+
+        let bytes = source.export(&source.query()).unwrap().to_bytes().unwrap();
+
+        // Truncation and a broken manifest reject the whole bundle
+        assert!(GraveyardBundle::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(GraveyardBundle::from_bytes(b"NOTABUNDLE").is_err());
+
+        // Editing one tombstone rejects only that entry
+        let field = b"\"tasks_completed\": 7";
+        let at = bytes.windows(field.len()).position(|window| window == field).unwrap();
+        let mut edited = bytes;
+        edited[at + field.len() - 1] = b'9';
+        let bundle = GraveyardBundle::from_bytes(&edited).unwrap();
+        let report = Graveyard::open(&target_root)
+            .unwrap()
+            .import(&bundle, &[source_key().public_key()])
+            .unwrap();
+
+        assert_eq!(report.imported().len(), 1);
+        assert_eq!(report.rejected().len(), 1);
+        assert!(matches!(
+            report.rejected()[0].result,
+            Err(GraveyardError::TamperingDetected { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&source_root);
+        let _ = fs::remove_dir_all(&target_root);
+    }
+
+    #[test]
+    fn bundle_import_with_untrusted_key_is_rejected() {
+        let source_root = temp_root();
+        let target_root = temp_root();
+        let source = Graveyard::open(&source_root).unwrap();
+        source.bury(&indexed_tombstone("foreign", 0, "Disk failure", 1)).unwrap();
+
+        let bundle = source.export(&source.query()).unwrap();
+        let trusted = GraveyardKeypair::generate().public_key();
+        let report = Graveyard::open(&target_root)
+            .unwrap()
+            .import(&bundle, &[trusted])
+            .unwrap();
+
+        assert!(report.imported().is_empty());
+
+        let _ = fs::remove_dir_all(&source_root);
+        let _ = fs::remove_dir_all(&target_root);
+    }
+
+    #[test]
+    fn self_signed_bundle_is_rejected() {
+        let forger_root = temp_root();
+        let target_root = temp_root();
+        let forger = Graveyard::open(&forger_root).unwrap();
+        let mut forged = indexed_tombstone("living-rival", 0, "Forged death", 1);
+        forged.sign(&GraveyardKeypair::generate());
+        forger.bury(&forged).unwrap();
+
+        // The bundle lists the forger's key, and every signature checks out against it
+        let bundle = forger.export(&forger.query()).unwrap();
+        assert_eq!(bundle.manifest().public_keys, vec![forged.signer_public_key.clone()]);
+
+        let target = Graveyard::open(&target_root).unwrap();
+        let report = target.import(&bundle, &[source_key().public_key()]).unwrap();
+        assert!(report.imported().is_empty());
+        assert!(matches!(report.rejected()[0].result, Err(GraveyardError::KeyError(_))));
+        assert!(!target.is_dead("living-rival"));
+
+        let _ = fs::remove_dir_all(&forger_root);
+        let _ = fs::remove_dir_all(&target_root);
+    }
+
+    #[test]
+    fn unsigned_tombstone_cannot_be_buried() {
+        let root = temp_root();
+        let graveyard = Graveyard::open(&root).unwrap();
+
+        assert!(graveyard.bury(&sample_tombstone()).is_err());
+        assert!(!graveyard.is_dead("agent-under-test"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,129 @@
+//! Tombstone inclusion proofs: prove a single scar or event to a third party.
+//!
+//! A proof carries one record, its Merkle path to the tombstone's root and
+//! the signed tombstone fields. It proves "agent X received scar Y at time T"
+//! without revealing any other scar or event.
+
+use serde::{Deserialize, Serialize};
+
+use crate::graveyard::{GraveyardError, GraveyardPublicKey, ScarRecord, Tombstone, TombstonePayload};
+use crate::memory::Event;
+use crate::merkle::MerkleProof;
+
+/// The single record a `TombstoneProof` discloses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProvenRecord {
+    /// A scar from the pathology report
+    Scar(ScarRecord),
+    /// An event from the sealed memory history
+    Event(Event),
+}
+
+/// Self-contained proof that one record is sealed in a signed tombstone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TombstoneProof {
+    /// ID of the buried agent
+    pub tombstone_id: String,
+    /// The disclosed scar or event
+    pub record: ProvenRecord,
+    /// Merkle path from the record to `merkle_root`
+    pub proof: MerkleProof,
+    /// Causal chain root of the tombstone
+    pub merkle_root: String,
+    /// Tombstone fields covered by the signature
+    pub payload: TombstonePayload,
+    /// Ed25519 signature (hex) over the encoded `payload`
+    pub signature: String,
+    /// Key ID of the signing graveyard keypair
+    pub signer_key_id: String,
+    /// Hex-encoded public key of the signer
+    pub signer_public_key: String,
+}
+
+impl Tombstone {
+    /// Issues an inclusion proof for the scar at `index` in the pathology report
+    pub fn scar_proof(&self, index: usize) -> Option<TombstoneProof> {
+        let scar = self.pathology.scars.get(index)?;
+        self.inclusion_proof(index, ProvenRecord::Scar(scar.clone()))
+    }
+
+    /// Issues an inclusion proof for the memory event at `sequence`
+    pub fn event_proof(&self, sequence: usize) -> Option<TombstoneProof> {
+        let event = self.causal_chain.events.get(sequence)?;
+        self.inclusion_proof(
+            self.pathology.scars.len() + sequence,
+            ProvenRecord::Event(event.clone()),
+        )
+    }
+
+    fn inclusion_proof(&self, leaf_index: usize, record: ProvenRecord) -> Option<TombstoneProof> {
+        let tree = Self::causal_tree(&self.pathology.scars, &self.causal_chain.event_hashes);
+
+        Some(TombstoneProof {
+            tombstone_id: self.identity.id.clone(),
+            record,
+            proof: tree.proof(leaf_index)?,
+            merkle_root: self.causal_chain.merkle_root.clone(),
+            payload: self.payload(),
+            signature: self.signature.clone(),
+            signer_key_id: self.signer_key_id.clone(),
+            signer_public_key: self.signer_public_key.clone(),
+        })
+    }
+}
+
+impl TombstoneProof {
+    /// Verify against the public key recorded in the proof.
+    ///
+    /// Proves the record is sealed in a consistently signed tombstone.
+    /// To prove *which* graveyard signed it, use `verify_with_key`.
+    pub fn verify(&self) -> Result<(), GraveyardError> {
+        if self.signature.is_empty() || self.signer_public_key.is_empty() {
+            return Err(GraveyardError::Unsigned {
+                id: self.tombstone_id.clone(),
+            });
+        }
+
+        let public_key = GraveyardPublicKey::from_hex(&self.signer_public_key)?;
+        self.verify_with_key(&public_key)
+    }
+
+    /// Verify against a trusted graveyard public key.
+    pub fn verify_with_key(&self, public_key: &GraveyardPublicKey) -> Result<(), GraveyardError> {
+        let tampered = || GraveyardError::TamperingDetected {
+            id: self.tombstone_id.clone(),
+        };
+
+        // The record must hash to a leaf under the root
+        let leaf = match &self.record {
+            ProvenRecord::Scar(scar) => Tombstone::scar_leaf(scar),
+            ProvenRecord::Event(event) => {
+                if !event.verify_digest() {
+                    return Err(tampered());
+                }
+                Tombstone::event_leaf(event.hash())
+            }
+        };
+        if !self.proof.verify(leaf.as_bytes(), &self.merkle_root) {
+            return Err(tampered());
+        }
+
+        // The root and ID must be exactly the ones the signed fields hold
+        if self.payload.id != self.tombstone_id
+            || self.payload.merkle_root != self.merkle_root
+            || self.payload.signer_key_id != self.signer_key_id
+        {
+            return Err(tampered());
+        }
+
+        // And the payload must carry a valid signature by that key
+        if public_key.key_id() != self.signer_key_id
+            || !public_key.verify(&self.payload.to_bytes(), &self.signature)
+        {
+            return Err(tampered());
+        }
+
+        Ok(())
+    }
+}
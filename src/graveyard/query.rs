@@ -0,0 +1,422 @@
+//! Graveyard query engine: an in-memory index over tombstone metadata.
+//!
+//! The index is built once when a graveyard is opened and kept current on
+//! every burial, so filtering thousands of dead agents never re-reads
+//! `.tomb` files. Only `GraveyardQuery::tombstones` touches the disk, and
+//! only for the requested page.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::Bound;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::graveyard::{Graveyard, GraveyardError, Tombstone};
+
+/// Indexed metadata of one tombstone
+#[derive(Debug, Clone, Serialize)]
+pub struct TombstoneSummary {
+    /// Agent's unique identifier
+    pub id: String,
+    /// Cause of death (the final scar)
+    pub cause_of_death: String,
+    /// Severity of the final scar, if any
+    pub cause_severity: Option<String>,
+    /// Generation number (0 = origin)
+    pub generation: u32,
+    /// ID of the parent agent, if spawned
+    pub parent_id: Option<String>,
+    /// Creation timestamp
+    pub creation_time: DateTime<Utc>,
+    /// Time of death
+    pub death_timestamp: DateTime<Utc>,
+    /// Total number of scars
+    pub scar_count: usize,
+    /// Efficiency rating: tasks_completed / energy_burned
+    pub efficiency_ratio: f64,
+    /// Legacy score at burial
+    pub legacy_score: f64,
+}
+
+impl TombstoneSummary {
+    /// Extracts the indexed fields from a tombstone
+    pub fn from_tombstone(tombstone: &Tombstone) -> Self {
+        TombstoneSummary {
+            id: tombstone.identity.id.clone(),
+            cause_of_death: tombstone.pathology.cause_of_death.clone(),
+            cause_severity: tombstone
+                .pathology
+                .scars
+                .last()
+                .map(|scar| scar.severity.clone()),
+            generation: tombstone.parentage.generation,
+            parent_id: tombstone.parentage.parent_id.clone(),
+            creation_time: tombstone.identity.creation_time,
+            death_timestamp: tombstone.pathology.death_timestamp,
+            scar_count: tombstone.pathology.scar_count,
+            efficiency_ratio: tombstone.metabolism.efficiency_ratio,
+            legacy_score: tombstone.legacy_score(),
+        }
+    }
+
+    /// Get lifespan in seconds
+    pub fn lifespan_seconds(&self) -> i64 {
+        (self.death_timestamp - self.creation_time).num_seconds()
+    }
+}
+
+/// Secondary indexes over tombstone summaries
+#[derive(Debug, Clone, Default)]
+pub struct GraveyardIndex {
+    summaries: HashMap<String, TombstoneSummary>,
+    by_cause: HashMap<String, BTreeSet<String>>,
+    by_generation: BTreeMap<u32, BTreeSet<String>>,
+    by_parent: HashMap<String, BTreeSet<String>>,
+    by_death_time: BTreeSet<(DateTime<Utc>, String)>,
+}
+
+impl GraveyardIndex {
+    /// Index a tombstone (replaces any previous entry with the same ID)
+    pub fn insert(&mut self, tombstone: &Tombstone) {
+        let summary = TombstoneSummary::from_tombstone(tombstone);
+        let id = summary.id.clone();
+
+        self.by_cause
+            .entry(summary.cause_of_death.clone())
+            .or_default()
+            .insert(id.clone());
+        self.by_generation
+            .entry(summary.generation)
+            .or_default()
+            .insert(id.clone());
+        if let Some(parent_id) = &summary.parent_id {
+            self.by_parent
+                .entry(parent_id.clone())
+                .or_default()
+                .insert(id.clone());
+        }
+        self.by_death_time.insert((summary.death_timestamp, id.clone()));
+        self.summaries.insert(id, summary);
+    }
+
+    /// Get the indexed summary of one tombstone
+    pub fn get(&self, id: &str) -> Option<&TombstoneSummary> {
+        self.summaries.get(id)
+    }
+
+    /// Number of indexed tombstones
+    pub fn len(&self) -> usize {
+        self.summaries.len()
+    }
+
+    /// True if nothing is indexed
+    pub fn is_empty(&self) -> bool {
+        self.summaries.is_empty()
+    }
+
+    /// All indexed summaries, in no particular order
+    pub fn summaries(&self) -> impl Iterator<Item = &TombstoneSummary> {
+        self.summaries.values()
+    }
+
+    /// Summaries matching `filter`, narrowed first by the most selective index
+    fn select(&self, filter: &QueryFilter) -> Vec<&TombstoneSummary> {
+        let mut narrowed: Vec<Vec<&String>> = Vec::new();
+
+        if let Some(cause) = &filter.cause_of_death {
+            narrowed.push(self.by_cause.get(cause).map(|ids| ids.iter().collect()).unwrap_or_default());
+        }
+        if let Some(generation) = filter.generation {
+            narrowed.push(
+                self.by_generation
+                    .get(&generation)
+                    .map(|ids| ids.iter().collect())
+                    .unwrap_or_default(),
+            );
+        }
+        if let Some(parent_id) = &filter.parent_id {
+            narrowed.push(self.by_parent.get(parent_id).map(|ids| ids.iter().collect()).unwrap_or_default());
+        }
+        if filter.died_after.is_some() || filter.died_before.is_some() {
+            let lower = filter
+                .died_after
+                .map_or(Bound::Unbounded, |after| Bound::Included((after, String::new())));
+            narrowed.push(
+                self.by_death_time
+                    .range((lower, Bound::Unbounded))
+                    .take_while(|(time, _)| filter.died_before.is_none_or(|before| *time < before))
+                    .map(|(_, id)| id)
+                    .collect(),
+            );
+        }
+
+        let candidates = narrowed.into_iter().min_by_key(|ids| ids.len());
+
+        match candidates {
+            Some(ids) => ids
+                .into_iter()
+                .filter_map(|id| self.summaries.get(id))
+                .filter(|summary| filter.matches(summary))
+                .collect(),
+            None => self
+                .summaries
+                .values()
+                .filter(|summary| filter.matches(summary))
+                .collect(),
+        }
+    }
+}
+
+/// Result ordering for graveyard queries (ties broken by ID)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueryOrder {
+    /// Most recent deaths first
+    #[default]
+    DeathTimeDesc,
+    /// Oldest deaths first
+    DeathTimeAsc,
+    /// Highest legacy score first
+    LegacyScoreDesc,
+    /// Most scarred first
+    ScarCountDesc,
+    /// Lowest generation first
+    GenerationAsc,
+}
+
+#[derive(Debug, Clone, Default)]
+struct QueryFilter {
+    cause_of_death: Option<String>,
+    cause_severity: Option<String>,
+    generation: Option<u32>,
+    parent_id: Option<String>,
+    died_after: Option<DateTime<Utc>>,
+    died_before: Option<DateTime<Utc>>,
+    min_scars: Option<usize>,
+    max_scars: Option<usize>,
+    min_legacy_score: Option<f64>,
+    max_legacy_score: Option<f64>,
+}
+
+impl QueryFilter {
+    fn matches(&self, summary: &TombstoneSummary) -> bool {
+        self.cause_of_death.as_ref().is_none_or(|cause| *cause == summary.cause_of_death)
+            && self
+                .cause_severity
+                .as_ref()
+                .is_none_or(|severity| summary.cause_severity.as_ref() == Some(severity))
+            && self.generation.is_none_or(|generation| generation == summary.generation)
+            && self
+                .parent_id
+                .as_ref()
+                .is_none_or(|parent_id| summary.parent_id.as_ref() == Some(parent_id))
+            && self.died_after.is_none_or(|after| summary.death_timestamp >= after)
+            && self.died_before.is_none_or(|before| summary.death_timestamp < before)
+            && self.min_scars.is_none_or(|min| summary.scar_count >= min)
+            && self.max_scars.is_none_or(|max| summary.scar_count <= max)
+            && self.min_legacy_score.is_none_or(|min| summary.legacy_score >= min)
+            && self.max_legacy_score.is_none_or(|max| summary.legacy_score <= max)
+    }
+}
+
+/// One page of query results
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryPage {
+    /// Total matches, ignoring pagination
+    pub total: usize,
+    /// Offset of the first item
+    pub offset: usize,
+    /// Requested page size (`None` = unbounded)
+    pub limit: Option<usize>,
+    /// Matching summaries on this page
+    pub items: Vec<TombstoneSummary>,
+}
+
+impl QueryPage {
+    /// Offset of the next page, if there are more matches
+    pub fn next_offset(&self) -> Option<usize> {
+        let next = self.offset + self.items.len();
+        (next < self.total && !self.items.is_empty()).then_some(next)
+    }
+}
+
+/// Query builder over a graveyard's index
+///
+/// ```no_run
+/// use chrono::{Duration, Utc};
+/// use lineage::Graveyard;
+///
+/// let graveyard = Graveyard::open("/tmp/graveyard").unwrap();
+/// let ids = graveyard
+///     .query()
+///     .generation(3)
+///     .cause_severity("Fatal")
+///     .died_after(Utc::now() - Duration::days(7))
+///     .limit(50)
+///     .ids();
+/// ```
+#[derive(Debug, Clone)]
+pub struct GraveyardQuery<'a> {
+    graveyard: &'a Graveyard,
+    filter: QueryFilter,
+    order: QueryOrder,
+    offset: usize,
+    limit: Option<usize>,
+}
+
+impl<'a> GraveyardQuery<'a> {
+    pub(crate) fn new(graveyard: &'a Graveyard) -> Self {
+        GraveyardQuery {
+            graveyard,
+            filter: QueryFilter::default(),
+            order: QueryOrder::default(),
+            offset: 0,
+            limit: None,
+        }
+    }
+
+    /// Only agents whose cause of death is exactly `cause`
+    pub fn cause_of_death(mut self, cause: impl Into<String>) -> Self {
+        self.filter.cause_of_death = Some(cause.into());
+        self
+    }
+
+    /// Only agents whose final scar had this severity (e.g. "Fatal")
+    pub fn cause_severity(mut self, severity: impl Into<String>) -> Self {
+        self.filter.cause_severity = Some(severity.into());
+        self
+    }
+
+    /// Only agents of this generation
+    pub fn generation(mut self, generation: u32) -> Self {
+        self.filter.generation = Some(generation);
+        self
+    }
+
+    /// Only direct offspring of `parent_id`
+    pub fn parent(mut self, parent_id: impl Into<String>) -> Self {
+        self.filter.parent_id = Some(parent_id.into());
+        self
+    }
+
+    /// Only agents that died at or after `time`
+    pub fn died_after(mut self, time: DateTime<Utc>) -> Self {
+        self.filter.died_after = Some(time);
+        self
+    }
+
+    /// Only agents that died strictly before `time`
+    pub fn died_before(mut self, time: DateTime<Utc>) -> Self {
+        self.filter.died_before = Some(time);
+        self
+    }
+
+    /// Only agents with at least `count` scars
+    pub fn min_scars(mut self, count: usize) -> Self {
+        self.filter.min_scars = Some(count);
+        self
+    }
+
+    /// Only agents with at most `count` scars
+    pub fn max_scars(mut self, count: usize) -> Self {
+        self.filter.max_scars = Some(count);
+        self
+    }
+
+    /// Only agents with a legacy score of at least `score`
+    pub fn min_legacy_score(mut self, score: f64) -> Self {
+        self.filter.min_legacy_score = Some(score);
+        self
+    }
+
+    /// Only agents with a legacy score of at most `score`
+    pub fn max_legacy_score(mut self, score: f64) -> Self {
+        self.filter.max_legacy_score = Some(score);
+        self
+    }
+
+    /// Result ordering (default: most recent deaths first)
+    pub fn order_by(mut self, order: QueryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Skip the first `offset` matches
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Return at most `limit` matches
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The same criteria, order and pagination evaluated against `graveyard`
+    pub(crate) fn against<'b>(&self, graveyard: &'b Graveyard) -> GraveyardQuery<'b> {
+        GraveyardQuery {
+            graveyard,
+            filter: self.filter.clone(),
+            order: self.order,
+            offset: self.offset,
+            limit: self.limit,
+        }
+    }
+
+    /// Total matches, ignoring pagination
+    pub fn count(&self) -> usize {
+        self.graveyard
+            .with_index(|index| index.select(&self.filter).len())
+    }
+
+    /// Execute and return one page of summaries
+    pub fn page(&self) -> QueryPage {
+        self.graveyard.with_index(|index| {
+            let mut matches = index.select(&self.filter);
+            let total = matches.len();
+            self.sort(&mut matches);
+
+            let items = matches
+                .into_iter()
+                .skip(self.offset)
+                .take(self.limit.unwrap_or(usize::MAX))
+                .cloned()
+                .collect();
+
+            QueryPage {
+                total,
+                offset: self.offset,
+                limit: self.limit,
+                items,
+            }
+        })
+    }
+
+    /// Execute and return matching summaries
+    pub fn summaries(&self) -> Vec<TombstoneSummary> {
+        self.page().items
+    }
+
+    /// Execute and return matching IDs
+    pub fn ids(&self) -> Vec<String> {
+        self.summaries().into_iter().map(|summary| summary.id).collect()
+    }
+
+    /// Execute and load the matching tombstones from disk
+    pub fn tombstones(&self) -> Result<Vec<Tombstone>, GraveyardError> {
+        self.ids().iter().map(|id| self.graveyard.load(id)).collect()
+    }
+
+    fn sort(&self, matches: &mut [&TombstoneSummary]) {
+        matches.sort_by(|a, b| {
+            let primary = match self.order {
+                QueryOrder::DeathTimeDesc => b.death_timestamp.cmp(&a.death_timestamp),
+                QueryOrder::DeathTimeAsc => a.death_timestamp.cmp(&b.death_timestamp),
+                QueryOrder::LegacyScoreDesc => b.legacy_score.total_cmp(&a.legacy_score),
+                QueryOrder::ScarCountDesc => b.scar_count.cmp(&a.scar_count),
+                QueryOrder::GenerationAsc => a.generation.cmp(&b.generation),
+            };
+            primary.then_with(|| a.id.cmp(&b.id))
+        });
+    }
+}
@@ -0,0 +1,192 @@
+//! Graveyard signing keys: Ed25519 keypairs that seal tombstones.
+//!
+//! The secret half never leaves the burying host. Auditors only need the
+//! public half (or its key ID) to verify a tombstone.
+
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+
+use crate::graveyard::GraveyardError;
+
+/// File name of the secret signing key inside a keys directory
+const SECRET_KEY_FILE: &str = "graveyard.ed25519";
+/// File name of the public verifying key inside a keys directory
+const PUBLIC_KEY_FILE: &str = "graveyard.pub";
+
+/// Derives a short, stable identifier for a public key
+fn key_id_for(verifying_key: &VerifyingKey) -> String {
+    let digest = Sha256::digest(verifying_key.as_bytes());
+    hex::encode(&digest[..8])
+}
+
+/// Ed25519 keypair used by a graveyard to sign tombstones
+pub struct GraveyardKeypair {
+    signing_key: SigningKey,
+}
+
+impl GraveyardKeypair {
+    /// Generate a fresh keypair from OS entropy
+    pub fn generate() -> Self {
+        GraveyardKeypair {
+            signing_key: SigningKey::generate(&mut OsRng),
+        }
+    }
+
+    /// Rebuild a keypair from its hex-encoded 32-byte secret
+    pub fn from_secret_hex(secret: &str) -> Result<Self, GraveyardError> {
+        let bytes: [u8; 32] = hex::decode(secret.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| GraveyardError::KeyError("Invalid graveyard secret key".to_string()))?;
+
+        Ok(GraveyardKeypair {
+            signing_key: SigningKey::from_bytes(&bytes),
+        })
+    }
+
+    /// Default directory holding the local graveyard keypair
+    pub fn default_dir() -> PathBuf {
+        PathBuf::from(".lineage/keys")
+    }
+
+    /// Load the keypair from `dir`, generating and storing one if absent.
+    ///
+    /// A new secret is written to a private temp file (owner-only on Unix,
+    /// never readable by others, not even briefly), fsynced, and then
+    /// published under its final name with a hard link, which never replaces
+    /// an existing key. Concurrent first uses therefore all end up with the
+    /// one key that won, and a secret file is never seen half-written. The
+    /// public key is written alongside it for distribution to auditors.
+    pub fn load_or_create(dir: &Path) -> Result<Self, GraveyardError> {
+        fs::create_dir_all(dir).map_err(|e| {
+            GraveyardError::DirectoryError(format!("Failed to create keys directory: {}", e))
+        })?;
+
+        let secret_path = dir.join(SECRET_KEY_FILE);
+        if !secret_path.exists() {
+            Self::publish_secret(dir, &secret_path)?;
+        }
+
+        let secret = fs::read_to_string(&secret_path)
+            .map_err(|e| GraveyardError::IoError(format!("Failed to read signing key: {}", e)))?;
+        let keypair = Self::from_secret_hex(&secret)?;
+
+        let public_path = dir.join(PUBLIC_KEY_FILE);
+        if !public_path.exists() {
+            let temp_path = dir.join(format!("{}.{}.tmp", PUBLIC_KEY_FILE, uuid::Uuid::new_v4()));
+            fs::write(&temp_path, keypair.public_key().to_hex())
+                .and_then(|_| fs::rename(&temp_path, &public_path))
+                .map_err(|e| GraveyardError::IoError(format!("Failed to write public key: {}", e)))?;
+        }
+
+        Ok(keypair)
+    }
+
+    /// Write a fresh secret to a temp file and link it into place unless
+    /// another writer got there first
+    fn publish_secret(dir: &Path, secret_path: &Path) -> Result<(), GraveyardError> {
+        let write_error =
+            |e: std::io::Error| GraveyardError::IoError(format!("Failed to write signing key: {}", e));
+
+        let temp_path = dir.join(format!("{}.{}.tmp", SECRET_KEY_FILE, uuid::Uuid::new_v4()));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600); // rw-------
+        }
+
+        let written = options.open(&temp_path).and_then(|mut file| {
+            file.write_all(hex::encode(Self::generate().signing_key.to_bytes()).as_bytes())?;
+            file.sync_all()
+        });
+        let published = written.and_then(|_| match fs::hard_link(&temp_path, secret_path) {
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(()),
+            result => result,
+        });
+        let _ = fs::remove_file(&temp_path);
+
+        published.map_err(write_error)
+    }
+
+    /// The public half, safe to hand to auditors
+    pub fn public_key(&self) -> GraveyardPublicKey {
+        GraveyardPublicKey {
+            verifying_key: self.signing_key.verifying_key(),
+        }
+    }
+
+    /// Identifier of this keypair (derived from the public key)
+    pub fn key_id(&self) -> String {
+        key_id_for(&self.signing_key.verifying_key())
+    }
+
+    /// Sign a message, returning the hex-encoded signature
+    pub(crate) fn sign(&self, message: &[u8]) -> String {
+        hex::encode(self.signing_key.sign(message).to_bytes())
+    }
+}
+
+impl std::fmt::Debug for GraveyardKeypair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GraveyardKeypair")
+            .field("key_id", &self.key_id())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Ed25519 public key used to verify tombstones without the secret
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraveyardPublicKey {
+    verifying_key: VerifyingKey,
+}
+
+impl GraveyardPublicKey {
+    /// Parse a hex-encoded 32-byte public key
+    pub fn from_hex(public_key: &str) -> Result<Self, GraveyardError> {
+        let bytes: [u8; 32] = hex::decode(public_key.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| GraveyardError::KeyError("Invalid graveyard public key".to_string()))?;
+
+        let verifying_key = VerifyingKey::from_bytes(&bytes)
+            .map_err(|e| GraveyardError::KeyError(format!("Invalid graveyard public key: {}", e)))?;
+
+        Ok(GraveyardPublicKey { verifying_key })
+    }
+
+    /// Load a public key previously written by `GraveyardKeypair::load_or_create`
+    pub fn load(dir: &Path) -> Result<Self, GraveyardError> {
+        let content = fs::read_to_string(dir.join(PUBLIC_KEY_FILE))
+            .map_err(|e| GraveyardError::IoError(format!("Failed to read public key: {}", e)))?;
+        Self::from_hex(&content)
+    }
+
+    /// Hex encoding of the public key
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.verifying_key.as_bytes())
+    }
+
+    /// Identifier of this key (matches the signer's `key_id`)
+    pub fn key_id(&self) -> String {
+        key_id_for(&self.verifying_key)
+    }
+
+    /// Verify a hex-encoded signature over a message
+    pub fn verify(&self, message: &[u8], signature: &str) -> bool {
+        let bytes: [u8; 64] = match hex::decode(signature).ok().and_then(|b| b.try_into().ok()) {
+            Some(bytes) => bytes,
+            None => return false,
+        };
+
+        self.verifying_key
+            .verify(message, &Signature::from_bytes(&bytes))
+            .is_ok()
+    }
+}
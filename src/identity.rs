@@ -4,6 +4,8 @@
 //! - One-time identity creation with cryptographic uniqueness
 //! - Identity cannot be cloned, copied, or duplicated
 //! - Identity is bound to creation moment and entropy source
+//! - A restored identity is refused while the same identity is live in
+//!   this process (across processes, the journal lock plays this role)
 //!
 //! ## What This Forbids
 //! - Clone or Copy traits
@@ -13,10 +15,42 @@
 //! ## Violations
 //! Any attempt to implement Clone/Copy constitutes an ontological violation.
 
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
 use sha2::{Digest, Sha256};
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, SystemClock};
+use crate::lazarus::LazarusViolation;
 use uuid::Uuid;
 
+/// Ids of every identity currently live in this process
+static LIVE: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+fn live() -> std::sync::MutexGuard<'static, BTreeSet<String>> {
+    LIVE.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Why a persisted identity could not be rebuilt
+#[derive(Debug)]
+pub(crate) enum RestoreError {
+    /// The identity is buried and Lazarus refuses to bring it back
+    Buried(LazarusViolation),
+    /// The creation context does not prove the id, or the id is already live
+    Invalid(String),
+}
+
+impl std::fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreError::Buried(violation) => write!(f, "{}", violation),
+            RestoreError::Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
 /// An irreversible, non-copyable identity.
 /// 
 /// Once created, this identity is bound to its creation context forever.
@@ -44,10 +78,24 @@ impl Identity {
     /// 
     /// **CONSEQUENCE**: This identity can never be recreated identically.
     pub fn create() -> Self {
-        let birth_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards - ontological violation")
-            .as_nanos();
+        Self::create_with_clock(&SystemClock)
+    }
+
+    /// Creates a new identity whose birth time is read from `clock`.
+    /// 
+    /// The entropy is still random, so a pinned clock never yields two
+    /// equal identities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clock` breaks the `Clock` contract by reporting an instant
+    /// before 1970 or past 2262. `ManualClock` refuses such instants up front.
+    pub fn create_with_clock(clock: &dyn Clock) -> Self {
+        let birth_time = clock
+            .now()
+            .timestamp_nanos_opt()
+            .and_then(|nanos| u128::try_from(nanos).ok())
+            .expect("Clock reported an instant outside the representable range");
 
         let uuid = Uuid::new_v4();
         let mut entropy = [0u8; 32];
@@ -59,14 +107,8 @@ impl Identity {
             entropy[i + 16] = byte.wrapping_add(i as u8);
         }
 
-        // Create irreversible hash of all creation context
-        let mut hasher = Sha256::new();
-        hasher.update(birth_time.to_le_bytes());
-        hasher.update(&entropy);
-        hasher.update(uuid_bytes);
-        
-        let hash = hasher.finalize();
-        let id = format!("{:x}", hash);
+        let id = Self::derive_id(birth_time, &entropy);
+        live().insert(id.clone());
 
         Identity {
             id,
@@ -89,6 +131,59 @@ impl Identity {
         self.birth_time
     }
 
+    /// Returns the entropy captured at creation.
+    /// 
+    /// Only exposed inside the crate so durable journals can prove the
+    /// identity hash when the lineage is reloaded.
+    pub(crate) fn entropy(&self) -> &[u8; 32] {
+        &self.entropy
+    }
+
+    /// Rebuilds an identity from its persisted creation context.
+    /// 
+    /// This is NOT a way to mint identities: the id is recomputed from the
+    /// birth time and entropy and must match the persisted id exactly.
+    /// Fails while the same identity is live in this process: importing the
+    /// export of a living lineage would otherwise fork it.
+    pub(crate) fn restore(id: &str, birth_time: u128, entropy: [u8; 32]) -> Result<Self, RestoreError> {
+        // LAZARUS CHECK: a buried identity is never reconstituted
+        crate::lazarus::check(id).map_err(RestoreError::Buried)?;
+
+        let derived = Self::derive_id(birth_time, &entropy);
+        if derived != id {
+            return Err(RestoreError::Invalid(format!(
+                "Identity corruption: persisted id {} does not match its creation context",
+                id
+            )));
+        }
+
+        if !live().insert(derived.clone()) {
+            return Err(RestoreError::Invalid(format!(
+                "Identity {} is already live and cannot exist in two places",
+                derived
+            )));
+        }
+
+        Ok(Identity {
+            id: derived,
+            birth_time,
+            entropy,
+        })
+    }
+
+    /// Creates the irreversible hash of all creation context.
+    /// 
+    /// The first 16 entropy bytes are the UUID the identity was created from.
+    fn derive_id(birth_time: u128, entropy: &[u8; 32]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(birth_time.to_le_bytes());
+        hasher.update(entropy);
+        hasher.update(&entropy[..16]);
+        
+        let hash = hasher.finalize();
+        format!("{:x}", hash)
+    }
+
     /// Verifies that another identity reference is the exact same instance.
     /// 
     /// Note: This is pointer equality, not value equality.
@@ -98,10 +193,17 @@ impl Identity {
     }
 }
 
+impl Drop for Identity {
+    fn drop(&mut self) {
+        live().remove(&self.id);
+    }
+}
+
 // EXPLICIT PREVENTION: No Clone
 // EXPLICIT PREVENTION: No Copy
 // EXPLICIT PREVENTION: No PartialEq (identities are never "equal")
 // EXPLICIT PREVENTION: No Default (identity must be explicitly created)
+// EXPLICIT PREVENTION: No Deserialize (identity is only rebuilt via `restore`)
 
 #[cfg(test)]
 mod tests {
@@ -188,4 +290,30 @@ mod tests {
         let same_hash = id.id();
         assert_eq!(original_hash, same_hash);
     }
+
+    #[test]
+    fn restoring_a_buried_identity_keeps_the_lazarus_violation() {
+        struct Buried(String);
+
+        impl crate::lazarus::DeathRegistry for Buried {
+            fn is_dead(&self, id: &str) -> bool {
+                id == self.0
+            }
+        }
+
+        let identity = Identity::create();
+        let (id, birth_time, entropy) =
+            (identity.id().to_string(), identity.birth_time(), *identity.entropy());
+        drop(identity);
+
+        let key = format!("identity-test-{}", id);
+        crate::lazarus::register(key.clone(), std::sync::Arc::new(Buried(id.clone())));
+        let restored = Identity::restore(&id, birth_time, entropy);
+        crate::lazarus::unregister(&key);
+
+        match restored {
+            Err(RestoreError::Buried(violation)) => assert_eq!(violation.id, id),
+            other => panic!("expected a Lazarus violation, got {:?}", other),
+        }
+    }
 }
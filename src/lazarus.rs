@@ -0,0 +1,109 @@
+//! # Lazarus Guard
+//!
+//! The single checkpoint every identity passes through before it may act.
+//!
+//! ## What This Enforces
+//! - `Lineage::create`, `TaskAgent::create`, `TrustedActor::create` and
+//!   `Identity::restore` consult the guard
+//! - Every importer and loader (archives, journals) consults the guard
+//! - Any registered death registry can veto an identity
+//!
+//! ## What This Forbids
+//! - Restoring, importing or reopening an identity that has been buried
+//! - Minting a fresh identity that collides with a buried one
+//!
+//! ## Registries
+//! The guard knows nothing about storage. Death registries (in practice,
+//! graveyards) register themselves under a key: every graveyard does so
+//! when opened and withdraws when dropped or `unguard`ed. Registering under
+//! an existing key replaces the previous registry.
+
+use std::sync::{Arc, RwLock};
+
+/// Anything that knows which identities are dead
+pub trait DeathRegistry: Send + Sync {
+    /// True if `id` has died and must never act again
+    fn is_dead(&self, id: &str) -> bool;
+}
+
+/// Registered death registries, by key
+static REGISTRIES: RwLock<Vec<(String, Arc<dyn DeathRegistry>)>> = RwLock::new(Vec::new());
+
+/// An attempt to bring a buried identity back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazarusViolation {
+    /// The buried identity
+    pub id: String,
+}
+
+impl std::fmt::Display for LazarusViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Lazarus violation: identity {} is buried and cannot be reborn",
+            self.id
+        )
+    }
+}
+
+impl std::error::Error for LazarusViolation {}
+
+/// Register a death registry under `key`, replacing any registry with that key
+pub fn register(key: impl Into<String>, registry: Arc<dyn DeathRegistry>) {
+    let key = key.into();
+    let mut registries = REGISTRIES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registries.retain(|(existing, _)| *existing != key);
+    registries.push((key, registry));
+}
+
+/// Remove the registry registered under `key`
+pub fn unregister(key: &str) {
+    REGISTRIES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .retain(|(existing, _)| existing != key);
+}
+
+/// The Lazarus check: fails if any registered registry knows `id` is dead
+pub fn check(id: &str) -> Result<(), LazarusViolation> {
+    let registries = REGISTRIES
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if registries.iter().any(|(_, registry)| registry.is_dead(id)) {
+        return Err(LazarusViolation { id: id.to_string() });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Buried(&'static str);
+
+    impl DeathRegistry for Buried {
+        fn is_dead(&self, id: &str) -> bool {
+            id == self.0
+        }
+    }
+
+    #[test]
+    fn registered_registry_vetoes_the_dead() {
+        register("lazarus-test", Arc::new(Buried("lazarus-test-dead")));
+
+        assert_eq!(
+            check("lazarus-test-dead"),
+            Err(LazarusViolation {
+                id: "lazarus-test-dead".to_string()
+            })
+        );
+        assert!(check("lazarus-test-alive").is_ok());
+
+        unregister("lazarus-test");
+        assert!(check("lazarus-test-dead").is_ok());
+    }
+}
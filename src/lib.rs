@@ -54,34 +54,64 @@
 //! ## Architecture
 //!
 //! - [`identity`] - Unique, non-copyable identity system
+//! - [`clock`] - Injectable time source (system or manual)
 //! - [`memory`] - Append-only causal event log
+//! - [`merkle`] - Binary Merkle trees with inclusion proofs
 //! - [`metabolism`] - Finite energy and death
 //! - [`scar`] - Permanent error recording
 //! - [`lineage`] - Complete lifecycle orchestration
+//! - [`lazarus`] - The single checkpoint refusing buried identities
+//! - [`genealogy`] - Family forest of agents: ancestors, descendants, dynasties
+//! - [`archive`] - Versioned export and verified import of whole lineages
+//! - [`persistence`] - Durable, append-only journals for living lineages
+//! - [`store`] - Pluggable append-only storage for graveyards, ledgers and vaults
+//! - [`violation`] - Quarantine and the process-wide ontological violation policy
+//! - [`policy`] - Declarative rules mapping named actions to required capabilities
+//! - [`anomaly`] - Threshold-based detection that raises trust violations automatically
 
+pub mod clock;
 pub mod identity;
 pub mod memory;
+pub mod merkle;
 pub mod metabolism;
 pub mod scar;
 pub mod lineage;
+pub mod lazarus;
 pub mod behavior;
 pub mod trust;
+pub mod policy;
+pub mod anomaly;
 pub mod agent;
 pub mod graveyard;
+pub mod genealogy;
+pub mod archive;
+pub mod persistence;
+pub mod store;
 pub mod finance;
 pub mod governance;
 pub mod provenance;
+pub mod violation;
 
 // Re-export main types for convenience
 pub use lineage::{Lineage, OperationResult, OperationError, LineageStatus};
+pub use clock::{Clock, SystemClock, ManualClock, SharedClock};
 pub use identity::Identity;
+pub use lazarus::{DeathRegistry, LazarusViolation};
 pub use memory::{Memory, Event};
-pub use metabolism::{Metabolism, ConsumptionResult};
-pub use scar::{ScarTissue, Scar, ScarSeverity};
-pub use behavior::{PulseBehavior, PulseOutput};
-pub use trust::{TrustedActor, TrustCapability, TrustResult, ViolationType};
+pub use merkle::{MerkleTree, MerkleProof};
+pub use metabolism::{Metabolism, ConsumptionResult, Reservation, AgingModel, AgingState};
+pub use scar::{ScarTissue, Scar, ScarCategory, ScarSeverity, DamageModel, SeverityTable, RecencyWeighted, Compounding};
+pub use behavior::{Behavior, BehaviorOutcome, PulseBehavior, PulseOutput};
+pub use trust::{TrustedActor, TrustCapability, TrustResult, ViolationType, Delegation, DelegationError};
 pub use agent::{TaskAgent, Task, TaskOutcome, TaskResult};
-pub use graveyard::{Graveyard, Tombstone, GraveyardError, IdentityBlock, MetabolicRecord, PathologyReport};
+pub use graveyard::{Graveyard, GraveyardQuery, QueryOrder, QueryPage, TombstoneSummary, Tombstone, GraveyardError, IdentityBlock, MetabolicRecord, PathologyReport, GraveyardKeypair, GraveyardPublicKey, TombstoneProof, ProvenRecord, GraveyardBundle, ImportReport};
+pub use genealogy::{Genealogy, FamilyMember, MemberStatus};
+pub use archive::{LineageArchive, ArchiveError, ARCHIVE_SCHEMA_VERSION};
+pub use persistence::{PersistentLineage, LineageJournal, JournalRecord, PersistenceError};
+pub use store::{LineageStore, FileStore, LogStore, StoreError};
+pub use violation::{OntologicalViolation, ViolationPolicy, set_violation_policy};
+pub use policy::{PolicySet, ActionPolicy, PolicyError};
+pub use anomaly::{AnomalyDetector, AnomalyConfig, AnomalyConfigError, AnomalyReport, AnomalySignal, Threshold};
 
 // Finance module re-exports
 pub use finance::{
@@ -89,7 +119,8 @@ pub use finance::{
     Trade, TradeDirection, TradeResult, TradeOperation, ExecutionError,
     FinancialScar, ScarImpact, FinancialDamage,
     Offspring, OffspringTraits, InheritanceStrategy,
-    PerformanceScore, TrustFormula, TrustGrant,
+    PerformanceScore, TrustFormula, TrustGrant, TrustPermission, TrustRecord,
+    TrustBridge, GrantDowngrade,
     Arena, CompetitionResult, MarketState,
     BlockchainHook, EvolutionaryStrategy,
     ResurrectionMechanic, ResurrectionRecord,
@@ -17,10 +17,18 @@
 //! - Memory corruption terminates the process
 //! - Identity violations terminate the process
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::behavior::{Behavior, BehaviorOutcome};
+use crate::clock::{self, SharedClock};
 use crate::identity::Identity;
+use crate::lazarus::LazarusViolation;
 use crate::memory::Memory;
-use crate::metabolism::{ConsumptionResult, Metabolism};
-use crate::scar::{ScarSeverity, ScarTissue};
+use chrono::{DateTime, Utc};
+
+use crate::metabolism::{AgingModel, ConsumptionResult, Metabolism, Reservation};
+use crate::scar::{DamageModel, Scar, ScarCategory, ScarSeverity, ScarTissue};
 
 /// Result of a lineage operation.
 #[derive(Debug, PartialEq)]
@@ -40,11 +48,17 @@ pub enum OperationResult {
 /// **INVARIANT**: Severity cannot be modified after creation.
 /// **INVARIANT**: Description cannot be modified after creation.
 /// **INVARIANT**: Context cannot be modified after creation.
+/// 
+/// Category, code and details classify the resulting scar so it can be
+/// queried and weighted alongside scars from other subsystems.
 #[derive(Debug)]
 pub struct OperationError {
     severity: ScarSeverity,
     description: String,
     context: Option<String>,
+    category: ScarCategory,
+    code: Option<String>,
+    details: BTreeMap<String, String>,
 }
 
 impl OperationError {
@@ -56,9 +70,30 @@ impl OperationError {
             severity,
             description,
             context: None,
+            category: ScarCategory::General,
+            code: None,
+            details: BTreeMap::new(),
         }
     }
 
+    /// Classifies this error under `category`. Consumes self.
+    pub fn with_category(mut self, category: ScarCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Attaches a machine-readable code (e.g. `TRUST_POLICY_BYPASS`). Consumes self.
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Attaches one key/value detail. Consumes self.
+    pub fn with_detail(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.insert(key.into(), value.into());
+        self
+    }
+
     /// Adds context to this error. Consumes self and returns new instance.
     /// 
     /// **CONSEQUENCE**: Context is final once set.
@@ -81,6 +116,34 @@ impl OperationError {
     pub fn context(&self) -> Option<&str> {
         self.context.as_deref()
     }
+
+    /// Returns the category of this error.
+    pub fn category(&self) -> ScarCategory {
+        self.category
+    }
+
+    /// Returns the machine-readable code, if any.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// Returns all key/value details.
+    pub fn details(&self) -> &BTreeMap<String, String> {
+        &self.details
+    }
+
+    /// Builds the scar this error leaves behind (timestamped on infliction).
+    fn into_scar(self) -> Scar {
+        let mut scar = Scar::new(self.severity, self.description, self.context)
+            .with_category(self.category);
+        if let Some(code) = self.code {
+            scar = scar.with_code(code);
+        }
+        for (key, value) in self.details {
+            scar = scar.with_detail(key, value);
+        }
+        scar
+    }
 }
 
 /// A complete lineage with identity, memory, metabolism, and scars.
@@ -101,6 +164,10 @@ pub struct Lineage {
     metabolism: Metabolism,
     /// Permanent scar tissue
     scars: ScarTissue,
+    /// Source of every timestamp this lineage records
+    clock: SharedClock,
+    /// Behaviors this lineage can run by name
+    behaviors: BTreeMap<String, Box<dyn Behavior>>,
 }
 
 impl Lineage {
@@ -108,9 +175,40 @@ impl Lineage {
     /// 
     /// **CONSEQUENCE**: This creates a unique identity that can never be recreated.
     /// **CONSEQUENCE**: This energy budget is final and cannot be increased.
+    ///
+    /// **LAZARUS CHECK**: Panics if the new identity is already buried
+    /// (see `try_create`).
     pub fn create(initial_energy: u64) -> Self {
-        let identity = Identity::create();
-        let mut memory = Memory::new();
+        match Self::try_create(initial_energy) {
+            Ok(lineage) => lineage,
+            Err(violation) => panic!("ONTOLOGICAL ERROR: {}", violation),
+        }
+    }
+
+    /// Creates a new lineage, refusing identities the Lazarus guard knows are dead.
+    pub fn try_create(initial_energy: u64) -> Result<Self, LazarusViolation> {
+        Self::try_create_with_clock(initial_energy, clock::system())
+    }
+
+    /// Creates a new lineage whose birth, events, scars and aging follow `clock`.
+    ///
+    /// **LAZARUS CHECK**: Panics like `create` if the identity is buried.
+    pub fn create_with_clock(initial_energy: u64, clock: SharedClock) -> Self {
+        match Self::try_create_with_clock(initial_energy, clock) {
+            Ok(lineage) => lineage,
+            Err(violation) => panic!("ONTOLOGICAL ERROR: {}", violation),
+        }
+    }
+
+    /// Creates a new lineage on `clock`, refusing buried identities.
+    pub fn try_create_with_clock(
+        initial_energy: u64,
+        clock: SharedClock,
+    ) -> Result<Self, LazarusViolation> {
+        let identity = Identity::create_with_clock(clock.as_ref());
+        crate::lazarus::check(identity.id())?;
+
+        let mut memory = Memory::with_clock(clock.clone());
         
         memory.append(format!(
             "Lineage created with identity {} and {} energy units",
@@ -118,12 +216,116 @@ impl Lineage {
             initial_energy
         ));
 
-        Lineage {
+        Ok(Lineage {
+            identity,
+            memory,
+            metabolism: Metabolism::with_clock(initial_energy, clock.clone()),
+            scars: ScarTissue::with_clock(clock.clone()),
+            clock,
+            behaviors: BTreeMap::new(),
+        })
+    }
+
+    /// Reassembles a lineage from restored components.
+    /// 
+    /// **INVARIANT**: The result must pass `verify_invariants`; a lineage
+    /// whose parts contradict each other is refused rather than repaired.
+    pub(crate) fn restore(
+        identity: Identity,
+        memory: Memory,
+        metabolism: Metabolism,
+        scars: ScarTissue,
+    ) -> Result<Self, String> {
+        let lineage = Lineage {
             identity,
             memory,
-            metabolism: Metabolism::new(initial_energy),
-            scars: ScarTissue::new(),
+            metabolism,
+            scars,
+            clock: clock::system(),
+            behaviors: BTreeMap::new(),
+        };
+
+        lineage.verify_invariants()?;
+
+        if lineage.metabolism.is_dead() && !lineage.memory.is_terminated() {
+            return Err("Memory corruption: dead lineage has unsealed memory".to_string());
+        }
+
+        lineage.verify_against_history()?;
+
+        Ok(lineage)
+    }
+
+    /// Replays the events this lineage wrote about itself and checks that
+    /// the restored energy, scars and death agree with them.
+    ///
+    /// The digest chain proves the events were not rewritten; this proves
+    /// the state beside them was not rolled back. A history cut back to an
+    /// earlier event is only accepted with the state it recorded there.
+    fn verify_against_history(&self) -> Result<(), String> {
+        let mut recorded_energy = self.metabolism.initial_energy();
+        let mut scar_events = 0;
+        let mut fatal = false;
+
+        for event in self.memory.history() {
+            let description = event.description();
+
+            if let Some(initial) = description
+                .strip_prefix("Lineage created with identity ")
+                .and_then(|rest| rest.rsplit_once(" and "))
+                .and_then(|(_, rest)| rest.strip_suffix(" energy units"))
+                .and_then(|initial| initial.parse::<u64>().ok())
+                && initial != self.metabolism.initial_energy()
+            {
+                return Err(format!(
+                    "Metabolism corruption: history records {} initial energy, state has {}",
+                    initial,
+                    self.metabolism.initial_energy()
+                ));
+            }
+
+            let spends = ["Operation: ", "Aging: ", "Reservation forfeit: "]
+                .iter()
+                .any(|prefix| description.starts_with(prefix));
+            if spends
+                && let Some(remaining) = Self::recorded_remaining(description)
+            {
+                recorded_energy = recorded_energy.min(remaining);
+            }
+
+            if let Some(scar) = description.strip_prefix("Scar inflicted: ") {
+                scar_events += 1;
+                fatal |= scar.starts_with("Fatal");
+            }
+        }
+
+        if self.metabolism.energy() > recorded_energy {
+            return Err(format!(
+                "Metabolism corruption: history records at most {} energy remaining, state has {}",
+                recorded_energy,
+                self.metabolism.energy()
+            ));
+        }
+
+        if self.scars.scar_count() != scar_events {
+            return Err(format!(
+                "Scar corruption: history records {} scars, state has {}",
+                scar_events,
+                self.scars.scar_count()
+            ));
+        }
+
+        if (fatal || recorded_energy == 0) && !self.metabolism.is_dead() {
+            return Err("Metabolism corruption: history records a death the state does not".to_string());
         }
+
+        Ok(())
+    }
+
+    /// Parses the `remaining: N)` suffix of an energy event
+    fn recorded_remaining(description: &str) -> Option<u64> {
+        let (_, rest) = description.rsplit_once("remaining: ")?;
+        rest.strip_suffix(')')?.parse().ok()
     }
 
     /// Returns the immutable identity.
@@ -153,6 +355,33 @@ impl Lineage {
         &self.scars
     }
 
+    /// Weights damage from one scar category (never below 1.0 or its current weight).
+    /// 
+    /// **CONSEQUENCE**: Scars can only be made to count more, never less.
+    pub fn set_scar_weight(&mut self, category: ScarCategory, weight: f64) {
+        self.scars.set_category_weight(category, weight);
+    }
+
+    /// Prices this lineage's scars with `model` (see `ScarTissue::set_damage_model`).
+    /// 
+    /// **CONSEQUENCE**: The change is recorded in memory; every scar remains.
+    /// Fails if the lineage is terminated or if `model` would price the
+    /// existing scars lower.
+    pub fn set_damage_model(&mut self, model: Arc<dyn DamageModel>) -> Result<(), String> {
+        if self.memory.is_terminated() {
+            return Err("Cannot change the damage model of a terminated lineage".to_string());
+        }
+        let description = format!("Damage model set: {:?}", model);
+        self.scars.set_damage_model(model)?;
+        self.memory.append(description);
+        Ok(())
+    }
+
+    /// Returns the clock this lineage reads time from.
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
     /// Checks if this lineage is alive.
     pub fn is_alive(&self) -> bool {
         !self.metabolism.is_dead()
@@ -166,24 +395,228 @@ impl Lineage {
     /// If the lineage is dead, this returns OperationResult::Dead.
     /// If insufficient energy, this returns OperationResult::InsufficientEnergy.
     pub fn perform_operation(&mut self, description: String, energy_cost: u64) -> OperationResult {
+        if let Err(result) = self.check_operable() {
+            return result;
+        }
+
+        let consumption = self.metabolism.consume(energy_cost);
+        self.record_consumption(description, energy_cost, consumption)
+    }
+
+    /// Reserves energy for an operation that completes later.
+    ///
+    /// **CONSEQUENCE**: The energy is unavailable until the reservation is
+    /// committed through `perform_reserved_operation`.
+    /// **CONSEQUENCE**: Dropping the reservation instead burns its forfeit.
+    pub fn reserve(&mut self, energy_cost: u64) -> Result<Reservation, OperationResult> {
+        self.check_operable()?;
+
+        match self.metabolism.reserve(energy_cost) {
+            Ok(reservation) => {
+                self.memory.append(format!(
+                    "Reserved {} energy (forfeit if abandoned: {})",
+                    energy_cost,
+                    reservation.forfeit()
+                ));
+                Ok(reservation)
+            }
+            Err(ConsumptionResult::Insufficient { requested, available }) => {
+                self.memory.append(format!(
+                    "Failed reservation (insufficient energy: needed {}, have {})",
+                    requested, available
+                ));
+                Err(OperationResult::InsufficientEnergy {
+                    required: requested,
+                    available,
+                })
+            }
+            Err(_) => Err(OperationResult::Dead),
+        }
+    }
+
+    /// Performs an operation paid from a reservation.
+    ///
+    /// **CONSEQUENCE**: The full reserved amount is consumed.
+    /// A lapsed reservation yields `InsufficientEnergy`: its hold is gone.
+    pub fn perform_reserved_operation(
+        &mut self,
+        description: String,
+        reservation: Reservation,
+    ) -> OperationResult {
+        if let Err(result) = self.check_operable() {
+            return result;
+        }
+
+        let energy_cost = reservation.amount();
+        let consumption = self.metabolism.commit(reservation);
+        self.record_consumption(description, energy_cost, consumption)
+    }
+
+    /// Performs a behavior under its contract.
+    ///
+    /// The contract is checked and the violation scar prepared before the
+    /// behavior runs; the cost is priced from the current scars.
+    /// **CONSEQUENCE**: If the behavior ran with its contract violated, the
+    /// violation scar is inflicted permanently.
+    pub fn perform_behavior(&mut self, behavior: &dyn Behavior) -> BehaviorOutcome {
+        let cost = behavior.cost(&self.scars);
+
+        if !self.is_alive() {
+            return BehaviorOutcome {
+                energy_before: 0,
+                cost,
+                result: OperationResult::Dead,
+                contract_held: false,
+                scarred: false,
+            };
+        }
+
+        // CONTRACT: observed before the behavior changes anything
+        let energy_before = self.metabolism.energy();
+        let contract_held = behavior.precondition(self);
+        let violation = (!contract_held).then(|| behavior.violation(self));
+
+        let result = self.perform_operation(format!("{} (cost: {})", behavior.name(), cost), cost);
+
+        // INJURY PATH: only a behavior that actually ran can be scarred
+        let mut scarred = false;
+        if let (OperationResult::Success { .. }, Some(violation)) = (&result, violation) {
+            self.record_error(violation);
+            scarred = true;
+        }
+
+        BehaviorOutcome {
+            energy_before,
+            cost,
+            result,
+            contract_held,
+            scarred,
+        }
+    }
+
+    /// Registers a behavior so it can be run by name.
+    ///
+    /// Returns false (and registers nothing) if the name is already taken
+    /// or the lineage is terminated. Registered behaviors cannot be removed.
+    pub fn register_behavior(&mut self, behavior: Box<dyn Behavior>) -> bool {
+        if self.memory.is_terminated() || self.behaviors.contains_key(behavior.name()) {
+            return false;
+        }
+
+        self.memory.append(format!("Behavior registered: {}", behavior.name()));
+        self.behaviors.insert(behavior.name().to_string(), behavior);
+        true
+    }
+
+    /// Runs a registered behavior; `None` if no behavior has that name.
+    pub fn run_behavior(&mut self, name: &str) -> Option<BehaviorOutcome> {
+        let behavior = self.behaviors.remove(name)?;
+        let outcome = self.perform_behavior(behavior.as_ref());
+        self.behaviors.insert(name.to_string(), behavior);
+        Some(outcome)
+    }
+
+    /// Returns the names of registered behaviors, sorted.
+    pub fn behaviors(&self) -> Vec<&str> {
+        self.behaviors.keys().map(String::as_str).collect()
+    }
+
+    /// Sets the fraction of future reservations burned if abandoned.
+    pub fn set_forfeit_fraction(&mut self, fraction: f64) {
+        self.metabolism.set_forfeit_fraction(fraction);
+    }
+
+    /// Makes this lineage age under `model` (see `Metabolism::set_aging`).
+    ///
+    /// Aging is applied lazily before every operation, or explicitly with
+    /// `tick`/`advance`. Each burn is recorded in memory. Fails if the
+    /// lineage already ages: the model can never be replaced.
+    pub fn set_aging(&mut self, model: AgingModel) -> Result<(), String> {
+        self.metabolism.set_aging(model)
+    }
+
+    /// Applies wall-clock aging up to `now`. Returns the energy burned.
+    ///
+    /// **CONSEQUENCE**: A lineage that runs out of energy this way dies of old age.
+    pub fn tick(&mut self, now: DateTime<Utc>) -> u64 {
+        if self.memory.is_terminated() {
+            return 0;
+        }
+        let burned = self.metabolism.tick(now);
+        self.record_aging(burned);
+        burned
+    }
+
+    /// Applies `ticks` logical ticks of aging. Returns the energy burned.
+    pub fn advance(&mut self, ticks: u64) -> u64 {
+        if self.memory.is_terminated() {
+            return 0;
+        }
+        let burned = self.metabolism.advance(ticks);
+        self.record_aging(burned);
+        burned
+    }
+
+    /// Records an aging burn and, if it was the last energy, the death it caused.
+    fn record_aging(&mut self, burned: u64) {
+        if burned == 0 {
+            return;
+        }
+
+        self.memory.append(format!(
+            "Aging: {} energy burned (remaining: {})",
+            burned,
+            self.metabolism.energy()
+        ));
+
+        if self.metabolism.is_dead() {
+            self.terminate("Died of old age");
+        }
+    }
+
+    /// Checks the lineage may operate, applying aging and reservation forfeits first.
+    fn check_operable(&mut self) -> Result<(), OperationResult> {
         // Check if terminated first
         if self.memory.is_terminated() {
-            return OperationResult::Dead;
+            return Err(OperationResult::Dead);
         }
 
         // Verify invariants before operation
         if let Err(violation) = self.verify_invariants() {
-            return OperationResult::OntologicalViolation {
+            return Err(OperationResult::OntologicalViolation {
                 reason: violation,
-            };
+            });
+        }
+
+        self.tick(self.clock.now());
+
+        let burned = self.metabolism.settle();
+        if burned > 0 {
+            self.memory.append(format!(
+                "Reservation forfeit: {} energy burned (remaining: {})",
+                burned,
+                self.metabolism.energy()
+            ));
+
+            if self.metabolism.is_dead() {
+                self.terminate("Energy depleted");
+            }
         }
 
         if !self.is_alive() {
-            return OperationResult::Dead;
+            return Err(OperationResult::Dead);
         }
 
-        let consumption = self.metabolism.consume(energy_cost);
+        Ok(())
+    }
 
+    /// Records the outcome of consuming energy for an operation.
+    fn record_consumption(
+        &mut self,
+        description: String,
+        energy_cost: u64,
+        consumption: ConsumptionResult,
+    ) -> OperationResult {
         match consumption {
             ConsumptionResult::Success { remaining } => {
                 self.memory.append(format!(
@@ -213,6 +646,30 @@ impl Lineage {
                     available,
                 }
             }
+            ConsumptionResult::Expired { forfeited } => {
+                self.memory.append(format!(
+                    "Failed operation: {} (reservation expired, forfeited {})",
+                    description, forfeited
+                ));
+
+                if self.metabolism.is_dead() {
+                    self.terminate("Energy depleted");
+                }
+
+                OperationResult::InsufficientEnergy {
+                    required: energy_cost,
+                    available: self.metabolism.available(),
+                }
+            }
+            ConsumptionResult::ForeignReservation => {
+                let reason = format!(
+                    "Reservation for {} belongs to another lineage",
+                    description
+                );
+                self.memory.append(format!("Failed operation: {}", reason));
+
+                OperationResult::OntologicalViolation { reason }
+            }
             ConsumptionResult::Dead => OperationResult::Dead,
         }
     }
@@ -229,16 +686,19 @@ impl Lineage {
 
         let is_fatal = error.severity() == ScarSeverity::Fatal;
 
-        self.scars.inflict(
-            error.severity(),
-            error.description().to_string(),
-            error.context().map(|s| s.to_string()),
-        );
+        let entry = match (error.category(), error.code()) {
+            (ScarCategory::General, None) => format!(
+                "Scar inflicted: {:?} - {}",
+                error.severity(), error.description()
+            ),
+            (category, code) => format!(
+                "Scar inflicted: {:?} [{:?}/{}] - {}",
+                error.severity(), category, code.unwrap_or("-"), error.description()
+            ),
+        };
 
-        self.memory.append(format!(
-            "Scar inflicted: {:?} - {}",
-            error.severity(), error.description()
-        ));
+        self.scars.inflict_scar(error.into_scar());
+        self.memory.append(entry);
 
         if is_fatal {
             self.metabolism.die();
@@ -267,7 +727,7 @@ impl Lineage {
     pub fn verify_invariants(&self) -> Result<(), String> {
         // Verify memory integrity
         if !self.memory.verify_integrity() {
-            return Err("Memory corruption: causal chain is broken".to_string());
+            return Err("Memory corruption: causal chain or event digest chain is broken".to_string());
         }
 
         // Verify death state consistency
@@ -283,6 +743,13 @@ impl Lineage {
         Ok(())
     }
 
+    /// Breaks the scar invariant (fatal scar on a living lineage) so tests
+    /// can exercise violation handling.
+    #[cfg(test)]
+    pub(crate) fn corrupt_for_test(&mut self) {
+        self.scars.inflict(ScarSeverity::Fatal, "Injected corruption".to_string(), None);
+    }
+
     /// Returns a comprehensive status summary.
     pub fn status(&self) -> LineageStatus {
         LineageStatus {
@@ -617,6 +1084,112 @@ mod tests {
         assert_eq!(history[4].previous(), Some(3));
     }
 
+    #[test]
+    fn reserved_operation_commits_and_abandoned_forfeit_kills() {
+        let mut lineage = Lineage::create(100);
+        lineage.set_forfeit_fraction(1.0);
+
+        let reservation = lineage.reserve(30).unwrap();
+        assert_eq!(lineage.metabolism().available(), 70);
+        assert_eq!(
+            lineage.perform_reserved_operation("Settle trade".to_string(), reservation),
+            OperationResult::Success { energy_consumed: 30 }
+        );
+        assert_eq!(lineage.metabolism().energy(), 70);
+
+        // Abandoning the rest burns all of it and ends the lineage
+        drop(lineage.reserve(70).unwrap());
+        assert_eq!(lineage.perform_operation("Next".to_string(), 1), OperationResult::Dead);
+        assert!(lineage.memory().is_terminated());
+        assert!(lineage.memory().verify_integrity());
+    }
+
+    #[test]
+    fn foreign_reservation_is_a_violation_not_a_panic() {
+        let mut owner = Lineage::create(100);
+        let mut other = Lineage::create(100);
+
+        let reservation = owner.reserve(30).unwrap();
+        assert!(matches!(
+            other.perform_reserved_operation("Borrowed".to_string(), reservation),
+            OperationResult::OntologicalViolation { .. }
+        ));
+        assert_eq!(other.metabolism().energy(), 100);
+        assert!(other.memory().verify_integrity());
+    }
+
+    #[test]
+    fn idle_lineage_dies_of_old_age() {
+        use crate::metabolism::AgingModel;
+
+        let mut lineage = Lineage::create(50);
+        lineage.set_aging(AgingModel::WallClock { burn: 10, period: chrono::Duration::hours(1) }).unwrap();
+
+        assert_eq!(lineage.tick(Utc::now() + chrono::Duration::hours(2)), 20);
+        assert!(lineage.memory().latest().description().starts_with("Aging: 20"));
+
+        assert_eq!(lineage.tick(Utc::now() + chrono::Duration::days(1)), 30);
+        assert!(!lineage.is_alive());
+        assert!(lineage.memory().latest().description().contains("Died of old age"));
+        assert!(lineage.verify_invariants().is_ok());
+        assert_eq!(lineage.perform_operation("Too late".to_string(), 1), OperationResult::Dead);
+    }
+
+    #[test]
+    fn classified_errors_leave_classified_scars() {
+        let mut lineage = Lineage::create(100);
+        lineage.set_scar_weight(ScarCategory::Integrity, 3.0);
+
+        lineage.record_error(
+            OperationError::new(ScarSeverity::Moderate, "Checksum mismatch".to_string())
+                .with_category(ScarCategory::Integrity)
+                .with_code("INT_CHECKSUM")
+                .with_detail("block", "42"),
+        );
+        lineage.record_error(OperationError::new(ScarSeverity::Minor, "Timeout".to_string()));
+
+        let scar = &lineage.scars().scars_by_code("INT_CHECKSUM")[0];
+        assert_eq!(scar.category(), ScarCategory::Integrity);
+        assert_eq!(scar.detail("block"), Some("42"));
+        assert_eq!(lineage.scars().damage_score(), 16);
+        assert!(lineage
+            .memory()
+            .history()
+            .iter()
+            .any(|event| event.description() == "Scar inflicted: Moderate [Integrity/INT_CHECKSUM] - Checksum mismatch"));
+    }
+
+    #[test]
+    fn manual_clock_pins_every_timestamp() {
+        use crate::clock::ManualClock;
+        use crate::metabolism::AgingModel;
+        use std::sync::Arc;
+
+        let clock = Arc::new(ManualClock::at_epoch());
+        let mut lineage = Lineage::create_with_clock(100, clock.clone());
+        assert_eq!(lineage.identity().birth_time(), 0);
+        assert!(lineage
+            .memory()
+            .history()
+            .iter()
+            .all(|event| event.timestamp() == DateTime::UNIX_EPOCH));
+
+        clock.advance(chrono::Duration::hours(1));
+        lineage.record_error(OperationError::new(ScarSeverity::Minor, "Timeout".to_string()));
+        let scarred_at = DateTime::UNIX_EPOCH + chrono::Duration::hours(1);
+        assert_eq!(lineage.scars().latest_scar().unwrap().timestamp(), scarred_at);
+        assert_eq!(lineage.memory().latest().timestamp(), scarred_at);
+
+        // Aging follows the injected clock, not the wall clock
+        lineage.set_aging(AgingModel::WallClock { burn: 5, period: chrono::Duration::hours(1) }).unwrap();
+        clock.advance(chrono::Duration::hours(2));
+        assert_eq!(
+            lineage.perform_operation("Work".to_string(), 1),
+            OperationResult::Success { energy_consumed: 1 }
+        );
+        assert_eq!(lineage.metabolism().energy(), 89);
+    }
+
     #[test]
     fn new_lineage_has_different_identity_after_process_restart() {
         // This test verifies that restarting doesn't continue the same lineage
@@ -7,10 +7,18 @@
 //! - Permanent scars
 //! - Irreversible death
 
+#[allow(dead_code)] // The demo runs on the system clock; manual clocks are for library users
+mod clock;
 mod identity;
+#[allow(dead_code)] // Registries are installed by the graveyard, which the binary does not load
+mod lazarus;
 mod memory;
+#[allow(dead_code)] // Only reachable through memory here; used fully by the library
+mod merkle;
+#[allow(dead_code)] // Reservations are driven through the library API
 mod metabolism;
 mod scar;
+#[allow(dead_code)] // Reservation and restore paths are library-only
 mod lineage;
 mod behavior;
 
@@ -4,6 +4,8 @@
 //! - Append-only event log with strict causal ordering
 //! - Immutable history that cannot be altered or deleted
 //! - Each event references its causal predecessor
+//! - Each event carries a SHA-256 digest chained to its predecessor's digest
+//! - Any single event can be proven against a Merkle root of the history
 //!
 //! ## What This Forbids
 //! - Event deletion or modification
@@ -14,13 +16,21 @@
 //! ## Violations
 //! - Attempting to modify past events is an ontological violation
 //! - Attempting to clear history terminates the lineage
+//! - A digest mismatch anywhere in the chain marks the memory as corrupted
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::clock::{self, SharedClock};
+use crate::merkle::{MerkleProof, MerkleTree};
 
 /// A single immutable event in the causal chain.
 /// 
 /// Once recorded, this event exists forever and cannot be modified.
-#[derive(Debug)]
+/// A deserialized event is inert: it only becomes history through
+/// `Memory::restore`, which re-verifies the whole digest chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
     /// Sequence number in the causal chain
     sequence: u64,
@@ -30,6 +40,10 @@ pub struct Event {
     description: String,
     /// Causal reference to previous event (None only for genesis)
     previous: Option<u64>,
+    /// Digest of the previous event (None only for genesis)
+    prev_hash: Option<String>,
+    /// SHA-256 digest over prev_hash, sequence, timestamp and description
+    hash: String,
 }
 
 impl Event {
@@ -52,6 +66,66 @@ impl Event {
     pub fn previous(&self) -> Option<u64> {
         self.previous
     }
+
+    /// Returns the digest of the causal predecessor, if any.
+    pub fn prev_hash(&self) -> Option<&str> {
+        self.prev_hash.as_deref()
+    }
+
+    /// Returns this event's digest.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Recomputes this event's digest and compares it to the stored one.
+    ///
+    /// Proves the contents match the digest, not that the event belongs
+    /// to any particular history (see `Memory::verify_chain`).
+    pub fn verify_digest(&self) -> bool {
+        Self::compute_hash(
+            self.prev_hash.as_deref(),
+            self.sequence,
+            self.timestamp,
+            &self.description,
+        ) == self.hash
+    }
+
+    /// Creates a sealed event chained to the given predecessor digest.
+    fn seal(
+        sequence: u64,
+        timestamp: DateTime<Utc>,
+        description: String,
+        previous: Option<u64>,
+        prev_hash: Option<String>,
+    ) -> Self {
+        let hash = Self::compute_hash(prev_hash.as_deref(), sequence, timestamp, &description);
+
+        Event {
+            sequence,
+            timestamp,
+            description,
+            previous,
+            prev_hash,
+            hash,
+        }
+    }
+
+    /// Computes the digest binding an event to its predecessor.
+    fn compute_hash(
+        prev_hash: Option<&str>,
+        sequence: u64,
+        timestamp: DateTime<Utc>,
+        description: &str,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        if let Some(prev) = prev_hash {
+            hasher.update(prev.as_bytes());
+        }
+        hasher.update(sequence.to_le_bytes());
+        hasher.update(timestamp.to_rfc3339().as_bytes());
+        hasher.update(description.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
 }
 
 /// Append-only causal memory.
@@ -61,14 +135,18 @@ impl Event {
 /// 
 /// **INVARIANT**: Events form a strict causal chain with no gaps.
 /// **INVARIANT**: Once terminated, no further events can be appended.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Memory {
     /// The complete, immutable event history
     events: Vec<Event>,
     /// Current sequence number
+    #[serde(skip)]
     current_sequence: u64,
     /// Whether this memory has been sealed by termination
     is_terminated: bool,
+    /// Source of event timestamps
+    #[serde(skip)]
+    clock: SharedClock,
 }
 
 impl Memory {
@@ -76,18 +154,48 @@ impl Memory {
     /// 
     /// The genesis event marks the beginning of this lineage's history.
     pub fn new() -> Self {
-        let genesis = Event {
-            sequence: 0,
-            timestamp: Utc::now(),
-            description: "Genesis".to_string(),
-            previous: None,
-        };
+        Self::with_clock(clock::system())
+    }
+
+    /// Creates a new memory whose events are timestamped by `clock`.
+    pub fn with_clock(clock: SharedClock) -> Self {
+        let genesis = Event::seal(0, clock.now(), "Genesis".to_string(), None, None);
 
         Memory {
             events: vec![genesis],
             current_sequence: 0,
             is_terminated: false,
+            clock,
+        }
+    }
+
+    /// Rebuilds memory from a persisted event history.
+    /// 
+    /// **INVARIANT**: The restored history must pass `verify_integrity`,
+    /// so a rewritten, reordered or truncated-in-the-middle chain is refused.
+    pub(crate) fn restore(events: Vec<Event>, is_terminated: bool) -> Result<Self, String> {
+        let current_sequence = match events.last() {
+            Some(event) => event.sequence,
+            None => return Err("Memory corruption: history has no genesis event".to_string()),
+        };
+
+        let memory = Memory {
+            events,
+            current_sequence,
+            is_terminated,
+            clock: clock::system(),
+        };
+
+        if !memory.verify_integrity() {
+            return Err("Memory corruption: causal chain or event digest chain is broken".to_string());
+        }
+
+        // Termination is the last event of a sealed ledger and never followed
+        if memory.latest().description.starts_with("TERMINATION: ") != is_terminated {
+            return Err("Memory corruption: sealed flag disagrees with the recorded termination".to_string());
         }
+
+        Ok(memory)
     }
 
     /// Appends a new event to the causal chain.
@@ -105,12 +213,13 @@ impl Memory {
 
         let new_sequence = self.current_sequence + 1;
         
-        let event = Event {
-            sequence: new_sequence,
-            timestamp: Utc::now(),
+        let event = Event::seal(
+            new_sequence,
+            self.clock.now(),
             description,
-            previous: Some(self.current_sequence),
-        };
+            Some(self.current_sequence),
+            Some(self.latest().hash().to_string()),
+        );
 
         self.events.push(event);
         self.current_sequence = new_sequence;
@@ -123,6 +232,19 @@ impl Memory {
         &self.events
     }
 
+    /// Builds a Merkle tree whose leaves are the event digests, in order.
+    pub fn merkle_tree(&self) -> MerkleTree {
+        MerkleTree::from_leaves(self.events.iter().map(|event| event.hash.as_bytes()))
+    }
+
+    /// Issues an inclusion proof for the event at `sequence`.
+    ///
+    /// The proof verifies against `merkle_tree().root()` with the event's
+    /// digest as leaf; `Event::verify_digest` ties the digest to its contents.
+    pub fn event_proof(&self, sequence: u64) -> Option<MerkleProof> {
+        self.merkle_tree().proof(usize::try_from(sequence).ok()?)
+    }
+
     /// Returns the most recent event.
     pub fn latest(&self) -> &Event {
         self.events
@@ -158,12 +280,13 @@ impl Memory {
         // Record termination in causal chain
         let new_sequence = self.current_sequence + 1;
         
-        let termination_event = Event {
-            sequence: new_sequence,
-            timestamp: Utc::now(),
-            description: format!("TERMINATION: {}", reason),
-            previous: Some(self.current_sequence),
-        };
+        let termination_event = Event::seal(
+            new_sequence,
+            self.clock.now(),
+            format!("TERMINATION: {}", reason),
+            Some(self.current_sequence),
+            Some(self.latest().hash().to_string()),
+        );
 
         self.events.push(termination_event);
         self.current_sequence = new_sequence;
@@ -174,21 +297,35 @@ impl Memory {
 
     /// Verifies the causal chain integrity.
     /// 
-    /// Returns true if all events form a valid causal chain.
+    /// Returns true if all events form a valid causal chain and every
+    /// event's digest matches its contents and its predecessor's digest.
     /// If this returns false, the lineage is corrupted and must terminate.
     pub fn verify_integrity(&self) -> bool {
-        if self.events.is_empty() {
+        Self::verify_chain(&self.events)
+    }
+
+    /// Verifies a detached event sequence (e.g. one sealed into a tombstone).
+    ///
+    /// Same rules as `verify_integrity`: genesis first, unbroken sequence,
+    /// every digest chained to its predecessor and matching its contents.
+    pub fn verify_chain(events: &[Event]) -> bool {
+        if events.is_empty() {
             return false;
         }
 
         // Genesis must be first
-        if self.events[0].sequence != 0 || self.events[0].previous.is_some() {
+        let genesis = &events[0];
+        if genesis.sequence != 0 || genesis.previous.is_some() || genesis.prev_hash.is_some() {
+            return false;
+        }
+
+        if !genesis.verify_digest() {
             return false;
         }
 
         // All subsequent events must form unbroken chain
-        for i in 1..self.events.len() {
-            let event = &self.events[i];
+        for i in 1..events.len() {
+            let event = &events[i];
             let expected_sequence = i as u64;
             
             if event.sequence != expected_sequence {
@@ -198,6 +335,15 @@ impl Memory {
             if event.previous != Some(expected_sequence - 1) {
                 return false;
             }
+
+            // Digest must chain to the predecessor and match the contents
+            if event.prev_hash.as_deref() != Some(events[i - 1].hash.as_str()) {
+                return false;
+            }
+
+            if !event.verify_digest() {
+                return false;
+            }
         }
 
         true
@@ -417,4 +563,78 @@ mod tests {
         // 
         // If such methods exist, history immutability has been violated.
     }
+
+    #[test]
+    fn events_are_hash_chained() {
+        let mut memory = Memory::new();
+
+        memory.append("Event 1".to_string());
+        memory.append("Event 2".to_string());
+
+        let history = memory.history();
+        assert_eq!(history[0].prev_hash(), None);
+        assert_eq!(history[0].hash().len(), 64);
+        assert_eq!(history[1].prev_hash(), Some(history[0].hash()));
+        assert_eq!(history[2].prev_hash(), Some(history[1].hash()));
+        assert_ne!(history[1].hash(), history[2].hash());
+    }
+
+    #[test]
+    fn rewritten_description_is_detected() {
+        let mut memory = Memory::new();
+        memory.append("Transferred 10 units".to_string());
+        memory.append("Audit passed".to_string());
+
+        assert!(memory.verify_integrity());
+
+        // Simulate an attacker editing the serialized history in place
+        memory.events[1].description = "Transferred 1000 units".to_string();
+
+        assert!(!memory.verify_integrity());
+    }
+
+    #[test]
+    fn rewritten_timestamp_is_detected() {
+        let mut memory = Memory::new();
+        memory.append("Event 1".to_string());
+
+        memory.events[1].timestamp -= chrono::Duration::hours(1);
+
+        assert!(!memory.verify_integrity());
+    }
+
+    #[test]
+    fn recomputed_digest_without_relinking_is_detected() {
+        let mut memory = Memory::new();
+        memory.append("Event 1".to_string());
+        memory.append("Event 2".to_string());
+
+        // Rewrite an event and fix up its own digest, but not its successor's link
+        let forged = Event::seal(
+            1,
+            memory.events[1].timestamp,
+            "Forged".to_string(),
+            Some(0),
+            memory.events[1].prev_hash.clone(),
+        );
+        memory.events[1] = forged;
+
+        assert!(!memory.verify_integrity());
+    }
+
+    #[test]
+    fn single_event_proves_against_history_root() {
+        let mut memory = Memory::new();
+        memory.append("Event 1".to_string());
+        memory.append("Event 2".to_string());
+        let root = memory.merkle_tree().root();
+
+        let event = &memory.history()[1];
+        let proof = memory.event_proof(1).unwrap();
+
+        assert!(event.verify_digest());
+        assert!(proof.verify(event.hash().as_bytes(), &root));
+        assert!(!proof.verify(memory.history()[2].hash().as_bytes(), &root));
+        assert!(memory.event_proof(3).is_none());
+    }
 }
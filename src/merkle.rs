@@ -0,0 +1,245 @@
+//! # Merkle Trees
+//!
+//! Binary SHA-256 Merkle trees with inclusion proofs.
+//!
+//! ## What This Enforces
+//! - One root hash commits to every leaf and to its position
+//! - A single leaf can be proven against the root without revealing the others
+//! - Leaves and interior nodes are domain-separated (no second-preimage splicing)
+//!
+//! ## What This Forbids
+//! - Duplicating an unpaired node to fill a level (it is promoted unchanged)
+//! - Proofs that verify at a different position than the one they were issued for
+//!
+//! ## Construction
+//! `leaf = SHA-256(0x00 || data)`, `node = SHA-256(0x01 || left || right)`.
+//! The root of an empty tree is `SHA-256("")`.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+type Hash = [u8; 32];
+
+/// Hashes raw leaf data
+fn leaf_hash(data: &[u8]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Hashes two child nodes
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// A binary Merkle tree over an ordered list of leaves.
+///
+/// Every level is kept so proofs can be issued for any leaf.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// `levels[0]` are leaf hashes, the last level holds the root
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree from leaf data, in order
+    pub fn from_leaves<I, T>(leaves: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let mut level: Vec<Hash> = leaves
+            .into_iter()
+            .map(|leaf| leaf_hash(leaf.as_ref()))
+            .collect();
+        let mut levels = Vec::new();
+
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks(2) yields one or two nodes"),
+                })
+                .collect();
+            levels.push(level);
+            level = next;
+        }
+        levels.push(level);
+
+        MerkleTree { levels }
+    }
+
+    /// Number of leaves
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// True if the tree has no leaves
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hex-encoded root hash
+    pub fn root(&self) -> String {
+        match self.levels.last().and_then(|level| level.first()) {
+            Some(root) => hex::encode(root),
+            None => format!("{:x}", Sha256::digest(b"")),
+        }
+    }
+
+    /// Issues an inclusion proof for the leaf at `index`
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut siblings = Vec::new();
+        let mut position = index;
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                siblings.push(hex::encode(level[sibling]));
+            }
+            position /= 2;
+        }
+
+        Some(MerkleProof {
+            leaf_index: index,
+            leaf_count: self.len(),
+            siblings,
+        })
+    }
+}
+
+/// Proof that one leaf sits at a given position under a Merkle root.
+///
+/// Self-contained: a third party needs only the leaf data and the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// Position of the proven leaf
+    pub leaf_index: usize,
+    /// Total leaves in the tree
+    pub leaf_count: usize,
+    /// Hex-encoded sibling hashes, leaf level first
+    pub siblings: Vec<String>,
+}
+
+impl MerkleProof {
+    /// Recomputes the root from `leaf` and compares it to `root`.
+    ///
+    /// Sibling sides are derived from `leaf_index` and `leaf_count`,
+    /// so a proof cannot be replayed for another position.
+    pub fn verify(&self, leaf: &[u8], root: &str) -> bool {
+        if self.leaf_index >= self.leaf_count {
+            return false;
+        }
+
+        let mut hash = leaf_hash(leaf);
+        let mut position = self.leaf_index;
+        let mut width = self.leaf_count;
+        let mut siblings = self.siblings.iter();
+
+        while width > 1 {
+            let sibling = position ^ 1;
+            if sibling < width {
+                let Some(sibling_hash) = siblings.next().and_then(|h| decode_hash(h)) else {
+                    return false;
+                };
+                hash = if position.is_multiple_of(2) {
+                    node_hash(&hash, &sibling_hash)
+                } else {
+                    node_hash(&sibling_hash, &hash)
+                };
+            }
+            position /= 2;
+            width = width.div_ceil(2);
+        }
+
+        siblings.next().is_none() && hex::encode(hash) == root
+    }
+}
+
+fn decode_hash(hex_str: &str) -> Option<Hash> {
+    hex::decode(hex_str).ok()?.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("leaf-{}", i)).collect()
+    }
+
+    #[test]
+    fn every_leaf_proves_for_odd_and_even_sizes() {
+        for count in 1..=9 {
+            let data = leaves(count);
+            let tree = MerkleTree::from_leaves(&data);
+            let root = tree.root();
+
+            for (index, leaf) in data.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert!(proof.verify(leaf.as_bytes(), &root), "count {} index {}", count, index);
+            }
+            assert!(tree.proof(count).is_none());
+        }
+    }
+
+    #[test]
+    fn root_commits_to_content_and_order() {
+        let data = leaves(4);
+        let root = MerkleTree::from_leaves(&data).root();
+
+        let mut edited = data.clone();
+        edited[2] = "forged".to_string();
+        assert_ne!(MerkleTree::from_leaves(&edited).root(), root);
+
+        let mut swapped = data.clone();
+        swapped.swap(0, 1);
+        assert_ne!(MerkleTree::from_leaves(&swapped).root(), root);
+    }
+
+    #[test]
+    fn proof_rejects_wrong_leaf_or_position() {
+        let data = leaves(5);
+        let tree = MerkleTree::from_leaves(&data);
+        let root = tree.root();
+        let proof = tree.proof(2).unwrap();
+
+        assert!(!proof.verify(b"leaf-3", &root));
+
+        let mut moved = proof.clone();
+        moved.leaf_index = 3;
+        assert!(!moved.verify(data[2].as_bytes(), &root));
+    }
+
+    #[test]
+    fn unpaired_node_is_not_duplicated() {
+        // With duplication, [a, b, c] and [a, b, c, c] would share a root
+        let three = MerkleTree::from_leaves(["a", "b", "c"]).root();
+        let four = MerkleTree::from_leaves(["a", "b", "c", "c"]).root();
+
+        assert_ne!(three, four);
+    }
+
+    #[test]
+    fn proof_survives_json_roundtrip() {
+        let tree = MerkleTree::from_leaves(leaves(6));
+        let json = serde_json::to_string(&tree.proof(4).unwrap()).unwrap();
+        let proof: MerkleProof = serde_json::from_str(&json).unwrap();
+
+        assert!(proof.verify(b"leaf-4", &tree.root()));
+    }
+}
@@ -4,6 +4,9 @@
 //! - Finite energy budget that depletes with operations
 //! - Death when energy reaches zero
 //! - No recharge or energy restoration mechanisms
+//! - Reserved energy is unavailable until committed (burned) or abandoned
+//! - Abandoned or expired reservations burn a forfeit fraction
+//! - Optional aging: a basal burn per wall-clock period or logical tick
 //!
 //! ## What This Forbids
 //! - Energy recharge or restoration
@@ -14,6 +17,36 @@
 //! ## Violations
 //! - Attempting to add energy is an ontological violation
 //! - Operating after death is an ontological violation
+//!
+//! ## Reservations
+//! `reserve` holds energy for work that completes later. The hold lowers
+//! `available()` immediately; `commit` burns it. A reservation dropped
+//! without commit (or past its deadline) releases the hold but burns
+//! `forfeit_fraction` of it. Forfeits are applied on the next mutating
+//! call (`settle`). Reservations are process-local: they are never
+//! archived or journaled. Serializing a metabolism therefore charges every
+//! pending forfeit, plus the forfeit of every open hold, since the holds
+//! cannot travel with it.
+//!
+//! ## Aging
+//! With an `AgingModel`, time itself costs energy: `tick(now)` burns the
+//! basal rate for every whole period elapsed, `advance(ticks)` for logical
+//! ticks. Partial periods carry over. Aging can kill; the model is set
+//! once and can never be replaced, slowed or switched off. The model and
+//! how far it has been applied are archived and journaled (`AgingState`),
+//! and a restored lineage is charged for the time that passed meanwhile.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{self, SharedClock};
+
+/// Fraction of an abandoned reservation burned by default
+pub const DEFAULT_FORFEIT_FRACTION: f64 = 0.1;
 
 /// The metabolic state of a lineage.
 /// 
@@ -30,6 +63,150 @@ pub struct Metabolism {
     initial_energy: u64,
     /// Whether this metabolism has reached death
     is_dead: bool,
+    /// Fraction of a reservation burned if it is abandoned
+    forfeit_fraction: f64,
+    /// Open reservations and pending forfeits (shared with `Reservation`s)
+    reservations: Arc<Mutex<ReservationBook>>,
+    /// Basal burn, if this metabolism ages
+    aging: Option<Aging>,
+    /// Source of reservation deadlines and aging time
+    clock: SharedClock,
+}
+
+/// Basal energy burn: how a metabolism ages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgingModel {
+    /// Burn `burn` energy for every full `period` of wall-clock time
+    WallClock { burn: u64, period: Duration },
+    /// Burn `burn` energy for every logical tick (see `Metabolism::advance`)
+    Logical { burn: u64 },
+}
+
+/// An aging model and how far it has been applied
+#[derive(Debug, Clone, Copy)]
+struct Aging {
+    model: AgingModel,
+    /// Wall-clock time aged up to (whole periods only)
+    aged_until: DateTime<Utc>,
+}
+
+impl Aging {
+    fn state(&self) -> AgingState {
+        let (burn, period_ns) = match self.model {
+            AgingModel::WallClock { burn, period } => {
+                (burn, Some(period.num_nanoseconds().unwrap_or(i64::MAX)))
+            }
+            AgingModel::Logical { burn } => (burn, None),
+        };
+
+        AgingState {
+            burn,
+            period_ns,
+            aged_until: self.aged_until,
+        }
+    }
+}
+
+/// Serialized aging: the model and how far it has been applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgingState {
+    /// Energy burned per period or logical tick
+    pub burn: u64,
+    /// Wall-clock period in nanoseconds; `None` for logical aging
+    pub period_ns: Option<i64>,
+    /// Wall-clock time aged up to
+    pub aged_until: DateTime<Utc>,
+}
+
+impl AgingState {
+    /// The aging model this state describes
+    pub fn model(&self) -> AgingModel {
+        match self.period_ns {
+            Some(ns) => AgingModel::WallClock {
+                burn: self.burn,
+                period: Duration::nanoseconds(ns),
+            },
+            None => AgingModel::Logical { burn: self.burn },
+        }
+    }
+}
+
+/// Energy held by one open reservation
+#[derive(Debug, Clone, Copy)]
+struct Hold {
+    amount: u64,
+    forfeit: u64,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+/// Open holds plus forfeits not yet burned
+#[derive(Debug, Default)]
+struct ReservationBook {
+    next_id: u64,
+    holds: BTreeMap<u64, Hold>,
+    /// Burned by abandoned reservations, deducted at the next `settle`
+    forfeited: u64,
+}
+
+impl ReservationBook {
+    fn held(&self) -> u64 {
+        self.holds.values().map(|hold| hold.amount).sum()
+    }
+
+    /// Release a hold, charging its forfeit
+    fn abandon(&mut self, id: u64) {
+        if let Some(hold) = self.holds.remove(&id) {
+            self.forfeited += hold.forfeit;
+        }
+    }
+
+    /// Everything owed if every open hold were abandoned now
+    fn outstanding(&self) -> u64 {
+        self.holds
+            .values()
+            .map(|hold| hold.forfeit)
+            .fold(self.forfeited, u64::saturating_add)
+    }
+}
+
+/// Energy held for a later commit.
+///
+/// **CONSEQUENCE**: Dropping this without `Metabolism::commit` burns its
+/// forfeit; the rest of the hold is released.
+#[derive(Debug)]
+pub struct Reservation {
+    id: u64,
+    amount: u64,
+    forfeit: u64,
+    expires_at: Option<DateTime<Utc>>,
+    book: Arc<Mutex<ReservationBook>>,
+}
+
+impl Reservation {
+    /// Energy held
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Energy burned if this reservation is abandoned or expires
+    pub fn forfeit(&self) -> u64 {
+        self.forfeit
+    }
+
+    /// Deadline after which the hold lapses, if any
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        lock_book(&self.book).abandon(self.id);
+    }
+}
+
+fn lock_book(book: &Mutex<ReservationBook>) -> MutexGuard<'_, ReservationBook> {
+    book.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 /// Result of an energy consumption attempt.
@@ -41,6 +218,10 @@ pub enum ConsumptionResult {
     Insufficient { requested: u64, available: u64 },
     /// Already dead - no operations possible
     Dead,
+    /// Reservation lapsed before commit - its forfeit was burned instead
+    Expired { forfeited: u64 },
+    /// Reservation was taken from a different metabolism - nothing burned here
+    ForeignReservation,
 }
 
 impl Metabolism {
@@ -48,11 +229,69 @@ impl Metabolism {
     /// 
     /// **CONSEQUENCE**: This energy can never be replenished.
     pub fn new(initial_energy: u64) -> Self {
+        Self::with_clock(initial_energy, clock::system())
+    }
+
+    /// Creates a new metabolism whose deadlines and aging follow `clock`.
+    pub fn with_clock(initial_energy: u64, clock: SharedClock) -> Self {
         Metabolism {
             energy: initial_energy,
             initial_energy,
             is_dead: false,
+            forfeit_fraction: DEFAULT_FORFEIT_FRACTION,
+            reservations: Arc::default(),
+            aging: None,
+            clock,
+        }
+    }
+
+    /// Rebuilds a metabolism from persisted state.
+    /// 
+    /// **INVARIANT**: Energy may not exceed the initial budget, and a dead
+    /// metabolism must have zero energy.
+    pub(crate) fn restore(initial_energy: u64, energy: u64, is_dead: bool) -> Result<Self, String> {
+        if energy > initial_energy {
+            return Err("Metabolism corruption: energy exceeds initial budget".to_string());
+        }
+
+        if is_dead && energy != 0 {
+            return Err("Metabolism corruption: dead but has energy".to_string());
         }
+
+        Ok(Metabolism {
+            energy,
+            initial_energy,
+            is_dead,
+            forfeit_fraction: DEFAULT_FORFEIT_FRACTION,
+            reservations: Arc::default(),
+            aging: None,
+            clock: clock::system(),
+        })
+    }
+
+    /// Sets the fraction (0.0-1.0) of future reservations burned if abandoned.
+    ///
+    /// Reservations already taken keep the forfeit they were issued with.
+    pub fn set_forfeit_fraction(&mut self, fraction: f64) {
+        self.forfeit_fraction = fraction.clamp(0.0, 1.0);
+    }
+
+    /// Returns the forfeit fraction for new reservations.
+    pub fn forfeit_fraction(&self) -> f64 {
+        self.forfeit_fraction
+    }
+
+    /// Returns energy held by open reservations.
+    pub fn reserved(&self) -> u64 {
+        lock_book(&self.reservations).held()
+    }
+
+    /// Returns energy free for new work: not held and not already forfeited.
+    pub fn available(&self) -> u64 {
+        let book = lock_book(&self.reservations);
+        self.energy
+            .saturating_sub(book.held())
+            .saturating_sub(book.forfeited)
     }
 
     /// Returns current energy level.
@@ -89,16 +328,19 @@ impl Metabolism {
     /// 
     /// Returns ConsumptionResult indicating success or reason for failure.
     pub fn consume(&mut self, cost: u64) -> ConsumptionResult {
+        self.settle();
+
         if self.is_dead {
             return ConsumptionResult::Dead;
         }
 
-        if cost > self.energy {
+        let available = self.available();
+        if cost > available {
             // Insufficient energy - operation fails
             // Do NOT set is_dead here - lineage continues but operation fails
             return ConsumptionResult::Insufficient {
                 requested: cost,
-                available: self.energy,
+                available,
             };
         }
 
@@ -114,11 +356,217 @@ impl Metabolism {
         }
     }
 
+    /// Holds `cost` energy until the reservation is committed or abandoned.
+    ///
+    /// **CONSEQUENCE**: Abandoning it burns `forfeit_fraction` of the hold.
+    pub fn reserve(&mut self, cost: u64) -> Result<Reservation, ConsumptionResult> {
+        self.reserve_until(cost, None)
+    }
+
+    /// Like `reserve`, but the hold lapses (burning its forfeit) after `ttl`.
+    pub fn reserve_for(&mut self, cost: u64, ttl: Duration) -> Result<Reservation, ConsumptionResult> {
+        self.reserve_until(cost, Some(self.clock.now() + ttl))
+    }
+
+    fn reserve_until(
+        &mut self,
+        cost: u64,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Reservation, ConsumptionResult> {
+        self.settle();
+
+        if self.is_dead {
+            return Err(ConsumptionResult::Dead);
+        }
+
+        let available = self.available();
+        if cost > available {
+            return Err(ConsumptionResult::Insufficient {
+                requested: cost,
+                available,
+            });
+        }
+
+        let forfeit = ((cost as f64 * self.forfeit_fraction).ceil() as u64).min(cost);
+        let mut book = lock_book(&self.reservations);
+        let id = book.next_id;
+        book.next_id += 1;
+        book.holds.insert(id, Hold { amount: cost, forfeit, expires_at });
+
+        Ok(Reservation {
+            id,
+            amount: cost,
+            forfeit,
+            expires_at,
+            book: Arc::clone(&self.reservations),
+        })
+    }
+
+    /// Burns a reservation's full hold.
+    ///
+    /// **CONSEQUENCE**: If this causes death, the lineage ends.
+    ///
+    /// A reservation taken from a different metabolism is refused with
+    /// `ForeignReservation`; dropping it charges its forfeit to its owner.
+    pub fn commit(&mut self, reservation: Reservation) -> ConsumptionResult {
+        if !Arc::ptr_eq(&reservation.book, &self.reservations) {
+            return ConsumptionResult::ForeignReservation;
+        }
+
+        self.settle();
+
+        if self.is_dead {
+            return ConsumptionResult::Dead;
+        }
+
+        let hold = lock_book(&self.reservations).holds.remove(&reservation.id);
+        let Some(hold) = hold else {
+            // Lapsed during settle: its forfeit has already been burned
+            return ConsumptionResult::Expired {
+                forfeited: reservation.forfeit,
+            };
+        };
+
+        // Aging may have eaten into held energy; never burn below zero
+        self.burn(hold.amount);
+
+        ConsumptionResult::Success {
+            remaining: self.energy,
+        }
+    }
+
+    /// Starts aging under `model`, from now.
+    ///
+    /// **CONSEQUENCE**: The model is permanent. A metabolism that already
+    /// ages refuses any replacement, so aging can never be slowed or stopped.
+    pub fn set_aging(&mut self, model: AgingModel) -> Result<(), String> {
+        if self.aging.is_some() {
+            return Err("Aging model is already set and cannot be replaced".to_string());
+        }
+
+        self.aging = Some(Aging {
+            model,
+            aged_until: self.clock.now(),
+        });
+        Ok(())
+    }
+
+    /// Returns the aging model, if this metabolism ages.
+    pub fn aging(&self) -> Option<AgingModel> {
+        self.aging.map(|aging| aging.model)
+    }
+
+    /// Returns the aging model and how far it has been applied.
+    pub fn aging_state(&self) -> Option<AgingState> {
+        self.aging.as_ref().map(Aging::state)
+    }
+
+    /// Reapplies persisted aging state.
+    ///
+    /// **INVARIANT**: A model already in place cannot change, and the
+    /// aged-until time can only move forward.
+    pub(crate) fn restore_aging(&mut self, state: AgingState) -> Result<(), String> {
+        let model = state.model();
+        if let Some(aging) = &self.aging
+            && (aging.model != model || state.aged_until < aging.aged_until)
+        {
+            return Err("Aging corruption: model replaced or aging rewound".to_string());
+        }
+
+        self.aging = Some(Aging {
+            model,
+            aged_until: state.aged_until,
+        });
+        Ok(())
+    }
+
+    /// Applies wall-clock aging up to `now`.
+    ///
+    /// **CONSEQUENCE**: If this exhausts the energy, the metabolism dies.
+    /// Returns the energy burned (0 without a wall-clock model).
+    pub fn tick(&mut self, now: DateTime<Utc>) -> u64 {
+        let Some(aging) = self.aging.as_mut() else {
+            return 0;
+        };
+        let AgingModel::WallClock { burn, period } = aging.model else {
+            return 0;
+        };
+
+        let (Some(elapsed), Some(period_ns)) = (
+            (now - aging.aged_until).num_nanoseconds(),
+            period.num_nanoseconds().filter(|ns| *ns > 0),
+        ) else {
+            return 0;
+        };
+        let periods = (elapsed / period_ns).max(0);
+        aging.aged_until += Duration::nanoseconds(periods * period_ns);
+
+        self.burn(burn.saturating_mul(periods as u64))
+    }
+
+    /// Applies `ticks` logical ticks of aging.
+    ///
+    /// **CONSEQUENCE**: If this exhausts the energy, the metabolism dies.
+    /// Returns the energy burned (0 without a logical model).
+    pub fn advance(&mut self, ticks: u64) -> u64 {
+        match self.aging.map(|aging| aging.model) {
+            Some(AgingModel::Logical { burn }) => self.burn(burn.saturating_mul(ticks)),
+            _ => 0,
+        }
+    }
+
+    /// Burns up to `amount` energy, dying at zero. Returns what was burned.
+    fn burn(&mut self, amount: u64) -> u64 {
+        if self.is_dead {
+            return 0;
+        }
+
+        let burned = amount.min(self.energy);
+        self.energy -= burned;
+        if self.energy == 0 {
+            self.is_dead = true;
+        }
+        burned
+    }
+
+    /// Applies lapsed reservations and pending forfeits.
+    ///
+    /// Returns the energy burned. Every mutating call settles first.
+    /// **CONSEQUENCE**: If forfeits exhaust the energy, the metabolism dies.
+    pub fn settle(&mut self) -> u64 {
+        let now = self.clock.now();
+        let mut book = lock_book(&self.reservations);
+        let lapsed: Vec<u64> = book
+            .holds
+            .iter()
+            .filter(|(_, hold)| hold.expires_at.is_some_and(|deadline| deadline <= now))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in lapsed {
+            book.abandon(id);
+        }
+
+        let forfeited = std::mem::take(&mut book.forfeited);
+        drop(book);
+
+        if forfeited > 0 {
+            self.burn(forfeited)
+        } else {
+            0
+        }
+    }
+
     /// Forces death regardless of remaining energy.
     /// 
     /// This represents a fatal injury or ontological violation.
+    /// Open reservations lapse with nothing left to burn.
     /// **CONSEQUENCE**: This is irreversible.
     pub fn die(&mut self) {
+        let mut book = lock_book(&self.reservations);
+        book.holds.clear();
+        book.forfeited = 0;
+        drop(book);
+
         self.energy = 0;
         self.is_dead = true;
     }
@@ -131,7 +579,24 @@ impl Metabolism {
             return 0;
         }
         
-        self.energy / operation_cost
+        self.available() / operation_cost
+    }
+}
+
+/// Serializes the settled state: pending forfeits and the forfeits of open
+/// holds are charged, so an export/import round trip cannot shed them.
+impl Serialize for Metabolism {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let outstanding = lock_book(&self.reservations).outstanding();
+        let energy = self.energy.saturating_sub(outstanding);
+        let is_dead = self.is_dead || (outstanding > 0 && energy == 0);
+
+        let mut state = serializer.serialize_struct("Metabolism", 4)?;
+        state.serialize_field("energy", &energy)?;
+        state.serialize_field("initial_energy", &self.initial_energy)?;
+        state.serialize_field("is_dead", &is_dead)?;
+        state.serialize_field("aging", &self.aging_state())?;
+        state.end()
     }
 }
 
@@ -139,6 +604,8 @@ impl Metabolism {
 // EXPLICIT PREVENTION: No method to restore energy
 // EXPLICIT PREVENTION: No method to revive after death
 // EXPLICIT PREVENTION: No method to reset metabolism
+// EXPLICIT PREVENTION: No method to cancel a reservation without its forfeit
+// EXPLICIT PREVENTION: No method to stop aging or rewind its clock
 
 #[cfg(test)]
 mod tests {
@@ -336,4 +803,126 @@ mod tests {
         // INVARIANT: There should be NO way to have zero energy but be alive
         // If alive status can be independent of energy, the metabolism system has been violated.
     }
+
+    #[test]
+    fn reservation_holds_energy_until_committed() {
+        let mut metabolism = Metabolism::new(100);
+
+        let reservation = metabolism.reserve(60).unwrap();
+        assert_eq!(metabolism.available(), 40);
+        assert_eq!(metabolism.energy(), 100);
+        assert_eq!(
+            metabolism.consume(50),
+            ConsumptionResult::Insufficient { requested: 50, available: 40 }
+        );
+
+        assert_eq!(metabolism.commit(reservation), ConsumptionResult::Success { remaining: 40 });
+        assert_eq!(metabolism.available(), 40);
+        assert_eq!(metabolism.reserved(), 0);
+    }
+
+    #[test]
+    fn dropped_reservation_burns_its_forfeit() {
+        let mut metabolism = Metabolism::new(100);
+        metabolism.set_forfeit_fraction(0.25);
+
+        let reservation = metabolism.reserve(40).unwrap();
+        assert_eq!(reservation.forfeit(), 10);
+        drop(reservation);
+
+        // Forfeited energy is never available again
+        assert_eq!(metabolism.available(), 90);
+        assert_eq!(metabolism.settle(), 10);
+        assert_eq!(metabolism.energy(), 90);
+        assert_eq!(metabolism.settle(), 0);
+    }
+
+    #[test]
+    fn foreign_reservation_is_refused() {
+        let mut owner = Metabolism::new(100);
+        let mut other = Metabolism::new(100);
+
+        let reservation = owner.reserve(40).unwrap();
+        assert_eq!(other.commit(reservation), ConsumptionResult::ForeignReservation);
+        assert_eq!(other.energy(), 100);
+
+        // The refused reservation was dropped: its owner pays the forfeit
+        assert_eq!(owner.settle(), 4);
+        assert_eq!(owner.available(), 96);
+    }
+
+    #[test]
+    fn lapsed_reservation_cannot_be_committed() {
+        let mut metabolism = Metabolism::new(100);
+
+        let reservation = metabolism.reserve_for(50, Duration::zero()).unwrap();
+        assert_eq!(metabolism.commit(reservation), ConsumptionResult::Expired { forfeited: 5 });
+        assert_eq!(metabolism.energy(), 95);
+        assert_eq!(metabolism.reserved(), 0);
+    }
+
+    #[test]
+    fn forfeits_can_exhaust_energy() {
+        let mut metabolism = Metabolism::new(10);
+        metabolism.set_forfeit_fraction(1.0);
+
+        drop(metabolism.reserve(10).unwrap());
+        metabolism.settle();
+
+        assert!(metabolism.is_dead());
+        assert_eq!(metabolism.energy(), 0);
+    }
+
+    #[test]
+    fn wall_clock_aging_burns_whole_periods_only() {
+        let mut metabolism = Metabolism::new(100);
+        metabolism.set_aging(AgingModel::WallClock { burn: 3, period: Duration::seconds(10) }).unwrap();
+        let start = Utc::now();
+
+        assert_eq!(metabolism.tick(start + Duration::seconds(25)), 6);
+        assert_eq!(metabolism.energy(), 94);
+
+        // The leftover 5 seconds carry into the next period
+        assert_eq!(metabolism.tick(start + Duration::seconds(31)), 3);
+        assert_eq!(metabolism.tick(start + Duration::seconds(31)), 0);
+    }
+
+    #[test]
+    fn aging_model_cannot_be_replaced() {
+        let mut metabolism = Metabolism::new(100);
+        let model = AgingModel::WallClock { burn: 3, period: Duration::seconds(10) };
+        metabolism.set_aging(model).unwrap();
+
+        assert!(metabolism.set_aging(AgingModel::WallClock { burn: 0, period: Duration::seconds(10) }).is_err());
+        assert!(metabolism.set_aging(AgingModel::Logical { burn: 100 }).is_err());
+        assert_eq!(metabolism.aging(), Some(model));
+    }
+
+    #[test]
+    fn aging_state_restores_but_never_rewinds() {
+        let mut metabolism = Metabolism::new(100);
+        metabolism.set_aging(AgingModel::WallClock { burn: 3, period: Duration::seconds(10) }).unwrap();
+        let state = metabolism.aging_state().unwrap();
+        assert_eq!(state.model(), metabolism.aging().unwrap());
+
+        let mut restored = Metabolism::new(100);
+        restored.restore_aging(state).unwrap();
+        assert_eq!(restored.aging_state(), Some(state));
+
+        let rewound = AgingState { aged_until: state.aged_until - Duration::hours(1), ..state };
+        assert!(restored.restore_aging(rewound).is_err());
+        assert!(restored.restore_aging(AgingState { burn: 0, ..state }).is_err());
+    }
+
+    #[test]
+    fn logical_aging_can_kill() {
+        let mut metabolism = Metabolism::new(10);
+        metabolism.set_aging(AgingModel::Logical { burn: 4 }).unwrap();
+
+        assert_eq!(metabolism.tick(Utc::now() + Duration::days(1)), 0);
+        assert_eq!(metabolism.advance(2), 8);
+        assert_eq!(metabolism.advance(1), 2);
+        assert!(metabolism.is_dead());
+        assert_eq!(metabolism.advance(1), 0);
+    }
 }
@@ -0,0 +1,820 @@
+//! # Persistence System
+//!
+//! Durable, append-only journals for living lineages.
+//!
+//! ## What This Enforces
+//! - Every memory event, energy consumption, scar and aging step is
+//!   journaled as it happens
+//! - Reopening charges the aging due for the time the journal sat closed
+//! - Journal records are hash-chained, so edits at rest are detected on load
+//! - The journal's head (record count and last hash) is kept beside it, so
+//!   dropping trailing records is detected on load
+//! - A reloaded lineage is rebuilt exactly and must pass all invariants
+//! - At most one live handle per identity (OS advisory lock)
+//! - Identities already buried in the graveyard can never be reloaded
+//!
+//! ## What This Forbids
+//! - Rewriting or truncating journal records
+//! - Opening the same journal twice concurrently (forking an identity)
+//! - Loading a journal whose memory chain or invariants fail
+//! - Loading a journal shorter than its head, or without a head
+//! - Resurrecting a dead identity through its journal
+//!
+//! ## Storage Format
+//! Each lineage is journaled as JSON lines in `<root>/<ID>.journal`, with
+//! its head in `<root>/<ID>.head`. The head is replaced atomically after
+//! every append; anchor [`LineageJournal::last_hash`] elsewhere (e.g. a
+//! signed tombstone or ledger) to also detect a rewritten head.
+//! While a handle is open it holds an advisory lock on `<root>/<ID>.lock`,
+//! which blocks other opens. The OS releases the lock when the handle is
+//! dropped or its process dies, so a crash never strands the journal.
+
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::archive::IdentityArchive;
+use crate::identity::RestoreError;
+use crate::lineage::{Lineage, OperationError, OperationResult};
+use crate::memory::{Event, Memory};
+use crate::metabolism::{AgingModel, AgingState, ConsumptionResult, Metabolism};
+use crate::scar::{Scar, ScarTissue};
+
+/// Errors that can occur while journaling or reloading a lineage
+#[derive(Debug, Clone)]
+pub enum PersistenceError {
+    /// IO operation failed
+    IoError(String),
+    /// Serialization/deserialization failed
+    SerializationError(String),
+    /// Another handle already holds the journal lock
+    Locked { id: String },
+    /// A journal for this identity already exists
+    JournalExists { id: String },
+    /// No journal found for this identity
+    NotFound { id: String },
+    /// The identity is buried in the graveyard
+    IdentityDead { id: String },
+    /// Journal record chain is broken (tampering detected)
+    TamperingDetected { id: String, record: u64 },
+    /// Journal holds fewer records than its head (trailing records dropped)
+    Truncated { id: String, expected: u64, found: u64 },
+    /// Journal replays into a lineage that violates its invariants
+    Corrupted { id: String, reason: String },
+    /// The lineage refused a change that would break its invariants
+    Refused { id: String, reason: String },
+}
+
+impl std::fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersistenceError::IoError(e) => write!(f, "IO Error: {}", e),
+            PersistenceError::SerializationError(e) => write!(f, "Serialization error: {}", e),
+            PersistenceError::Locked { id } => {
+                write!(f, "Journal is already open for ID: {}", id)
+            }
+            PersistenceError::JournalExists { id } => {
+                write!(f, "Journal already exists for ID: {}", id)
+            }
+            PersistenceError::NotFound { id } => write!(f, "No journal found for ID: {}", id),
+            PersistenceError::IdentityDead { id } => {
+                write!(f, "Identity is buried in the graveyard: {}", id)
+            }
+            PersistenceError::TamperingDetected { id, record } => {
+                write!(f, "Tampering detected in journal {} at record {}", id, record)
+            }
+            PersistenceError::Truncated { id, expected, found } => {
+                write!(f, "Journal {} is truncated: {} of {} records remain", id, found, expected)
+            }
+            PersistenceError::Corrupted { id, reason } => {
+                write!(f, "Journal {} is corrupted: {}", id, reason)
+            }
+            PersistenceError::Refused { id, reason } => {
+                write!(f, "Lineage {} refused the change: {}", id, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PersistenceError {}
+
+/// A single fact recorded in a lineage journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum JournalRecord {
+    /// Creation context of the identity and its energy budget
+    Birth {
+        identity: IdentityArchive,
+        initial_energy: u64,
+    },
+    /// A memory event, with its digest chain
+    Event(Event),
+    /// Energy permanently consumed
+    Consumption { cost: u64, remaining: u64 },
+    /// A permanent scar
+    Scar(Scar),
+    /// The aging model and how far it has been applied
+    Aging(AgingState),
+    /// Metabolic death
+    Death,
+    /// Memory sealed by termination
+    Sealed,
+}
+
+/// A journal record together with its position in the record chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalEntry {
+    seq: u64,
+    prev_hash: Option<String>,
+    hash: String,
+    record: JournalRecord,
+}
+
+impl JournalEntry {
+    fn compute_hash(seq: u64, prev_hash: Option<&str>, record: &JournalRecord) -> Result<String, PersistenceError> {
+        let record_json = serde_json::to_string(record)
+            .map_err(|e| PersistenceError::SerializationError(e.to_string()))?;
+
+        let mut hasher = Sha256::new();
+        if let Some(prev) = prev_hash {
+            hasher.update(prev.as_bytes());
+        }
+        hasher.update(seq.to_le_bytes());
+        hasher.update(record_json.as_bytes());
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
+
+/// Last known end of a journal, stored beside it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct JournalHead {
+    records: u64,
+    last_hash: Option<String>,
+}
+
+/// Append-only journal file for a single lineage.
+///
+/// Holding a `LineageJournal` means holding the identity's lock.
+/// The lock is released when the journal is dropped.
+#[derive(Debug)]
+pub struct LineageJournal {
+    id: String,
+    path: PathBuf,
+    head_path: PathBuf,
+    /// Open lock file; the advisory lock lives as long as this handle
+    _lock: File,
+    file: File,
+    next_seq: u64,
+    last_hash: Option<String>,
+}
+
+impl LineageJournal {
+    /// Returns the journal path for an identity under the given root
+    pub fn journal_path(root: &Path, id: &str) -> PathBuf {
+        root.join(format!("{}.journal", id))
+    }
+
+    /// Returns the head file path for an identity under the given root
+    pub fn head_path(root: &Path, id: &str) -> PathBuf {
+        root.join(format!("{}.head", id))
+    }
+
+    /// Returns the lock file path for an identity under the given root
+    pub fn lock_path(root: &Path, id: &str) -> PathBuf {
+        root.join(format!("{}.lock", id))
+    }
+
+    /// Creates a brand-new journal. Fails if one already exists.
+    fn create(root: &Path, id: &str) -> Result<Self, PersistenceError> {
+        fs::create_dir_all(root).map_err(|e| PersistenceError::IoError(e.to_string()))?;
+
+        let lock = Self::acquire_lock(root, id)?;
+        let path = Self::journal_path(root, id);
+
+        let file = match OpenOptions::new().append(true).create_new(true).open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                return Err(PersistenceError::JournalExists { id: id.to_string() });
+            }
+            Err(e) => return Err(PersistenceError::IoError(e.to_string())),
+        };
+
+        let journal = LineageJournal {
+            id: id.to_string(),
+            path,
+            head_path: Self::head_path(root, id),
+            _lock: lock,
+            file,
+            next_seq: 0,
+            last_hash: None,
+        };
+        journal.write_head()?;
+
+        Ok(journal)
+    }
+
+    /// Opens an existing journal, verifying its record chain.
+    fn open(root: &Path, id: &str) -> Result<(Self, Vec<JournalRecord>), PersistenceError> {
+        let path = Self::journal_path(root, id);
+        if !path.exists() {
+            return Err(PersistenceError::NotFound { id: id.to_string() });
+        }
+
+        let lock = Self::acquire_lock(root, id)?;
+
+        let (records, hashes) = Self::read_verified(&path, id)?;
+        let head_path = Self::head_path(root, id);
+        Self::check_head(&head_path, id, &hashes)?;
+
+        let file = OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .map_err(|e| PersistenceError::IoError(e.to_string()))?;
+
+        Ok((
+            LineageJournal {
+                id: id.to_string(),
+                path,
+                head_path,
+                _lock: lock,
+                file,
+                next_seq: hashes.len() as u64,
+                last_hash: hashes.last().cloned(),
+            },
+            records,
+        ))
+    }
+
+    /// Takes the advisory lock for an identity (fails if already held).
+    ///
+    /// A lock file left behind by a crashed process is simply reused: the
+    /// OS dropped its lock when the process died.
+    fn acquire_lock(root: &Path, id: &str) -> Result<File, PersistenceError> {
+        let lock_path = Self::lock_path(root, id);
+        let mut lock = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)
+            .map_err(|e| PersistenceError::IoError(e.to_string()))?;
+
+        match lock.try_lock() {
+            Ok(()) => {
+                // Holder PID, for operators only; the lock itself is the OS lock
+                let _ = lock.set_len(0).and_then(|_| writeln!(lock, "{}", std::process::id()));
+                Ok(lock)
+            }
+            Err(TryLockError::WouldBlock) => Err(PersistenceError::Locked { id: id.to_string() }),
+            Err(TryLockError::Error(e)) => Err(PersistenceError::IoError(e.to_string())),
+        }
+    }
+
+    /// Reads every record, checking the record chain as it goes.
+    ///
+    /// Returns the records and the hash of each one.
+    fn read_verified(path: &Path, id: &str) -> Result<(Vec<JournalRecord>, Vec<String>), PersistenceError> {
+        let file = File::open(path).map_err(|e| PersistenceError::IoError(e.to_string()))?;
+        let reader = BufReader::new(file);
+
+        let mut records = Vec::new();
+        let mut hashes: Vec<String> = Vec::new();
+        let mut expected_seq = 0u64;
+
+        for line in reader.lines() {
+            let line = line.map_err(|e| PersistenceError::IoError(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry: JournalEntry = serde_json::from_str(&line)
+                .map_err(|e| PersistenceError::SerializationError(e.to_string()))?;
+
+            let computed = JournalEntry::compute_hash(entry.seq, entry.prev_hash.as_deref(), &entry.record)?;
+            if entry.seq != expected_seq || entry.prev_hash.as_ref() != hashes.last() || computed != entry.hash {
+                return Err(PersistenceError::TamperingDetected {
+                    id: id.to_string(),
+                    record: expected_seq,
+                });
+            }
+
+            hashes.push(entry.hash);
+            records.push(entry.record);
+            expected_seq += 1;
+        }
+
+        Ok((records, hashes))
+    }
+
+    /// Refuses a journal that ends before its head or disagrees with it.
+    ///
+    /// A journal may run past its head: the process died between appending
+    /// a record and replacing the head.
+    fn check_head(head_path: &Path, id: &str, hashes: &[String]) -> Result<(), PersistenceError> {
+        let content = match fs::read_to_string(head_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(PersistenceError::Corrupted {
+                    id: id.to_string(),
+                    reason: "journal head is missing".to_string(),
+                });
+            }
+            Err(e) => return Err(PersistenceError::IoError(e.to_string())),
+        };
+        let head: JournalHead = serde_json::from_str(&content)
+            .map_err(|e| PersistenceError::SerializationError(e.to_string()))?;
+
+        let found = hashes.len() as u64;
+        if head.records > found {
+            return Err(PersistenceError::Truncated {
+                id: id.to_string(),
+                expected: head.records,
+                found,
+            });
+        }
+
+        let at_head = head.records.checked_sub(1).map(|last| hashes[last as usize].clone());
+        if at_head != head.last_hash {
+            return Err(PersistenceError::TamperingDetected {
+                id: id.to_string(),
+                record: head.records.saturating_sub(1),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Atomically replaces the head file with the current end of the journal
+    fn write_head(&self) -> Result<(), PersistenceError> {
+        let head = JournalHead {
+            records: self.next_seq,
+            last_hash: self.last_hash.clone(),
+        };
+        let json = serde_json::to_vec(&head)
+            .map_err(|e| PersistenceError::SerializationError(e.to_string()))?;
+
+        let tmp_path = self.head_path.with_extension("head.tmp");
+        let mut tmp = File::create(&tmp_path).map_err(|e| PersistenceError::IoError(e.to_string()))?;
+        tmp.write_all(&json)
+            .and_then(|_| tmp.sync_data())
+            .map_err(|e| PersistenceError::IoError(e.to_string()))?;
+        fs::rename(&tmp_path, &self.head_path).map_err(|e| PersistenceError::IoError(e.to_string()))
+    }
+
+    /// Appends and fsyncs one record
+    fn append(&mut self, record: JournalRecord) -> Result<(), PersistenceError> {
+        let hash = JournalEntry::compute_hash(self.next_seq, self.last_hash.as_deref(), &record)?;
+        let entry = JournalEntry {
+            seq: self.next_seq,
+            prev_hash: self.last_hash.clone(),
+            hash,
+            record,
+        };
+
+        let mut line = serde_json::to_string(&entry)
+            .map_err(|e| PersistenceError::SerializationError(e.to_string()))?;
+        line.push('\n');
+
+        self.file
+            .write_all(line.as_bytes())
+            .map_err(|e| PersistenceError::IoError(e.to_string()))?;
+        self.file
+            .sync_data()
+            .map_err(|e| PersistenceError::IoError(e.to_string()))?;
+
+        self.next_seq += 1;
+        self.last_hash = Some(entry.hash);
+        self.write_head()
+    }
+
+    /// Returns the identity this journal belongs to
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the path of the journal file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the number of records written so far
+    pub fn record_count(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// Returns the hash of the last record, if any
+    pub fn last_hash(&self) -> Option<&str> {
+        self.last_hash.as_deref()
+    }
+}
+
+/// A lineage bound to its durable journal.
+///
+/// All mutations go through this handle so that every new event,
+/// consumption and scar reaches disk before the call returns.
+///
+/// **INVARIANT**: At most one `PersistentLineage` exists per identity.
+/// **INVARIANT**: A buried identity can never be reopened.
+pub struct PersistentLineage {
+    lineage: Lineage,
+    journal: LineageJournal,
+    persisted_events: usize,
+    persisted_scars: usize,
+    persisted_energy: u64,
+    persisted_aging: Option<AgingState>,
+    persisted_dead: bool,
+    persisted_sealed: bool,
+}
+
+impl PersistentLineage {
+    /// Creates a new lineage and starts its journal under `root`.
+    pub fn create(root: impl AsRef<Path>, initial_energy: u64) -> Result<Self, PersistenceError> {
+        let lineage = Lineage::create(initial_energy);
+        let mut journal = LineageJournal::create(root.as_ref(), lineage.identity().id())?;
+
+        journal.append(JournalRecord::Birth {
+            identity: IdentityArchive::from_identity(lineage.identity()),
+            initial_energy,
+        })?;
+
+        let mut persistent = PersistentLineage {
+            lineage,
+            journal,
+            persisted_events: 0,
+            persisted_scars: 0,
+            persisted_energy: initial_energy,
+            persisted_aging: None,
+            persisted_dead: false,
+            persisted_sealed: false,
+        };
+        persistent.sync()?;
+
+        Ok(persistent)
+    }
+
+    /// Reloads a lineage from its journal under `root`.
+    ///
+    /// Fails if the journal is locked, tampered with, replays into a
+    /// lineage that violates its invariants, or if the identity is dead.
+    /// Aging due for the time since the last record is charged and journaled.
+    pub fn open(root: impl AsRef<Path>, id: &str) -> Result<Self, PersistenceError> {
+        crate::lazarus::check(id)
+            .map_err(|violation| PersistenceError::IdentityDead { id: violation.id })?;
+
+        let (journal, records) = LineageJournal::open(root.as_ref(), id)?;
+        let lineage = Self::replay(id, records)?;
+
+        let mut persistent = PersistentLineage {
+            persisted_events: lineage.memory().event_count(),
+            persisted_scars: lineage.scars().scar_count(),
+            persisted_energy: lineage.metabolism().energy(),
+            persisted_aging: lineage.metabolism().aging_state(),
+            persisted_dead: lineage.metabolism().is_dead(),
+            persisted_sealed: lineage.memory().is_terminated(),
+            lineage,
+            journal,
+        };
+
+        // Time kept passing while the journal was closed
+        let now = persistent.lineage.clock().now();
+        persistent.tick(now)?;
+
+        Ok(persistent)
+    }
+
+    /// Rebuilds the exact lineage described by a journal's records
+    fn replay(id: &str, records: Vec<JournalRecord>) -> Result<Lineage, PersistenceError> {
+        let corrupted = |reason: String| PersistenceError::Corrupted {
+            id: id.to_string(),
+            reason,
+        };
+
+        let mut records = records.into_iter();
+
+        let (identity, mut metabolism) = match records.next() {
+            Some(JournalRecord::Birth {
+                identity,
+                initial_energy,
+            }) => {
+                if identity.id != id {
+                    return Err(corrupted(format!("journal belongs to {}", identity.id)));
+                }
+
+                (
+                    identity.into_identity().map_err(|error| match error {
+                        RestoreError::Buried(violation) => {
+                            PersistenceError::IdentityDead { id: violation.id }
+                        }
+                        RestoreError::Invalid(reason) => corrupted(reason),
+                    })?,
+                    Metabolism::new(initial_energy),
+                )
+            }
+            _ => return Err(corrupted("journal does not start with a birth record".to_string())),
+        };
+
+        let mut events = Vec::new();
+        let mut scars = ScarTissue::new();
+        let mut sealed = false;
+
+        for record in records {
+            match record {
+                JournalRecord::Birth { .. } => {
+                    return Err(corrupted("duplicate birth record".to_string()));
+                }
+                JournalRecord::Event(event) => events.push(event),
+                JournalRecord::Consumption { cost, remaining } => match metabolism.consume(cost) {
+                    ConsumptionResult::Success { remaining: actual } if actual == remaining => {}
+                    _ => return Err(corrupted(format!("consumption of {} does not replay", cost))),
+                },
+                JournalRecord::Scar(scar) => scars.restore(scar).map_err(corrupted)?,
+                JournalRecord::Aging(state) => metabolism.restore_aging(state).map_err(corrupted)?,
+                JournalRecord::Death => metabolism.die(),
+                JournalRecord::Sealed => sealed = true,
+            }
+        }
+
+        let memory = Memory::restore(events, sealed).map_err(corrupted)?;
+        Lineage::restore(identity, memory, metabolism, scars).map_err(corrupted)
+    }
+
+    /// Returns read-only access to the lineage.
+    pub fn lineage(&self) -> &Lineage {
+        &self.lineage
+    }
+
+    /// Returns the underlying journal.
+    pub fn journal(&self) -> &LineageJournal {
+        &self.journal
+    }
+
+    /// Performs an operation and journals its consequences.
+    pub fn perform_operation(
+        &mut self,
+        description: String,
+        energy_cost: u64,
+    ) -> Result<OperationResult, PersistenceError> {
+        let result = self.lineage.perform_operation(description, energy_cost);
+        self.sync()?;
+        Ok(result)
+    }
+
+    /// Records an error as a permanent scar and journals it.
+    pub fn record_error(&mut self, error: OperationError) -> Result<OperationResult, PersistenceError> {
+        let result = self.lineage.record_error(error);
+        self.sync()?;
+        Ok(result)
+    }
+
+    /// Makes the lineage age under `model` and journals it.
+    ///
+    /// Fails with `Refused` if the lineage already ages: the model can
+    /// never be replaced.
+    pub fn set_aging(&mut self, model: AgingModel) -> Result<(), PersistenceError> {
+        self.lineage
+            .set_aging(model)
+            .map_err(|reason| PersistenceError::Refused {
+                id: self.journal.id().to_string(),
+                reason,
+            })?;
+        self.sync()
+    }
+
+    /// Applies wall-clock aging up to `now` and journals it. Returns the energy burned.
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> Result<u64, PersistenceError> {
+        let burned = self.lineage.tick(now);
+        self.sync()?;
+        Ok(burned)
+    }
+
+    /// Applies `ticks` logical ticks of aging and journals them. Returns the energy burned.
+    pub fn advance(&mut self, ticks: u64) -> Result<u64, PersistenceError> {
+        let burned = self.lineage.advance(ticks);
+        self.sync()?;
+        Ok(burned)
+    }
+
+    /// Appends a free-form event to memory and journals it.
+    pub fn record_event(&mut self, description: String) -> Result<(), PersistenceError> {
+        self.lineage.memory_mut().append(description);
+        self.sync()
+    }
+
+    /// Consumes the handle, releasing the lock and returning the lineage.
+    pub fn into_lineage(self) -> Lineage {
+        self.lineage
+    }
+
+    /// Writes every change since the last sync to the journal.
+    fn sync(&mut self) -> Result<(), PersistenceError> {
+        let metabolism = self.lineage.metabolism();
+        if metabolism.energy() < self.persisted_energy {
+            self.journal.append(JournalRecord::Consumption {
+                cost: self.persisted_energy - metabolism.energy(),
+                remaining: metabolism.energy(),
+            })?;
+            self.persisted_energy = metabolism.energy();
+        }
+
+        let aging = metabolism.aging_state();
+        if aging != self.persisted_aging
+            && let Some(state) = aging
+        {
+            self.journal.append(JournalRecord::Aging(state))?;
+            self.persisted_aging = aging;
+        }
+
+        let scars = self.lineage.scars().all_scars();
+        for scar in &scars[self.persisted_scars..] {
+            self.journal.append(JournalRecord::Scar(scar.clone()))?;
+        }
+        self.persisted_scars = scars.len();
+
+        if self.lineage.metabolism().is_dead() && !self.persisted_dead {
+            self.journal.append(JournalRecord::Death)?;
+            self.persisted_dead = true;
+        }
+
+        let history = self.lineage.memory().history();
+        for event in &history[self.persisted_events..] {
+            self.journal.append(JournalRecord::Event(event.clone()))?;
+        }
+        self.persisted_events = history.len();
+
+        if self.lineage.memory().is_terminated() && !self.persisted_sealed {
+            self.journal.append(JournalRecord::Sealed)?;
+            self.persisted_sealed = true;
+        }
+
+        Ok(())
+    }
+}
+
+// EXPLICIT PREVENTION: No method to rewrite or truncate a journal
+// EXPLICIT PREVENTION: No method to open a journal without its lock
+// EXPLICIT PREVENTION: No method to reopen a buried identity
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scar::ScarSeverity;
+
+    fn temp_root() -> PathBuf {
+        std::env::temp_dir().join(format!("lineage-journal-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn reload_rebuilds_exact_lineage() {
+        let root = temp_root();
+        let id = {
+            let mut persistent = PersistentLineage::create(&root, 1000).unwrap();
+            persistent.perform_operation("Op 1".to_string(), 100).unwrap();
+            persistent
+                .record_error(OperationError::new(ScarSeverity::Moderate, "Timeout".to_string()))
+                .unwrap();
+            persistent.record_event("Checkpoint".to_string()).unwrap();
+            persistent.lineage().identity().id().to_string()
+        };
+
+        let reloaded = PersistentLineage::open(&root, &id).unwrap();
+        let lineage = reloaded.lineage();
+
+        assert_eq!(lineage.identity().id(), id);
+        assert_eq!(lineage.metabolism().energy(), 900);
+        assert_eq!(lineage.scars().scar_count(), 1);
+        assert_eq!(lineage.memory().latest().description(), "Checkpoint");
+        assert!(lineage.verify_invariants().is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn second_concurrent_open_is_locked() {
+        let root = temp_root();
+        let persistent = PersistentLineage::create(&root, 100).unwrap();
+        let id = persistent.lineage().identity().id().to_string();
+
+        let second = PersistentLineage::open(&root, &id);
+        assert!(matches!(second, Err(PersistenceError::Locked { .. })));
+
+        drop(persistent);
+        assert!(PersistentLineage::open(&root, &id).is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn stale_lock_file_from_crashed_process_does_not_block_open() {
+        let root = temp_root();
+        let id = {
+            let persistent = PersistentLineage::create(&root, 100).unwrap();
+            persistent.lineage().identity().id().to_string()
+        };
+
+        // A process that died while holding the journal leaves its lock file behind
+        fs::write(LineageJournal::lock_path(&root, &id), "999999\n").unwrap();
+
+        let reopened = PersistentLineage::open(&root, &id).unwrap();
+        assert!(matches!(PersistentLineage::open(&root, &id), Err(PersistenceError::Locked { .. })));
+        drop(reopened);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn death_survives_reload() {
+        let root = temp_root();
+        let id = {
+            let mut persistent = PersistentLineage::create(&root, 100).unwrap();
+            persistent
+                .record_error(OperationError::new(ScarSeverity::Fatal, "Crash".to_string()))
+                .unwrap();
+            persistent.lineage().identity().id().to_string()
+        };
+
+        let reloaded = PersistentLineage::open(&root, &id).unwrap();
+        assert!(!reloaded.lineage().is_alive());
+        assert!(reloaded.lineage().memory().is_terminated());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn tampered_journal_is_refused() {
+        let root = temp_root();
+        let id = {
+            let mut persistent = PersistentLineage::create(&root, 1000).unwrap();
+            persistent.perform_operation("Transfer 10".to_string(), 10).unwrap();
+            persistent.lineage().identity().id().to_string()
+        };
+
+        let path = LineageJournal::journal_path(&root, &id);
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, content.replace("Transfer 10", "Transfer 99")).unwrap();
+
+        let result = PersistentLineage::open(&root, &id);
+        assert!(matches!(result, Err(PersistenceError::TamperingDetected { .. })));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn dropping_trailing_records_is_refused() {
+        let root = temp_root();
+        let id = {
+            let mut persistent = PersistentLineage::create(&root, 100).unwrap();
+            persistent.perform_operation("Spend".to_string(), 60).unwrap();
+            persistent
+                .record_error(OperationError::new(ScarSeverity::Fatal, "Crash".to_string()))
+                .unwrap();
+            persistent.lineage().identity().id().to_string()
+        };
+
+        // Cut the journal back to its birth: energy and life would come back
+        let path = LineageJournal::journal_path(&root, &id);
+        let content = fs::read_to_string(&path).unwrap();
+        let birth = content.lines().next().unwrap();
+        fs::write(&path, format!("{}\n", birth)).unwrap();
+
+        let result = PersistentLineage::open(&root, &id);
+        assert!(matches!(result, Err(PersistenceError::Truncated { expected, found: 1, .. }) if expected > 1));
+
+        // Deleting the head as well does not help
+        fs::remove_file(LineageJournal::head_path(&root, &id)).unwrap();
+        assert!(matches!(PersistentLineage::open(&root, &id), Err(PersistenceError::Corrupted { .. })));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn aging_is_journaled_and_charged_on_reopen() {
+        let root = temp_root();
+        let model = AgingModel::WallClock { burn: 1, period: chrono::Duration::milliseconds(1) };
+        let id = {
+            let mut persistent = PersistentLineage::create(&root, 1000).unwrap();
+            persistent.set_aging(model).unwrap();
+            assert!(matches!(
+                persistent.set_aging(AgingModel::Logical { burn: 0 }),
+                Err(PersistenceError::Refused { .. })
+            ));
+            persistent.lineage().identity().id().to_string()
+        };
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let reopened = PersistentLineage::open(&root, &id).unwrap();
+        assert_eq!(reopened.lineage().metabolism().aging(), Some(model));
+        let energy = reopened.lineage().metabolism().energy();
+        assert!(energy <= 980);
+        assert!(reopened.lineage().memory().latest().description().starts_with("Aging:"));
+        drop(reopened);
+
+        // The charge was journaled: it is never refunded by another reopen
+        let reopened = PersistentLineage::open(&root, &id).unwrap();
+        assert!(reopened.lineage().metabolism().energy() <= energy);
+        drop(reopened);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
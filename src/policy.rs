@@ -0,0 +1,278 @@
+//! # Action Policies
+//!
+//! ## What This Enforces
+//! - Every named action declares the capabilities it requires, its energy
+//!   cost and the violation raised when it is attempted without them
+//! - Capability checks live in one declarative, auditable place instead of
+//!   at every call site
+//! - A policy set has a stable digest, so the rules in force can be recorded
+//!
+//! ## What This Forbids
+//! - Registering the same action twice (the first rule stands)
+//! - Running an action no policy describes (unknown actions are denied)
+//!
+//! ## File Format
+//! Policies load from TOML or JSON with the same shape:
+//!
+//! ```toml
+//! [[action]]
+//! name = "rotate_keys"
+//! requires = ["Configure", "AccessSensitive"]
+//! energy_cost = 25
+//! on_violation = "PolicyBypass"
+//! ```
+//!
+//! Enforcement happens in [`TrustedActor::attempt_named_action`](crate::trust::TrustedActor::attempt_named_action).
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::trust::{TrustCapability, ViolationType};
+
+/// Errors that can occur while building or loading a policy set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    /// Policy file could not be read
+    IoError(String),
+    /// Policy file is not valid TOML/JSON for a policy set
+    ParseError(String),
+    /// Policy file extension is neither `.toml` nor `.json`
+    UnsupportedFormat(String),
+    /// An action with this name is already registered
+    DuplicateAction(String),
+    /// Action names must be non-empty ASCII alphanumerics, `-`, `_` or `.`
+    InvalidName(String),
+}
+
+impl std::fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyError::IoError(msg) => write!(f, "Policy IO error: {}", msg),
+            PolicyError::ParseError(msg) => write!(f, "Invalid policy file: {}", msg),
+            PolicyError::UnsupportedFormat(path) => {
+                write!(f, "Unsupported policy format (expected .toml or .json): {}", path)
+            }
+            PolicyError::DuplicateAction(name) => write!(f, "Action {} is already registered", name),
+            PolicyError::InvalidName(name) => write!(f, "Invalid action name: {:?}", name),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+/// Rule for one named action
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionPolicy {
+    /// Action name, e.g. `rotate_keys`
+    pub name: String,
+    /// Capabilities the actor must hold (all of them)
+    #[serde(default)]
+    pub requires: Vec<TrustCapability>,
+    /// Energy consumed by a permitted attempt
+    #[serde(default)]
+    pub energy_cost: u64,
+    /// Violation recorded when the actor lacks a required capability
+    pub on_violation: ViolationType,
+}
+
+impl ActionPolicy {
+    /// Create a rule for `name`.
+    pub fn new(
+        name: impl Into<String>,
+        requires: &[TrustCapability],
+        energy_cost: u64,
+        on_violation: ViolationType,
+    ) -> Self {
+        ActionPolicy {
+            name: name.into(),
+            requires: requires.to_vec(),
+            energy_cost,
+            on_violation,
+        }
+    }
+}
+
+/// On-disk shape of a policy set (`[[action]]` tables)
+#[derive(Debug, Serialize, Deserialize)]
+struct PolicyFile {
+    #[serde(default, rename = "action")]
+    actions: Vec<ActionPolicy>,
+}
+
+/// Registry of named actions and the rules that govern them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicySet {
+    actions: BTreeMap<String, ActionPolicy>,
+}
+
+impl PolicySet {
+    /// Create an empty policy set (every action is denied).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a rule.
+    ///
+    /// Required capabilities are stored sorted and deduplicated.
+    pub fn register(&mut self, mut policy: ActionPolicy) -> Result<(), PolicyError> {
+        validate_name(&policy.name)?;
+        if self.actions.contains_key(&policy.name) {
+            return Err(PolicyError::DuplicateAction(policy.name));
+        }
+
+        policy.requires.sort();
+        policy.requires.dedup();
+        self.actions.insert(policy.name.clone(), policy);
+        Ok(())
+    }
+
+    /// Look up the rule for an action.
+    pub fn get(&self, name: &str) -> Option<&ActionPolicy> {
+        self.actions.get(name)
+    }
+
+    /// Every rule, ordered by action name.
+    pub fn actions(&self) -> impl Iterator<Item = &ActionPolicy> {
+        self.actions.values()
+    }
+
+    /// Number of registered actions.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// True if no actions are registered.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Parse a policy set from TOML.
+    pub fn from_toml(source: &str) -> Result<Self, PolicyError> {
+        let file: PolicyFile = toml::from_str(source).map_err(parse_error)?;
+        Self::from_file(file)
+    }
+
+    /// Parse a policy set from JSON.
+    pub fn from_json(source: &str) -> Result<Self, PolicyError> {
+        let file: PolicyFile = serde_json::from_str(source).map_err(parse_error)?;
+        Self::from_file(file)
+    }
+
+    /// Load a policy set from a `.toml` or `.json` file.
+    pub fn load(path: &Path) -> Result<Self, PolicyError> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| PolicyError::IoError(format!("{}: {}", path.display(), e)))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&source),
+            Some("json") => Self::from_json(&source),
+            _ => Err(PolicyError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+
+    /// Serialize to TOML (the canonical, reviewable form).
+    pub fn to_toml(&self) -> String {
+        toml::to_string(&self.to_file()).expect("Policy sets always serialize")
+    }
+
+    /// SHA-256 over the canonical JSON form.
+    ///
+    /// Identical rules give identical digests regardless of file format or
+    /// registration order, so the digest identifies the rules in force.
+    pub fn digest(&self) -> String {
+        let canonical = serde_json::to_vec(&self.to_file()).expect("Policy sets always serialize");
+        format!("{:x}", Sha256::digest(&canonical))
+    }
+
+    fn from_file(file: PolicyFile) -> Result<Self, PolicyError> {
+        let mut set = PolicySet::new();
+        for policy in file.actions {
+            set.register(policy)?;
+        }
+        Ok(set)
+    }
+
+    fn to_file(&self) -> PolicyFile {
+        PolicyFile {
+            actions: self.actions.values().cloned().collect(),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), PolicyError> {
+    let valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(PolicyError::InvalidName(name.to_string()))
+    }
+}
+
+fn parse_error(e: impl std::fmt::Display) -> PolicyError {
+    PolicyError::ParseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICIES: &str = r#"
+        [[action]]
+        name = "rotate_keys"
+        requires = ["Configure", "AccessSensitive", "Configure"]
+        energy_cost = 25
+        on_violation = "PolicyBypass"
+
+        [[action]]
+        name = "read_audit_log"
+        requires = ["Audit"]
+        energy_cost = 1
+        on_violation = "UnauthorizedAccess"
+    "#;
+
+    #[test]
+    fn toml_and_json_load_the_same_rules() {
+        let from_toml = PolicySet::from_toml(POLICIES).unwrap();
+        let rule = from_toml.get("rotate_keys").unwrap();
+        assert_eq!(rule.requires, vec![TrustCapability::Configure, TrustCapability::AccessSensitive]);
+        assert_eq!(rule.on_violation, ViolationType::PolicyBypass);
+
+        let json = serde_json::json!({
+            "action": [
+                { "name": "read_audit_log", "requires": ["Audit"], "energy_cost": 1, "on_violation": "UnauthorizedAccess" },
+                { "name": "rotate_keys", "requires": ["AccessSensitive", "Configure"], "energy_cost": 25, "on_violation": "PolicyBypass" }
+            ]
+        });
+        let from_json = PolicySet::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(from_json, from_toml);
+        assert_eq!(from_json.digest(), from_toml.digest());
+        assert_eq!(PolicySet::from_toml(&from_toml.to_toml()).unwrap(), from_toml);
+    }
+
+    #[test]
+    fn malformed_or_duplicate_rules_are_refused() {
+        let duplicate = format!("{}\n[[action]]\nname = \"rotate_keys\"\non_violation = \"PolicyBypass\"", POLICIES);
+        assert_eq!(
+            PolicySet::from_toml(&duplicate),
+            Err(PolicyError::DuplicateAction("rotate_keys".to_string()))
+        );
+
+        let unknown_capability = "[[action]]\nname = \"x\"\nrequires = [\"Root\"]\non_violation = \"PolicyBypass\"";
+        assert!(matches!(PolicySet::from_toml(unknown_capability), Err(PolicyError::ParseError(_))));
+
+        let mut set = PolicySet::new();
+        assert_eq!(
+            set.register(ActionPolicy::new("../escape", &[], 0, ViolationType::PolicyBypass)),
+            Err(PolicyError::InvalidName("../escape".to_string()))
+        );
+        assert!(set.is_empty());
+    }
+}
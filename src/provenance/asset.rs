@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::clock::{Clock, SystemClock};
 use crate::identity::Identity;
+use crate::merkle::{MerkleProof, MerkleTree};
 use crate::provenance::events::CustodyEvent;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -16,7 +19,7 @@ impl AssetId {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CustodianId(String);
 
 impl CustodianId {
@@ -29,7 +32,7 @@ impl CustodianId {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MetadataHash(String);
 
 impl MetadataHash {
@@ -60,11 +63,41 @@ pub struct Asset {
 
 impl Asset {
     pub fn new(label: String, metadata_hash: MetadataHash, custodian: CustodianId) -> Self {
+        Self::with_clock(label, metadata_hash, custodian, &SystemClock)
+    }
+
+    /// Create an asset whose identity and creation time are read from `clock`
+    pub fn with_clock(
+        label: String,
+        metadata_hash: MetadataHash,
+        custodian: CustodianId,
+        clock: &dyn Clock,
+    ) -> Self {
         Self {
-            id: Identity::create(),
+            id: Identity::create_with_clock(clock),
             label,
             metadata_hash,
-            created_at: Utc::now(),
+            created_at: clock.now(),
+            current_custodian: custodian,
+            sealed: false,
+            seal_reason: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Rebuilds an asset from its persisted identity and genesis fields
+    pub(crate) fn restore(
+        id: Identity,
+        label: String,
+        metadata_hash: MetadataHash,
+        created_at: DateTime<Utc>,
+        custodian: CustodianId,
+    ) -> Self {
+        Self {
+            id,
+            label,
+            metadata_hash,
+            created_at,
             current_custodian: custodian,
             sealed: false,
             seal_reason: None,
@@ -104,6 +137,16 @@ impl Asset {
         &self.events
     }
 
+    /// Merkle tree over the custody event hashes, in order.
+    pub fn custody_tree(&self) -> MerkleTree {
+        MerkleTree::from_leaves(self.events.iter().map(|event| event.hash.as_bytes()))
+    }
+
+    /// Inclusion proof for a single custody event against `custody_tree().root()`.
+    pub fn custody_proof(&self, index: usize) -> Option<MerkleProof> {
+        self.custody_tree().proof(index)
+    }
+
     pub(crate) fn push_event(&mut self, event: CustodyEvent) {
         self.events.push(event);
     }
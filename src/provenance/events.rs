@@ -1,8 +1,9 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::provenance::{CustodianId, MetadataHash};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CustodyEventType {
     Genesis {
         custodian: CustodianId,
@@ -21,7 +22,7 @@ pub enum CustodyEventType {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustodyEvent {
     pub event_type: CustodyEventType,
     pub timestamp: DateTime<Utc>,
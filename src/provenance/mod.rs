@@ -8,4 +8,4 @@ mod vault;
 pub use asset::{Asset, AssetId, CustodianId, MetadataHash};
 pub use events::{CustodyEvent, CustodyEventType};
 pub use verify::{VerifyReport, VerifyStatus};
-pub use vault::{ProvenanceConfig, ProvenanceError, ProvenanceVault};
+pub use vault::{ProvenanceConfig, ProvenanceError, ProvenanceVault, CUSTODY_NAMESPACE};
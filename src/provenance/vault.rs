@@ -1,12 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::archive::IdentityArchive;
+use crate::clock::{self, SharedClock};
+use crate::identity::RestoreError;
 use crate::lineage::{Lineage, OperationResult};
 use crate::provenance::asset::{Asset, AssetId, CustodianId, MetadataHash};
 use crate::provenance::events::{CustodyEvent, CustodyEventType};
 use crate::provenance::verify::{verify_chain, VerifyReport};
+use crate::store::{LineageStore, StoreError};
+
+/// Store namespace holding custody records
+pub const CUSTODY_NAMESPACE: &str = "custody";
 
 #[derive(Debug, Clone)]
 pub struct ProvenanceConfig {
@@ -23,10 +32,28 @@ impl Default for ProvenanceConfig {
     }
 }
 
+/// Asset identity, persisted with its genesis record
+#[derive(Debug, Serialize, Deserialize)]
+struct AssetRecord {
+    identity: IdentityArchive,
+    created_at: DateTime<Utc>,
+}
+
+/// One persisted custody event, keyed `<asset id>-<sequence>`
+#[derive(Debug, Serialize, Deserialize)]
+struct CustodyRecord {
+    /// Present on the genesis record only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asset: Option<AssetRecord>,
+    event: CustodyEvent,
+}
+
 pub struct ProvenanceVault {
     lineage: Lineage,
     config: ProvenanceConfig,
     assets: HashMap<AssetId, Asset>,
+    store: Option<Arc<dyn LineageStore>>,
+    clock: SharedClock,
 }
 
 impl ProvenanceVault {
@@ -35,11 +62,141 @@ impl ProvenanceVault {
     }
 
     pub fn with_config(config: ProvenanceConfig) -> Self {
+        Self::with_clock(config, clock::system())
+    }
+
+    /// Create a vault whose lineage, assets and custody events read time from `clock`.
+    pub fn with_clock(config: ProvenanceConfig, clock: SharedClock) -> Self {
         Self {
-            lineage: Lineage::create(config.initial_energy),
+            lineage: Lineage::create_with_clock(config.initial_energy, clock.clone()),
             config,
             assets: HashMap::new(),
+            store: None,
+            clock,
+        }
+    }
+
+    /// Open a vault whose custody chains persist in `store`.
+    ///
+    /// Every stored asset is replayed and its chain re-verified. The
+    /// vault's energy budget is not persisted: it starts fresh from `config`.
+    pub fn with_store(
+        config: ProvenanceConfig,
+        store: Arc<dyn LineageStore>,
+    ) -> Result<Self, ProvenanceError> {
+        Self::with_store_and_clock(config, store, clock::system())
+    }
+
+    /// Open a vault persisting in `store` that reads time from `clock`.
+    pub fn with_store_and_clock(
+        config: ProvenanceConfig,
+        store: Arc<dyn LineageStore>,
+        clock: SharedClock,
+    ) -> Result<Self, ProvenanceError> {
+        let mut records: BTreeMap<String, Vec<(String, CustodyRecord)>> = BTreeMap::new();
+        for key in store.keys(CUSTODY_NAMESPACE).map_err(storage_error)? {
+            let (asset_id, _) = key
+                .rsplit_once('-')
+                .ok_or_else(|| corrupted(format!("custody key {}", key)))?;
+            let bytes = store
+                .read(CUSTODY_NAMESPACE, &key)
+                .map_err(storage_error)?
+                .ok_or_else(|| corrupted(format!("custody record {} vanished", key)))?;
+            let record = serde_json::from_slice(&bytes)
+                .map_err(|e| corrupted(format!("custody record {}: {}", key, e)))?;
+            records.entry(asset_id.to_string()).or_default().push((key, record));
+        }
+
+        let mut vault = Self::with_clock(config, clock);
+        for (asset_id, records) in records {
+            let asset = Self::replay(&asset_id, records)?;
+            vault.assets.insert(AssetId::from_identity(asset.id()), asset);
         }
+        vault.store = Some(store);
+
+        Ok(vault)
+    }
+
+    /// Rebuild one asset from its records (sorted by key)
+    fn replay(asset_id: &str, records: Vec<(String, CustodyRecord)>) -> Result<Asset, ProvenanceError> {
+        let mut asset: Option<Asset> = None;
+
+        for (sequence, (key, record)) in records.into_iter().enumerate() {
+            if key != Self::record_key(asset_id, sequence) {
+                return Err(corrupted(format!("asset {} expected event {} but found {}", asset_id, sequence, key)));
+            }
+
+            match (asset.as_mut(), record.asset, &record.event.event_type) {
+                (
+                    None,
+                    Some(genesis),
+                    CustodyEventType::Genesis { custodian, label, metadata_hash },
+                ) => {
+                    let identity = genesis.identity.into_identity().map_err(|error| match error {
+                        RestoreError::Buried(violation) => ProvenanceError::OntologicalViolation(violation.to_string()),
+                        RestoreError::Invalid(reason) => corrupted(reason),
+                    })?;
+                    if identity.id() != asset_id {
+                        return Err(corrupted(format!("asset {} stored under {}", identity.id(), asset_id)));
+                    }
+                    let mut restored = Asset::restore(
+                        identity,
+                        label.clone(),
+                        metadata_hash.clone(),
+                        genesis.created_at,
+                        custodian.clone(),
+                    );
+                    restored.push_event(record.event);
+                    asset = Some(restored);
+                }
+                (Some(current), None, event_type) if !current.is_sealed() => {
+                    match event_type {
+                        CustodyEventType::Transfer { to, .. } => current.set_custodian(to.clone()),
+                        CustodyEventType::Seal { reason } => current.seal(reason.clone()),
+                        CustodyEventType::Inspection { .. } => {}
+                        CustodyEventType::Genesis { .. } => {
+                            return Err(corrupted(format!("asset {} has a second genesis", asset_id)));
+                        }
+                    }
+                    current.push_event(record.event);
+                }
+                _ => return Err(corrupted(format!("asset {} has an invalid record {}", asset_id, key))),
+            }
+        }
+
+        let asset = asset.ok_or_else(|| corrupted(format!("asset {} has no records", asset_id)))?;
+        let report = verify_chain(&asset);
+        if !report.is_valid() {
+            return Err(corrupted(format!("asset {}: {}", asset_id, report.errors.join("; "))));
+        }
+
+        Ok(asset)
+    }
+
+    fn record_key(asset_id: &str, sequence: usize) -> String {
+        format!("{}-{:08}", asset_id, sequence)
+    }
+
+    /// Write a custody event before it joins the in-memory chain
+    fn persist(&self, asset: &Asset, genesis: bool, event: &CustodyEvent) -> Result<(), ProvenanceError> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+
+        let record = CustodyRecord {
+            asset: genesis.then(|| AssetRecord {
+                identity: IdentityArchive::from_identity(asset.id()),
+                created_at: asset.created_at(),
+            }),
+            event: event.clone(),
+        };
+        let bytes = serde_json::to_vec(&record)
+            .map_err(|e| ProvenanceError::StorageError(e.to_string()))?;
+        let key = Self::record_key(asset.id().id(), asset.events().len());
+
+        store
+            .append(CUSTODY_NAMESPACE, &key, &bytes)
+            .map_err(storage_error)
     }
 
     pub fn create_asset(
@@ -51,7 +208,7 @@ impl ProvenanceVault {
         self.consume_energy("Create asset", self.config.operation_cost)?;
 
         let custodian = CustodianId::new(custodian);
-        let mut asset = Asset::new(label, metadata_hash.clone(), custodian.clone());
+        let mut asset = Asset::with_clock(label, metadata_hash.clone(), custodian.clone(), self.clock.as_ref());
         let asset_id = AssetId::from_identity(asset.id());
 
         let event = self.build_event(None, CustodyEventType::Genesis {
@@ -59,6 +216,7 @@ impl ProvenanceVault {
             label: asset.label().to_string(),
             metadata_hash,
         });
+        self.persist(&asset, true, &event)?;
         asset.push_event(event);
 
         self.assets.insert(asset_id.clone(), asset);
@@ -103,6 +261,7 @@ impl ProvenanceVault {
             from: from_id,
             to: to_id.clone(),
         });
+        self.persist_to(asset_id, &event)?;
         let asset = self
             .assets
             .get_mut(asset_id)
@@ -135,6 +294,7 @@ impl ProvenanceVault {
         self.consume_energy("Record custody event", self.config.operation_cost)?;
 
         let event = self.build_event(prev_hash, event_type);
+        self.persist_to(asset_id, &event)?;
         let asset = self
             .assets
             .get_mut(asset_id)
@@ -162,6 +322,7 @@ impl ProvenanceVault {
         self.consume_energy("Seal asset", self.config.operation_cost)?;
 
         let event = self.build_event(prev_hash, CustodyEventType::Seal { reason: reason.clone() });
+        self.persist_to(asset_id, &event)?;
         let asset = self
             .assets
             .get_mut(asset_id)
@@ -183,6 +344,14 @@ impl ProvenanceVault {
         Ok(verify_chain(asset))
     }
 
+    fn persist_to(&self, asset_id: &AssetId, event: &CustodyEvent) -> Result<(), ProvenanceError> {
+        let asset = self
+            .assets
+            .get(asset_id)
+            .ok_or(ProvenanceError::AssetNotFound)?;
+        self.persist(asset, false, event)
+    }
+
     fn consume_energy(&mut self, description: &str, cost: u64) -> Result<(), ProvenanceError> {
         match self.lineage.perform_operation(description.to_string(), cost) {
             OperationResult::Success { .. } => Ok(()),
@@ -201,7 +370,7 @@ impl ProvenanceVault {
         prev_hash: Option<String>,
         event_type: CustodyEventType,
     ) -> CustodyEvent {
-        let timestamp = Utc::now();
+        let timestamp = self.clock.now();
         let mut hasher = Sha256::new();
         if let Some(prev) = &prev_hash {
             hasher.update(prev.as_bytes());
@@ -227,6 +396,15 @@ pub enum ProvenanceError {
     InsufficientEnergy { required: u64, available: u64 },
     VaultTerminated,
     OntologicalViolation(String),
+    StorageError(String),
+}
+
+fn storage_error(error: StoreError) -> ProvenanceError {
+    ProvenanceError::StorageError(error.to_string())
+}
+
+fn corrupted(reason: impl Into<String>) -> ProvenanceError {
+    storage_error(StoreError::Corrupted(reason.into()))
 }
 
 impl std::fmt::Display for ProvenanceError {
@@ -246,6 +424,7 @@ impl std::fmt::Display for ProvenanceError {
             ),
             ProvenanceError::VaultTerminated => write!(f, "Provenance vault is terminated"),
             ProvenanceError::OntologicalViolation(reason) => write!(f, "{}", reason),
+            ProvenanceError::StorageError(reason) => write!(f, "Vault storage failed: {}", reason),
         }
     }
 }
@@ -4,6 +4,8 @@
 //! - Permanent recording of all errors and failures
 //! - Scars remain visible forever
 //! - Accumulation of scars over time
+//! - Scars are classified (category, code, key/value details) so they can be
+//!   aggregated across finance, trust and governance
 //!
 //! ## What This Forbids
 //! - Scar removal or healing
@@ -12,11 +14,23 @@
 //!
 //! ## Violations
 //! - Attempting to remove scars is an ontological violation
+//! - Weighting a category below 1.0 would hide its scars and is refused
+//!
+//! ## Damage Models
+//! How much history bites is pluggable ([`DamageModel`]). The default is the
+//! fixed [`SeverityTable`]; [`RecencyWeighted`] and [`Compounding`] are
+//! alternatives. Models only change the score: scars themselves stay. A
+//! new model is refused if it would price the existing history lower.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use crate::clock::{self, SharedClock};
 
 /// Severity level of a scar.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ScarSeverity {
     /// Minor issue - functionality preserved
     Minor,
@@ -28,13 +42,37 @@ pub enum ScarSeverity {
     Fatal,
 }
 
+/// What kind of damage a scar records.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ScarCategory {
+    /// Unclassified (every scar recorded before categories existed)
+    #[default]
+    General,
+    /// Connectivity, timeouts, unreachable peers
+    Network,
+    /// Corrupted data, broken hashes, tampering
+    Integrity,
+    /// Trust violations and revoked capabilities
+    Trust,
+    /// Losses, drawdowns, failed trades
+    Financial,
+    /// Dissent penalties and governance failures
+    Governance,
+}
+
+impl ScarCategory {
+    pub(crate) fn is_general(&self) -> bool {
+        *self == ScarCategory::General
+    }
+}
+
 /// A permanent scar from an error or failure.
 /// 
 /// Once created, a scar exists forever and cannot be removed or healed.
 /// Scars accumulate over the lifetime of a lineage.
 /// 
 /// **INVARIANT**: Scars are immutable after creation.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scar {
     /// When this scar was inflicted
     timestamp: DateTime<Utc>,
@@ -44,6 +82,15 @@ pub struct Scar {
     description: String,
     /// Context or stack trace
     context: Option<String>,
+    /// Kind of damage
+    #[serde(default, skip_serializing_if = "ScarCategory::is_general")]
+    category: ScarCategory,
+    /// Machine-readable code (e.g. `NET_TIMEOUT`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    /// Structured key/value context
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    details: BTreeMap<String, String>,
 }
 
 impl Scar {
@@ -51,14 +98,45 @@ impl Scar {
     /// 
     /// **CONSEQUENCE**: This scar is now permanent.
     pub fn new(severity: ScarSeverity, description: String, context: Option<String>) -> Self {
+        Self::new_at(Utc::now(), severity, description, context)
+    }
+
+    /// Creates a new scar inflicted at `timestamp`.
+    pub fn new_at(
+        timestamp: DateTime<Utc>,
+        severity: ScarSeverity,
+        description: String,
+        context: Option<String>,
+    ) -> Self {
         Scar {
-            timestamp: Utc::now(),
+            timestamp,
             severity,
             description,
             context,
+            category: ScarCategory::General,
+            code: None,
+            details: BTreeMap::new(),
         }
     }
 
+    /// Classifies this scar under `category`. Consumes self.
+    pub fn with_category(mut self, category: ScarCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Attaches a machine-readable code. Consumes self.
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Attaches one key/value detail. Consumes self.
+    pub fn with_detail(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.insert(key.into(), value.into());
+        self
+    }
+
     /// Returns when this scar was inflicted.
     pub fn timestamp(&self) -> DateTime<Utc> {
         self.timestamp
@@ -78,6 +156,151 @@ impl Scar {
     pub fn context(&self) -> Option<&str> {
         self.context.as_deref()
     }
+
+    /// Returns the category of this scar.
+    pub fn category(&self) -> ScarCategory {
+        self.category
+    }
+
+    /// Returns the machine-readable code, if any.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// Returns all key/value details.
+    pub fn details(&self) -> &BTreeMap<String, String> {
+        &self.details
+    }
+
+    /// Returns one detail by key.
+    pub fn detail(&self, key: &str) -> Option<&str> {
+        self.details.get(key).map(String::as_str)
+    }
+}
+
+/// How much damage a scar contributes to a lineage's damage score.
+/// 
+/// Models only price scars; they cannot remove or hide them.
+pub trait DamageModel: Send + Sync + std::fmt::Debug {
+    /// Damage from `scar`, the `index`-th scar ever inflicted (0-based), as of `now`.
+    fn scar_damage(&self, scar: &Scar, index: usize, now: DateTime<Utc>) -> f64;
+}
+
+/// Fixed damage per severity (the default: 1/5/20/100).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeverityTable {
+    pub minor: u32,
+    pub moderate: u32,
+    pub severe: u32,
+    pub fatal: u32,
+}
+
+impl SeverityTable {
+    /// Damage points for a severity.
+    pub fn points(&self, severity: ScarSeverity) -> u32 {
+        match severity {
+            ScarSeverity::Minor => self.minor,
+            ScarSeverity::Moderate => self.moderate,
+            ScarSeverity::Severe => self.severe,
+            ScarSeverity::Fatal => self.fatal,
+        }
+    }
+}
+
+impl Default for SeverityTable {
+    fn default() -> Self {
+        SeverityTable {
+            minor: 1,
+            moderate: 5,
+            severe: 20,
+            fatal: 100,
+        }
+    }
+}
+
+impl DamageModel for SeverityTable {
+    fn scar_damage(&self, scar: &Scar, _index: usize, _now: DateTime<Utc>) -> f64 {
+        self.points(scar.severity()) as f64
+    }
+}
+
+/// Recent scars bite hardest; older ones fade towards a floor.
+/// 
+/// **INVARIANT**: A scar never fades below `floor` of its table damage, so
+/// history always counts.
+#[derive(Debug, Clone, Copy)]
+pub struct RecencyWeighted {
+    table: SeverityTable,
+    half_life: Duration,
+    floor: f64,
+}
+
+impl RecencyWeighted {
+    /// Lowest floor accepted: an old scar keeps at least a tenth of its damage.
+    pub const MIN_FLOOR: f64 = 0.1;
+
+    /// Halves a scar's damage every `half_life`, down to `floor` (clamped to `[0.1, 1.0]`).
+    pub fn new(half_life: Duration, floor: f64) -> Self {
+        let floor = if floor.is_finite() { floor.clamp(Self::MIN_FLOOR, 1.0) } else { 1.0 };
+        RecencyWeighted {
+            table: SeverityTable::default(),
+            half_life,
+            floor,
+        }
+    }
+
+    /// Uses `table` instead of the default severity table.
+    pub fn with_table(mut self, table: SeverityTable) -> Self {
+        self.table = table;
+        self
+    }
+}
+
+impl DamageModel for RecencyWeighted {
+    fn scar_damage(&self, scar: &Scar, _index: usize, now: DateTime<Utc>) -> f64 {
+        let base = self.table.points(scar.severity()) as f64;
+        let half_life = self.half_life.num_milliseconds();
+        if half_life <= 0 {
+            return base;
+        }
+
+        let age = (now - scar.timestamp()).num_milliseconds().max(0) as f64;
+        let decay = 0.5f64.powf(age / half_life as f64);
+        base * decay.max(self.floor)
+    }
+}
+
+/// Every scar bites harder than the one before it.
+/// 
+/// The `n`-th scar (0-based) scores its table damage times `(1 + rate)^n`.
+#[derive(Debug, Clone, Copy)]
+pub struct Compounding {
+    table: SeverityTable,
+    rate: f64,
+}
+
+impl Compounding {
+    /// Compounds by `rate` per prior scar (negative rates are treated as 0).
+    pub fn new(rate: f64) -> Self {
+        let rate = if rate.is_finite() { rate.max(0.0) } else { 0.0 };
+        Compounding {
+            table: SeverityTable::default(),
+            rate,
+        }
+    }
+
+    /// Uses `table` instead of the default severity table.
+    pub fn with_table(mut self, table: SeverityTable) -> Self {
+        self.table = table;
+        self
+    }
+}
+
+impl DamageModel for Compounding {
+    fn scar_damage(&self, scar: &Scar, index: usize, _now: DateTime<Utc>) -> f64 {
+        let exponent = index.min(i32::MAX as usize) as i32;
+        self.table.points(scar.severity()) as f64 * (1.0 + self.rate).powi(exponent)
+    }
 }
 
 /// Permanent scar tissue accumulated over a lineage's lifetime.
@@ -86,26 +309,68 @@ impl Scar {
 /// Scars cannot be removed, hidden, or healed.
 /// 
 /// **INVARIANT**: Scar count can only increase, never decrease.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
 pub struct ScarTissue {
     /// All scars, in chronological order
     scars: Vec<Scar>,
+    /// Source of scar timestamps
+    #[serde(skip)]
+    clock: SharedClock,
+    /// Damage multiplier per category (absent = 1.0)
+    #[serde(skip)]
+    weights: BTreeMap<ScarCategory, f64>,
+    /// Prices scars into a damage score
+    #[serde(skip)]
+    model: Arc<dyn DamageModel>,
 }
 
 impl ScarTissue {
     /// Creates new, unmarked scar tissue.
     pub fn new() -> Self {
-        ScarTissue { scars: Vec::new() }
+        Self::with_clock(clock::system())
+    }
+
+    /// Creates new, unmarked scar tissue timestamped by `clock`.
+    pub fn with_clock(clock: SharedClock) -> Self {
+        ScarTissue {
+            scars: Vec::new(),
+            clock,
+            weights: BTreeMap::new(),
+            model: Arc::new(SeverityTable::default()),
+        }
     }
 
     /// Inflicts a new scar.
     /// 
     /// **CONSEQUENCE**: This scar is permanent and visible forever.
     pub fn inflict(&mut self, severity: ScarSeverity, description: String, context: Option<String>) {
-        let scar = Scar::new(severity, description, context);
+        let scar = Scar::new_at(self.clock.now(), severity, description, context);
+        self.scars.push(scar);
+    }
+
+    /// Inflicts a classified scar, stamped with this tissue's clock.
+    /// 
+    /// **CONSEQUENCE**: This scar is permanent and visible forever.
+    pub fn inflict_scar(&mut self, mut scar: Scar) {
+        scar.timestamp = self.clock.now();
         self.scars.push(scar);
     }
 
+    /// Re-attaches a persisted scar.
+    /// 
+    /// **INVARIANT**: Scars must be restored in chronological order.
+    pub(crate) fn restore(&mut self, scar: Scar) -> Result<(), String> {
+        if let Some(latest) = self.scars.last()
+            && scar.timestamp() < latest.timestamp()
+        {
+            return Err("Scar corruption: restored scars are out of order".to_string());
+        }
+
+        self.scars.push(scar);
+        Ok(())
+    }
+
     /// Returns all scars in chronological order.
     pub fn all_scars(&self) -> &[Scar] {
         &self.scars
@@ -124,6 +389,30 @@ impl ScarTissue {
             .collect()
     }
 
+    /// Returns scars of a specific category.
+    pub fn scars_by_category(&self, category: ScarCategory) -> Vec<&Scar> {
+        self.scars
+            .iter()
+            .filter(|scar| scar.category() == category)
+            .collect()
+    }
+
+    /// Returns scars carrying a specific code.
+    pub fn scars_by_code(&self, code: &str) -> Vec<&Scar> {
+        self.scars
+            .iter()
+            .filter(|scar| scar.code() == Some(code))
+            .collect()
+    }
+
+    /// Returns scars inflicted in `[from, until)`.
+    pub fn scars_between(&self, from: DateTime<Utc>, until: DateTime<Utc>) -> Vec<&Scar> {
+        self.scars
+            .iter()
+            .filter(|scar| scar.timestamp() >= from && scar.timestamp() < until)
+            .collect()
+    }
+
     /// Returns the most recent scar, if any.
     pub fn latest_scar(&self) -> Option<&Scar> {
         self.scars.last()
@@ -140,18 +429,79 @@ impl ScarTissue {
 
     /// Calculates a "damage score" based on accumulated scars.
     /// 
-    /// This is informational and does not affect behavior.
+    /// Each scar is priced by the damage model (1/5/20/100 by severity
+    /// unless changed) and multiplied by its category weight.
     pub fn damage_score(&self) -> u32 {
+        self.weighted_damage(|_| true)
+    }
+
+    /// Damage score of one category alone (weighted).
+    pub fn category_damage_score(&self, category: ScarCategory) -> u32 {
+        self.weighted_damage(|scar| scar.category() == category)
+    }
+
+    fn weighted_damage(&self, include: impl Fn(&Scar) -> bool) -> u32 {
+        let score = self.priced_damage(self.model.as_ref(), self.clock.now(), include);
+        score.round().min(u32::MAX as f64) as u32
+    }
+
+    /// Unrounded weighted damage of the included scars under `model`.
+    fn priced_damage(
+        &self,
+        model: &dyn DamageModel,
+        now: DateTime<Utc>,
+        include: impl Fn(&Scar) -> bool,
+    ) -> f64 {
         self.scars
             .iter()
-            .map(|scar| match scar.severity() {
-                ScarSeverity::Minor => 1,
-                ScarSeverity::Moderate => 5,
-                ScarSeverity::Severe => 20,
-                ScarSeverity::Fatal => 100,
-            })
+            .enumerate()
+            .filter(|(_, scar)| include(scar))
+            .map(|(index, scar)| model.scar_damage(scar, index, now) * self.category_weight(scar.category()))
             .sum()
     }
+
+    /// Replaces the damage model used to price scars.
+    /// 
+    /// **CONSEQUENCE**: Only the score changes; every scar remains.
+    /// 
+    /// **INVARIANT**: A model that would price the existing history lower
+    /// than the current one is refused. Swapping models can make scars
+    /// hurt more, never less.
+    pub fn set_damage_model(&mut self, model: Arc<dyn DamageModel>) -> Result<(), String> {
+        let now = self.clock.now();
+        let current = self.priced_damage(self.model.as_ref(), now, |_| true);
+        let proposed = self.priced_damage(model.as_ref(), now, |_| true);
+        if proposed < current {
+            return Err(format!(
+                "Damage model {:?} would lower the damage of existing scars ({:.2} < {:.2})",
+                model, proposed, current
+            ));
+        }
+
+        self.model = model;
+        Ok(())
+    }
+
+    /// Returns the damage model used to price scars.
+    pub fn damage_model(&self) -> &Arc<dyn DamageModel> {
+        &self.model
+    }
+
+    /// Weights a category's damage by `weight`.
+    /// 
+    /// **INVARIANT**: Weights never drop below 1.0 or below the weight
+    /// already set. A category can be made to hurt more, never less:
+    /// discounting it would hide its scars.
+    pub fn set_category_weight(&mut self, category: ScarCategory, weight: f64) {
+        let weight = if weight.is_finite() { weight } else { 1.0 };
+        let weight = weight.max(self.category_weight(category));
+        self.weights.insert(category, weight);
+    }
+
+    /// Returns the damage multiplier for a category (1.0 unless set).
+    pub fn category_weight(&self, category: ScarCategory) -> f64 {
+        self.weights.get(&category).copied().unwrap_or(1.0)
+    }
 }
 
 // EXPLICIT PREVENTION: No method to remove scars
@@ -373,4 +723,132 @@ mod tests {
         let fatal_scars = tissue.scars_by_severity(ScarSeverity::Fatal);
         assert_eq!(fatal_scars.len(), 0);
     }
+
+    #[test]
+    fn classified_scars_are_queryable() {
+        use crate::clock::ManualClock;
+        use std::sync::Arc;
+
+        let clock = Arc::new(ManualClock::at_epoch());
+        let mut tissue = ScarTissue::with_clock(clock.clone());
+
+        tissue.inflict(ScarSeverity::Minor, "Legacy".to_string(), None);
+        clock.advance(chrono::Duration::minutes(10));
+        tissue.inflict_scar(
+            Scar::new(ScarSeverity::Moderate, "Peer unreachable".to_string(), None)
+                .with_category(ScarCategory::Network)
+                .with_code("NET_TIMEOUT")
+                .with_detail("peer", "node-7"),
+        );
+        clock.advance(chrono::Duration::minutes(10));
+        tissue.inflict_scar(
+            Scar::new(ScarSeverity::Minor, "Peer unreachable".to_string(), None)
+                .with_category(ScarCategory::Network)
+                .with_code("NET_TIMEOUT"),
+        );
+
+        assert_eq!(tissue.scars_by_category(ScarCategory::General).len(), 1);
+        assert_eq!(tissue.scars_by_category(ScarCategory::Network).len(), 2);
+        assert_eq!(tissue.scars_by_code("NET_TIMEOUT").len(), 2);
+        assert!(tissue.scars_by_code("FIN_DRAWDOWN").is_empty());
+
+        let window = tissue.scars_between(
+            DateTime::UNIX_EPOCH + chrono::Duration::minutes(5),
+            DateTime::UNIX_EPOCH + chrono::Duration::minutes(20),
+        );
+        assert_eq!(window.len(), 1);
+        assert_eq!(window[0].detail("peer"), Some("node-7"));
+        assert_eq!(window[0].timestamp(), DateTime::UNIX_EPOCH + chrono::Duration::minutes(10));
+    }
+
+    #[test]
+    fn category_weights_only_increase_damage() {
+        let mut tissue = ScarTissue::new();
+        tissue.inflict(ScarSeverity::Moderate, "".to_string(), None); // 5
+        tissue.inflict_scar(
+            Scar::new(ScarSeverity::Severe, "".to_string(), None).with_category(ScarCategory::Trust),
+        ); // 20
+        assert_eq!(tissue.damage_score(), 25);
+
+        tissue.set_category_weight(ScarCategory::Trust, 2.5);
+        assert_eq!(tissue.damage_score(), 55);
+        assert_eq!(tissue.category_damage_score(ScarCategory::Trust), 50);
+
+        // Discounting a category would hide its scars
+        tissue.set_category_weight(ScarCategory::Trust, 0.0);
+        assert_eq!(tissue.category_weight(ScarCategory::Trust), 2.5);
+        tissue.set_category_weight(ScarCategory::Trust, 1.5);
+        assert_eq!(tissue.category_weight(ScarCategory::Trust), 2.5);
+        assert_eq!(tissue.damage_score(), 55);
+
+        tissue.set_category_weight(ScarCategory::Network, 0.0);
+        assert_eq!(tissue.category_weight(ScarCategory::Network), 1.0);
+    }
+
+    #[test]
+    fn legacy_scars_deserialize_as_general() {
+        let legacy = r#"[{"timestamp":"2024-01-01T00:00:00Z","severity":"Minor","description":"Old","context":null}]"#;
+        let scars: Vec<Scar> = serde_json::from_str(legacy).unwrap();
+        let scar = &scars[0];
+
+        assert_eq!(scar.category(), ScarCategory::General);
+        assert_eq!(scar.code(), None);
+        assert!(scar.details().is_empty());
+        assert_eq!(serde_json::to_string(&scars).unwrap(), legacy);
+    }
+
+    #[test]
+    fn damage_models_price_the_same_history_differently() {
+        use crate::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::at_epoch());
+        let mut tissue = ScarTissue::with_clock(clock.clone());
+        tissue.set_damage_model(Arc::new(RecencyWeighted::new(Duration::hours(1), 0.1))).unwrap();
+        tissue.inflict(ScarSeverity::Severe, "Old".to_string(), None); // 20
+        clock.advance(Duration::hours(2));
+        tissue.inflict(ScarSeverity::Moderate, "Recent".to_string(), None); // 5
+
+        // Old scar has lived two half-lives: 20 * 0.25 + 5
+        assert_eq!(tissue.damage_score(), 10);
+
+        // Far in the future the old scars still count at the floor
+        clock.advance(Duration::days(365));
+        assert_eq!(tissue.damage_score(), 3);
+
+        // Second scar bites 50% harder: 20 + 5 * 1.5
+        tissue.set_damage_model(Arc::new(Compounding::new(0.5))).unwrap();
+        assert_eq!(tissue.damage_score(), 28);
+        assert_eq!(tissue.scar_count(), 2);
+    }
+
+    #[test]
+    fn damage_model_cannot_be_swapped_to_lower_damage() {
+        use crate::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::at_epoch());
+        let mut tissue = ScarTissue::with_clock(clock.clone());
+        tissue.inflict(ScarSeverity::Severe, "Old".to_string(), None);
+        clock.advance(Duration::hours(2));
+        tissue.inflict(ScarSeverity::Moderate, "Recent".to_string(), None);
+        assert_eq!(tissue.damage_score(), 25);
+
+        // Fading the old scar would hide it
+        assert!(tissue.set_damage_model(Arc::new(RecencyWeighted::new(Duration::hours(1), 0.1))).is_err());
+        assert_eq!(tissue.damage_score(), 25);
+
+        // Compounding, then back to the plain table, is refused too
+        tissue.set_damage_model(Arc::new(Compounding::new(0.5))).unwrap();
+        assert!(tissue.set_damage_model(Arc::new(SeverityTable::default())).is_err());
+        assert_eq!(tissue.damage_score(), 28);
+        assert!(format!("{:?}", tissue.damage_model()).starts_with("Compounding"));
+    }
+
+    #[test]
+    fn recency_floor_cannot_erase_scars() {
+        let model = RecencyWeighted::new(Duration::seconds(1), 0.0);
+        let scar = Scar::new_at(DateTime::UNIX_EPOCH, ScarSeverity::Fatal, "".to_string(), None);
+        let much_later = DateTime::UNIX_EPOCH + Duration::days(10_000);
+
+        assert_eq!(model.scar_damage(&scar, 0, much_later), 100.0 * RecencyWeighted::MIN_FLOOR);
+    }
 }
@@ -0,0 +1,137 @@
+//! Filesystem store: one read-only file per record.
+//!
+//! ## Layout
+//! Each record is `<root>/<key>.<namespace>`, so a graveyard's tombstones
+//! stay at `<root>/<ID>.tomb` as they always have.
+//!
+//! ## Atomicity
+//! A record is written to `<key>.<namespace>.tmp`, fsynced, renamed into
+//! place, marked read-only, and the directory is fsynced. Appends within a
+//! process are serialized so two writers cannot race past the existence check.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::store::{io_error, validate_name, LineageStore, StoreError};
+
+/// Directory-backed store
+#[derive(Debug)]
+pub struct FileStore {
+    root: PathBuf,
+    /// Serializes appends (existence check + rename)
+    write_lock: Mutex<()>,
+}
+
+impl FileStore {
+    /// Open (creating if needed) a store rooted at `root`
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        let root = root.into();
+        fs::create_dir_all(&root).map_err(io_error)?;
+
+        Ok(FileStore {
+            root,
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    fn record_path(&self, namespace: &str, key: &str) -> Result<PathBuf, StoreError> {
+        validate_name(namespace)?;
+        validate_name(key)?;
+        Ok(self.root.join(format!("{}.{}", key, namespace)))
+    }
+
+    /// Flush the directory entry so a rename survives a crash
+    #[cfg(unix)]
+    fn sync_root(&self) -> Result<(), StoreError> {
+        File::open(&self.root)
+            .and_then(|dir| dir.sync_all())
+            .map_err(io_error)
+    }
+
+    /// Directories cannot be opened for fsync here; the rename is already durable
+    #[cfg(not(unix))]
+    fn sync_root(&self) -> Result<(), StoreError> {
+        Ok(())
+    }
+
+    /// Mark a file as read-only at OS level (Unix)
+    #[cfg(unix)]
+    fn make_readonly(path: &Path) -> Result<(), StoreError> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let perms = fs::Permissions::from_mode(0o444); // r--r--r--
+        fs::set_permissions(path, perms).map_err(io_error)
+    }
+
+    /// Mark a file as read-only at OS level
+    #[cfg(not(unix))]
+    fn make_readonly(path: &Path) -> Result<(), StoreError> {
+        let mut perms = fs::metadata(path).map_err(io_error)?.permissions();
+        perms.set_readonly(true);
+        fs::set_permissions(path, perms).map_err(io_error)
+    }
+}
+
+impl LineageStore for FileStore {
+    fn location(&self) -> &Path {
+        &self.root
+    }
+
+    fn append(&self, namespace: &str, key: &str, bytes: &[u8]) -> Result<(), StoreError> {
+        let final_path = self.record_path(namespace, key)?;
+        let _guard = self
+            .write_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if final_path.exists() {
+            return Err(StoreError::RecordExists {
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+            });
+        }
+
+        let temp_path = self.root.join(format!("{}.{}.tmp", key, namespace));
+        let mut temp = File::create(&temp_path).map_err(io_error)?;
+        temp.write_all(bytes).map_err(io_error)?;
+        temp.sync_all().map_err(io_error)?;
+        drop(temp);
+
+        fs::rename(&temp_path, &final_path).map_err(io_error)?;
+        Self::make_readonly(&final_path)?;
+        self.sync_root()
+    }
+
+    fn read(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        match fs::read(self.record_path(namespace, key)?) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error(e)),
+        }
+    }
+
+    fn keys(&self, namespace: &str) -> Result<Vec<String>, StoreError> {
+        validate_name(namespace)?;
+        let mut keys = Vec::new();
+
+        for entry in fs::read_dir(&self.root).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+
+            if path.extension().is_some_and(|ext| ext == namespace)
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+                && validate_name(stem).is_ok()
+            {
+                keys.push(stem.to_string());
+            }
+        }
+
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn contains(&self, namespace: &str, key: &str) -> Result<bool, StoreError> {
+        Ok(self.record_path(namespace, key)?.exists())
+    }
+}
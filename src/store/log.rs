@@ -0,0 +1,443 @@
+//! Embedded store: a single append-only log file with an in-memory index.
+//!
+//! ## Format
+//! `LINSTORE` magic, format version (u32 LE), then records of
+//! `namespace length (u16 LE) || key length (u16 LE) || data length (u64 LE)
+//! || namespace || key || data || SHA-256 of everything before it`.
+//!
+//! ## Recovery
+//! Opening scans the whole log and rebuilds the index. A record that claims
+//! to run past the end of the log is an append that never completed: it is
+//! truncated away. A bad record anywhere else, including a complete final
+//! record failing its checksum, is corruption and the log refuses to open.
+//!
+//! While a handle is open it holds an OS advisory lock on `<log>.lock`,
+//! which blocks other opens. The OS releases the lock when the holder exits,
+//! so a crashed process never strands the log.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use sha2::{Digest, Sha256};
+
+use crate::store::{io_error, validate_name, LineageStore, StoreError};
+
+/// Magic bytes opening every log
+pub const LOG_MAGIC: &[u8; 8] = b"LINSTORE";
+/// Current log format version
+pub const LOG_FORMAT_VERSION: u32 = 1;
+
+const HEADER_LEN: u64 = 12;
+const RECORD_PREFIX_LEN: usize = 12;
+const CHECKSUM_LEN: usize = 32;
+
+/// Where one record's data sits in the log
+#[derive(Debug, Clone, Copy)]
+struct Extent {
+    offset: u64,
+    len: u64,
+}
+
+#[derive(Debug)]
+struct LogState {
+    file: File,
+    /// (namespace, key) -> data extent
+    index: BTreeMap<(String, String), Extent>,
+    /// Offset of the next append
+    end: u64,
+}
+
+/// Single-file, append-only store
+#[derive(Debug)]
+pub struct LogStore {
+    path: PathBuf,
+    /// Held for the handle's lifetime; the OS lock drops with it
+    _lock: File,
+    state: Mutex<LogState>,
+}
+
+/// Outcome of scanning one record
+enum Scan {
+    Record {
+        namespace: String,
+        key: String,
+        data: Extent,
+        next: usize,
+    },
+    /// Not enough bytes left, or the checksum failed
+    Broken(String),
+}
+
+fn scan_record(bytes: &[u8], at: usize) -> Scan {
+    let rest = &bytes[at..];
+    let Some((prefix, body)) = rest.split_first_chunk::<RECORD_PREFIX_LEN>() else {
+        return Scan::Broken("truncated record header".to_string());
+    };
+
+    let ns_len = u16::from_le_bytes([prefix[0], prefix[1]]) as usize;
+    let key_len = u16::from_le_bytes([prefix[2], prefix[3]]) as usize;
+    let mut data_len = [0u8; 8];
+    data_len.copy_from_slice(&prefix[4..]);
+    let Ok(data_len) = usize::try_from(u64::from_le_bytes(data_len)) else {
+        return Scan::Broken("record too large".to_string());
+    };
+
+    let Some(payload_len) = ns_len
+        .checked_add(key_len)
+        .and_then(|len| len.checked_add(data_len))
+    else {
+        return Scan::Broken("record too large".to_string());
+    };
+    if body.len() < payload_len + CHECKSUM_LEN {
+        return Scan::Broken("truncated record".to_string());
+    }
+
+    let record_len = RECORD_PREFIX_LEN + payload_len;
+    if Sha256::digest(&rest[..record_len]).as_slice() != &rest[record_len..record_len + CHECKSUM_LEN] {
+        return Scan::Broken("record checksum mismatch".to_string());
+    }
+
+    let (namespace, body) = body.split_at(ns_len);
+    let key = &body[..key_len];
+    match (std::str::from_utf8(namespace), std::str::from_utf8(key)) {
+        (Ok(namespace), Ok(key)) => Scan::Record {
+            namespace: namespace.to_string(),
+            key: key.to_string(),
+            data: Extent {
+                offset: (at + RECORD_PREFIX_LEN + ns_len + key_len) as u64,
+                len: data_len as u64,
+            },
+            next: at + record_len + CHECKSUM_LEN,
+        },
+        _ => Scan::Broken("record name is not UTF-8".to_string()),
+    }
+}
+
+impl LogStore {
+    /// Open (creating if needed) the log at `path`
+    ///
+    /// Fails if another handle holds the log, or if a record before
+    /// the tail is corrupted.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        let lock = Self::acquire_lock(&path)?;
+        let state = Self::load(&path)?;
+
+        Ok(LogStore {
+            path,
+            _lock: lock,
+            state: Mutex::new(state),
+        })
+    }
+
+    fn acquire_lock(path: &Path) -> Result<File, StoreError> {
+        let mut lock = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(Self::lock_path(path))
+            .map_err(io_error)?;
+
+        match lock.try_lock() {
+            Ok(()) => {
+                // Holder PID, for operators only; the lock itself is the OS lock
+                let _ = lock.set_len(0).and_then(|_| writeln!(lock, "{}", std::process::id()));
+                Ok(lock)
+            }
+            Err(TryLockError::WouldBlock) => Err(StoreError::IoError(format!(
+                "log {} is locked by another handle",
+                path.display()
+            ))),
+            Err(TryLockError::Error(e)) => Err(io_error(e)),
+        }
+    }
+
+    /// Returns the lock file path for a log
+    pub fn lock_path(path: &Path) -> PathBuf {
+        let mut lock = path.as_os_str().to_owned();
+        lock.push(".lock");
+        PathBuf::from(lock)
+    }
+
+    fn load(path: &Path) -> Result<LogState, StoreError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(io_error)?;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes).map_err(io_error)?;
+
+        if bytes.is_empty() {
+            file.write_all(LOG_MAGIC).map_err(io_error)?;
+            file.write_all(&LOG_FORMAT_VERSION.to_le_bytes()).map_err(io_error)?;
+            file.sync_all().map_err(io_error)?;
+            return Ok(LogState {
+                file,
+                index: BTreeMap::new(),
+                end: HEADER_LEN,
+            });
+        }
+
+        let version = bytes
+            .strip_prefix(LOG_MAGIC.as_slice())
+            .and_then(|rest| rest.first_chunk::<4>())
+            .ok_or_else(|| StoreError::Corrupted("missing log header".to_string()))?;
+        if u32::from_le_bytes(*version) != LOG_FORMAT_VERSION {
+            return Err(StoreError::Corrupted(format!(
+                "unsupported log format version {}",
+                u32::from_le_bytes(*version)
+            )));
+        }
+
+        let mut index = BTreeMap::new();
+        let mut at = HEADER_LEN as usize;
+
+        while at < bytes.len() {
+            match scan_record(&bytes, at) {
+                Scan::Record { namespace, key, data, next } => {
+                    if index.insert((namespace, key), data).is_some() {
+                        return Err(StoreError::Corrupted(format!(
+                            "duplicate record at offset {}",
+                            at
+                        )));
+                    }
+                    at = next;
+                }
+                Scan::Broken(reason) => {
+                    if Self::is_tail(&bytes, at) {
+                        // An append that never completed: drop it
+                        file.set_len(at as u64).map_err(io_error)?;
+                        file.sync_all().map_err(io_error)?;
+                        break;
+                    }
+                    return Err(StoreError::Corrupted(format!("{} at offset {}", reason, at)));
+                }
+            }
+        }
+
+        Ok(LogState {
+            file,
+            index,
+            end: at as u64,
+        })
+    }
+
+    /// True if the broken record at `at` claims to run past the end of the log
+    ///
+    /// A record that fits in the log but fails its checks was written in
+    /// full and then damaged, so it is not a tail.
+    fn is_tail(bytes: &[u8], at: usize) -> bool {
+        let rest = &bytes[at..];
+        let Some(prefix) = rest.first_chunk::<RECORD_PREFIX_LEN>() else {
+            return true;
+        };
+        let ns_len = u16::from_le_bytes([prefix[0], prefix[1]]) as u64;
+        let key_len = u16::from_le_bytes([prefix[2], prefix[3]]) as u64;
+        let mut data_len = [0u8; 8];
+        data_len.copy_from_slice(&prefix[4..]);
+        let claimed = (RECORD_PREFIX_LEN + CHECKSUM_LEN) as u64 + ns_len + key_len;
+
+        claimed.saturating_add(u64::from_le_bytes(data_len)) > rest.len() as u64
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, LogState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl LineageStore for LogStore {
+    fn location(&self) -> &Path {
+        &self.path
+    }
+
+    fn append(&self, namespace: &str, key: &str, bytes: &[u8]) -> Result<(), StoreError> {
+        validate_name(namespace)?;
+        validate_name(key)?;
+        let (Ok(ns_len), Ok(key_len)) = (u16::try_from(namespace.len()), u16::try_from(key.len())) else {
+            return Err(StoreError::InvalidKey(key.to_string()));
+        };
+
+        let mut state = self.lock_state();
+        let id = (namespace.to_string(), key.to_string());
+        if state.index.contains_key(&id) {
+            return Err(StoreError::RecordExists { namespace: id.0, key: id.1 });
+        }
+
+        let mut record = Vec::with_capacity(
+            RECORD_PREFIX_LEN + namespace.len() + key.len() + bytes.len() + CHECKSUM_LEN,
+        );
+        record.extend_from_slice(&ns_len.to_le_bytes());
+        record.extend_from_slice(&key_len.to_le_bytes());
+        record.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        record.extend_from_slice(namespace.as_bytes());
+        record.extend_from_slice(key.as_bytes());
+        record.extend_from_slice(bytes);
+        let checksum = Sha256::digest(&record);
+        record.extend_from_slice(&checksum);
+
+        let start = state.end;
+        let written = state
+            .file
+            .seek(SeekFrom::Start(start))
+            .and_then(|_| state.file.write_all(&record))
+            .and_then(|_| state.file.sync_data());
+        if let Err(e) = written {
+            // Leave no partial record behind for the next append to follow
+            let _ = state.file.set_len(start);
+            return Err(io_error(e));
+        }
+
+        let data = Extent {
+            offset: start + (RECORD_PREFIX_LEN + namespace.len() + key.len()) as u64,
+            len: bytes.len() as u64,
+        };
+        state.index.insert(id, data);
+        state.end = start + record.len() as u64;
+        Ok(())
+    }
+
+    fn read(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        validate_name(namespace)?;
+        validate_name(key)?;
+
+        let mut state = self.lock_state();
+        let Some(extent) = state.index.get(&(namespace.to_string(), key.to_string())).copied()
+        else {
+            return Ok(None);
+        };
+
+        let mut data = vec![0u8; extent.len as usize];
+        state
+            .file
+            .seek(SeekFrom::Start(extent.offset))
+            .and_then(|_| state.file.read_exact(&mut data))
+            .map_err(io_error)?;
+        Ok(Some(data))
+    }
+
+    fn keys(&self, namespace: &str) -> Result<Vec<String>, StoreError> {
+        validate_name(namespace)?;
+
+        Ok(self
+            .lock_state()
+            .index
+            .keys()
+            .filter(|(ns, _)| ns == namespace)
+            .map(|(_, key)| key.clone())
+            .collect())
+    }
+
+    fn contains(&self, namespace: &str, key: &str) -> Result<bool, StoreError> {
+        validate_name(namespace)?;
+        validate_name(key)?;
+        Ok(self
+            .lock_state()
+            .index
+            .contains_key(&(namespace.to_string(), key.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_log() -> PathBuf {
+        std::env::temp_dir().join(format!("lineage-log-{}.log", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn torn_tail_is_dropped_on_open() {
+        let path = temp_log();
+        {
+            let store = LogStore::open(&path).unwrap();
+            store.append("tomb", "kept", b"complete").unwrap();
+            store.append("tomb", "torn", b"never finished").unwrap();
+        }
+
+        let len = fs::metadata(&path).unwrap().len();
+        OpenOptions::new().write(true).open(&path).unwrap().set_len(len - 5).unwrap();
+
+        let store = LogStore::open(&path).unwrap();
+        assert_eq!(store.keys("tomb").unwrap(), vec!["kept"]);
+        store.append("tomb", "torn", b"retried").unwrap();
+        drop(store);
+
+        let store = LogStore::open(&path).unwrap();
+        assert_eq!(store.read("tomb", "torn").unwrap(), Some(b"retried".to_vec()));
+        drop(store);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn corruption_before_the_tail_refuses_to_open() {
+        let path = temp_log();
+        {
+            let store = LogStore::open(&path).unwrap();
+            store.append("tomb", "first", b"original").unwrap();
+            store.append("tomb", "second", b"later").unwrap();
+        }
+
+        let mut bytes = fs::read(&path).unwrap();
+        let at = bytes.windows(8).position(|w| w == b"original").unwrap();
+        bytes[at] = b'O';
+        fs::write(&path, bytes).unwrap();
+
+        assert!(matches!(LogStore::open(&path), Err(StoreError::Corrupted(_))));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn corrupted_final_record_refuses_to_open() {
+        let path = temp_log();
+        {
+            let store = LogStore::open(&path).unwrap();
+            store.append("tomb", "first", b"original").unwrap();
+            store.append("tomb", "last", b"complete").unwrap();
+        }
+
+        let mut bytes = fs::read(&path).unwrap();
+        let at = bytes.windows(8).position(|w| w == b"complete").unwrap();
+        bytes[at] = b'C';
+        fs::write(&path, &bytes).unwrap();
+
+        assert!(matches!(LogStore::open(&path), Err(StoreError::Corrupted(_))));
+        assert_eq!(fs::read(&path).unwrap(), bytes);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn second_open_is_locked() {
+        let path = temp_log();
+        let store = LogStore::open(&path).unwrap();
+
+        assert!(matches!(LogStore::open(&path), Err(StoreError::IoError(_))));
+
+        drop(store);
+        assert!(LogStore::open(&path).is_ok());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn stale_lock_file_from_crashed_process_does_not_block_open() {
+        let path = temp_log();
+        fs::write(LogStore::lock_path(&path), "4242\n").unwrap();
+
+        let store = LogStore::open(&path).unwrap();
+        store.append("tomb", "after-crash", b"alive").unwrap();
+
+        drop(store);
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(LogStore::lock_path(&path));
+    }
+}
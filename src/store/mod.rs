@@ -0,0 +1,227 @@
+//! # Lineage Store
+//!
+//! One append-only storage layer for every durable record in the crate.
+//!
+//! ## What This Enforces
+//! - Records are written once under `(namespace, key)` and never change
+//! - A successful `append` is durable (data and directory entries are fsynced)
+//! - A record is either fully visible or absent; torn writes never surface
+//! - Graveyards, governance ledgers and provenance vaults share the same guarantees
+//!
+//! ## What This Forbids
+//! - Overwriting, updating or deleting a record
+//! - Keys or namespaces that could escape the store (paths, separators)
+//!
+//! ## Implementations
+//! - [`FileStore`] - one read-only file per record, `<root>/<key>.<namespace>`
+//! - [`LogStore`] - a single append-only log file with an in-memory index
+//!
+//! Several components may share one store; each uses its own namespace
+//! (`tomb`, `governance`, `custody`).
+
+use std::path::Path;
+
+mod file;
+mod log;
+
+pub use file::FileStore;
+pub use log::{LogStore, LOG_FORMAT_VERSION, LOG_MAGIC};
+
+/// Errors that can occur in a store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreError {
+    /// IO operation failed
+    IoError(String),
+    /// A record already exists under this key (no overwrites allowed)
+    RecordExists { namespace: String, key: String },
+    /// Namespace or key contains characters a store cannot hold
+    InvalidKey(String),
+    /// Stored bytes fail their integrity checks
+    Corrupted(String),
+}
+
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::IoError(msg) => write!(f, "Store IO error: {}", msg),
+            StoreError::RecordExists { namespace, key } => {
+                write!(f, "Record {}/{} already exists", namespace, key)
+            }
+            StoreError::InvalidKey(key) => write!(f, "Invalid store key: {:?}", key),
+            StoreError::Corrupted(msg) => write!(f, "Store is corrupted: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// Append-only record storage
+///
+/// Keys are listed in lexicographic order, so callers that need a
+/// sequence (ledgers, custody chains) use zero-padded sequence keys.
+pub trait LineageStore: Send + Sync + std::fmt::Debug {
+    /// Where the store lives (a directory or a log file)
+    fn location(&self) -> &Path;
+
+    /// Write a new record; fails with `RecordExists` if the key is taken
+    fn append(&self, namespace: &str, key: &str, bytes: &[u8]) -> Result<(), StoreError>;
+
+    /// Read a record, `None` if it was never written
+    fn read(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>, StoreError>;
+
+    /// Every key in a namespace, sorted
+    fn keys(&self, namespace: &str) -> Result<Vec<String>, StoreError>;
+
+    /// True if a record exists under this key
+    fn contains(&self, namespace: &str, key: &str) -> Result<bool, StoreError> {
+        Ok(self.read(namespace, key)?.is_some())
+    }
+}
+
+/// Check a namespace or key: non-empty ASCII alphanumerics, `-` and `_`
+pub(crate) fn validate_name(name: &str) -> Result<(), StoreError> {
+    let valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(StoreError::InvalidKey(name.to_string()))
+    }
+}
+
+pub(crate) fn io_error(e: std::io::Error) -> StoreError {
+    StoreError::IoError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn temp_path(kind: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("lineage-store-{}-{}", kind, uuid::Uuid::new_v4()))
+    }
+
+    fn stores() -> Vec<(PathBuf, Arc<dyn LineageStore>)> {
+        let dir = temp_path("file");
+        let log = temp_path("log");
+        vec![
+            (dir.clone(), Arc::new(FileStore::open(&dir).unwrap())),
+            (log.clone(), Arc::new(LogStore::open(&log).unwrap())),
+        ]
+    }
+
+    fn cleanup(path: &Path) {
+        if path.is_dir() {
+            let _ = std::fs::remove_dir_all(path);
+        } else {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn records_are_write_once() {
+        for (path, store) in stores() {
+            store.append("tomb", "agent-1", b"first").unwrap();
+
+            assert_eq!(
+                store.append("tomb", "agent-1", b"second"),
+                Err(StoreError::RecordExists {
+                    namespace: "tomb".to_string(),
+                    key: "agent-1".to_string(),
+                })
+            );
+            assert_eq!(store.read("tomb", "agent-1").unwrap(), Some(b"first".to_vec()));
+            assert_eq!(store.read("tomb", "agent-2").unwrap(), None);
+
+            cleanup(&path);
+        }
+    }
+
+    #[test]
+    fn namespaces_are_separate_and_keys_sorted() {
+        for (path, store) in stores() {
+            store.append("ledger", "0002", b"b").unwrap();
+            store.append("ledger", "0001", b"a").unwrap();
+            store.append("custody", "0001", b"c").unwrap();
+
+            assert_eq!(store.keys("ledger").unwrap(), vec!["0001", "0002"]);
+            assert_eq!(store.keys("custody").unwrap(), vec!["0001"]);
+            assert!(store.keys("tomb").unwrap().is_empty());
+
+            cleanup(&path);
+        }
+    }
+
+    #[test]
+    fn escaping_keys_are_rejected() {
+        for (path, store) in stores() {
+            for key in ["", "../escape", "a/b", "a.tomb"] {
+                assert!(matches!(
+                    store.append("tomb", key, b"x"),
+                    Err(StoreError::InvalidKey(_))
+                ));
+            }
+
+            cleanup(&path);
+        }
+    }
+
+    #[test]
+    fn ledger_and_vault_replay_from_a_shared_log() {
+        use crate::governance::{GovernanceConfig, GovernanceCouncil, GovernanceLedger, ProposalRisk};
+        use crate::provenance::{CustodyEventType, MetadataHash, ProvenanceConfig, ProvenanceVault};
+
+        let path = temp_path("log");
+        let store: Arc<dyn LineageStore> = Arc::new(LogStore::open(&path).unwrap());
+
+        let mut council = GovernanceCouncil::with_ledger(
+            GovernanceConfig::default(),
+            GovernanceLedger::with_store(Arc::clone(&store)).unwrap(),
+        );
+        council.try_add_member("Ada".to_string(), 100).unwrap();
+        council.try_propose("Rotate keys".to_string(), ProposalRisk::Low, 60).unwrap();
+
+        let mut vault = ProvenanceVault::with_store(ProvenanceConfig::default(), Arc::clone(&store)).unwrap();
+        let asset_id = vault
+            .create_asset("Sample".to_string(), MetadataHash::from_bytes(b"sample"), "lab".to_string())
+            .unwrap();
+        vault.transfer(&asset_id, "lab".to_string(), "courier".to_string(), 1).unwrap();
+        vault
+            .record_event(&asset_id, CustodyEventType::Inspection { notes: "intact".to_string() })
+            .unwrap();
+        vault.seal(&asset_id, "delivered".to_string()).unwrap();
+        drop((council, vault, store));
+
+        let store: Arc<dyn LineageStore> = Arc::new(LogStore::open(&path).unwrap());
+        let ledger = GovernanceLedger::with_store(Arc::clone(&store)).unwrap();
+        assert_eq!(ledger.events().len(), 2);
+
+        let vault = ProvenanceVault::with_store(ProvenanceConfig::default(), store).unwrap();
+        let asset = vault.asset(&asset_id).unwrap();
+        assert_eq!(asset.events().len(), 4);
+        assert_eq!(asset.current_custodian().as_str(), "courier");
+        assert!(asset.is_sealed());
+        assert!(vault.verify(&asset_id).unwrap().is_valid());
+
+        drop(vault);
+        cleanup(&path);
+    }
+
+    #[test]
+    fn reopened_store_keeps_its_records() {
+        let dir = temp_path("file");
+        FileStore::open(&dir).unwrap().append("tomb", "a", b"1").unwrap();
+        assert_eq!(FileStore::open(&dir).unwrap().read("tomb", "a").unwrap(), Some(b"1".to_vec()));
+        cleanup(&dir);
+
+        let log = temp_path("log");
+        LogStore::open(&log).unwrap().append("tomb", "a", b"1").unwrap();
+        assert_eq!(LogStore::open(&log).unwrap().read("tomb", "a").unwrap(), Some(b"1".to_vec()));
+        cleanup(&log);
+    }
+}
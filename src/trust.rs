@@ -11,15 +11,28 @@
 // - A set of capabilities that can only shrink
 // - Permanent consequence of violations
 // - Irreversible loss enforced by type system
+//
+// Delegation lends a subset of an actor's capabilities to another actor.
+// A lent capability is only as good as the delegator's own hold on it:
+// revoke the delegator and every delegate down the chain loses it too.
 
+use crate::anomaly::{AnomalyDetector, Observation};
+use crate::lazarus::LazarusViolation;
 use crate::lineage::{Lineage, OperationError};
-use crate::scar::ScarSeverity;
+use crate::policy::PolicySet;
+use crate::scar::{ScarCategory, ScarSeverity};
+use crate::violation::{self, OntologicalViolation};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
 
 /// Actions that privileged actors may perform.
 /// 
 /// Once a capability is revoked, it CANNOT be restored.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum TrustCapability {
     /// Execute privileged operations
     Execute,
@@ -57,7 +70,7 @@ impl TrustCapability {
 }
 
 /// Classification of trust violations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ViolationType {
     /// Unauthorized access attempt
     UnauthorizedAccess,
@@ -124,6 +137,25 @@ impl ViolationType {
             ViolationType::MaliciousAction => "Malicious action detected",
         }
     }
+    
+    /// Machine-readable scar code for this violation.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ViolationType::UnauthorizedAccess => "TRUST_UNAUTHORIZED_ACCESS",
+            ViolationType::IntegrityViolation => "TRUST_INTEGRITY_VIOLATION",
+            ViolationType::PolicyBypass => "TRUST_POLICY_BYPASS",
+            ViolationType::CredentialMisuse => "TRUST_CREDENTIAL_MISUSE",
+            ViolationType::MaliciousAction => "TRUST_MALICIOUS_ACTION",
+        }
+    }
+    
+    /// Scar category for this violation.
+    pub fn category(&self) -> ScarCategory {
+        match self {
+            ViolationType::IntegrityViolation => ScarCategory::Integrity,
+            _ => ScarCategory::Trust,
+        }
+    }
 }
 
 /// Trust profile tracking active capabilities.
@@ -150,6 +182,15 @@ impl TrustProfile {
         }
     }
     
+    /// Create trust profile holding no capabilities of its own.
+    /// 
+    /// Used by service accounts, which act only on delegated capabilities.
+    pub fn empty() -> Self {
+        TrustProfile {
+            active_capabilities: HashSet::new(),
+        }
+    }
+    
     /// Check if capability is currently active.
     pub fn has_capability(&self, capability: TrustCapability) -> bool {
         self.active_capabilities.contains(&capability)
@@ -193,6 +234,206 @@ pub enum TrustResult {
     
     /// Actor lineage is terminated (dead).
     Terminated,
+    
+    /// Actor's invariants broke; it is quarantined and will never act again.
+    Quarantined { cause: OntologicalViolation },
+}
+
+/// Signed record of capabilities lent from one actor to another.
+/// 
+/// Recorded in both lineages. The signature is made with the delegator's
+/// Ed25519 key, so either party (or an auditor) holding that key can verify
+/// the grant. The embedded `signer_public_key` is never trusted on its own:
+/// anyone can sign with a fresh key and claim any delegator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delegation {
+    /// Unique delegation ID
+    pub id: String,
+    /// Identity of the granting actor
+    pub delegator_id: String,
+    /// Identity of the receiving actor
+    pub delegate_id: String,
+    /// Capabilities lent (sorted)
+    pub capabilities: Vec<TrustCapability>,
+    /// Energy the delegator paid for the grant
+    pub energy_cost: u64,
+    /// When the grant was made
+    pub granted_at: DateTime<Utc>,
+    /// Delegator's Ed25519 public key (hex)
+    pub signer_public_key: String,
+    /// Ed25519 signature (hex) over the canonical delegation payload
+    pub signature: String,
+}
+
+impl Delegation {
+    /// Canonical payload covered by the signature
+    fn signing_payload(&self) -> String {
+        format!(
+            "lineage-delegation-v1|{}|{}|{}|{:?}|{}|{}",
+            self.id,
+            self.delegator_id,
+            self.delegate_id,
+            self.capabilities,
+            self.energy_cost,
+            self.granted_at.to_rfc3339(),
+        )
+    }
+
+    /// Verify the signature against `delegator_key`, the delegator's public
+    /// key (hex) as obtained from the delegator itself or another trusted
+    /// source (see `TrustedActor::delegation_key`).
+    /// 
+    /// Fails if the delegation claims a different signer key.
+    pub fn verify(&self, delegator_key: &str) -> bool {
+        if !self.signer_public_key.eq_ignore_ascii_case(delegator_key) {
+            return false;
+        }
+        let key: Option<[u8; 32]> = hex::decode(delegator_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok());
+        let signature: Option<[u8; 64]> = hex::decode(&self.signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok());
+
+        match (key.and_then(|k| VerifyingKey::from_bytes(&k).ok()), signature) {
+            (Some(key), Some(signature)) => key
+                .verify(self.signing_payload().as_bytes(), &Signature::from_bytes(&signature))
+                .is_ok(),
+            _ => false,
+        }
+    }
+}
+
+/// Errors that can occur when delegating capabilities
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DelegationError {
+    /// Delegator or delegate lineage is terminated
+    Terminated,
+    /// Delegator or delegate is quarantined after an ontological violation
+    Quarantined(OntologicalViolation),
+    /// Delegator lacks `TrustCapability::Delegate`
+    CannotDelegate,
+    /// No capabilities were requested
+    NothingToDelegate,
+    /// Delegator does not hold these capabilities (grants must be a subset)
+    NotHeld(Vec<TrustCapability>),
+    /// Delegate previously lost these capabilities (lost trust is never restored)
+    PreviouslyRevoked(Vec<TrustCapability>),
+    /// Delegate already lends authority to the delegator (chains cannot loop)
+    Cycle,
+    /// Delegator cannot pay for the grant
+    InsufficientEnergy { required: u64, available: u64 },
+}
+
+impl std::fmt::Display for DelegationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DelegationError::Terminated => write!(f, "Actor is terminated"),
+            DelegationError::Quarantined(cause) => write!(f, "Actor is quarantined: {}", cause),
+            DelegationError::CannotDelegate => write!(f, "Delegator lacks capability Delegate"),
+            DelegationError::NothingToDelegate => write!(f, "No capabilities to delegate"),
+            DelegationError::NotHeld(caps) => write!(f, "Delegator does not hold {:?}", caps),
+            DelegationError::PreviouslyRevoked(caps) => {
+                write!(f, "Delegate previously lost {:?}", caps)
+            }
+            DelegationError::Cycle => write!(f, "Delegation would create a cycle"),
+            DelegationError::InsufficientEnergy { required, available } => write!(
+                f,
+                "Insufficient energy: required {}, available {}",
+                required, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DelegationError {}
+
+/// Capabilities lent to an actor by a delegator
+#[derive(Debug)]
+struct Grant {
+    from: Arc<Authority>,
+    capabilities: HashSet<TrustCapability>,
+}
+
+/// What an actor may currently exercise, shared with its delegates.
+/// 
+/// `own` mirrors the actor's `TrustProfile`. Delegates hold an `Arc` to their
+/// delegator's authority, so revocations upstream are seen immediately.
+#[derive(Debug, Default)]
+struct Authority {
+    /// Capabilities held in the actor's own right
+    own: RwLock<HashSet<TrustCapability>>,
+    /// Capabilities lost to violations (never restored, not even by delegation)
+    revoked: RwLock<HashSet<TrustCapability>>,
+    /// Capabilities lent to the actor
+    received: RwLock<Vec<Grant>>,
+}
+
+impl Authority {
+    fn new(own: &HashSet<TrustCapability>) -> Self {
+        Authority {
+            own: RwLock::new(own.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// True if the capability is held in own right or through a live grant.
+    /// 
+    /// Terminates because delegation refuses to create cycles.
+    fn holds(&self, capability: TrustCapability) -> bool {
+        if read(&self.revoked).contains(&capability) {
+            return false;
+        }
+        read(&self.own).contains(&capability)
+            || read(&self.received)
+                .iter()
+                .any(|grant| grant.capabilities.contains(&capability) && grant.from.holds(capability))
+    }
+
+    /// True if the actor has lost the capability to a revocation.
+    fn is_revoked(&self, capability: TrustCapability) -> bool {
+        read(&self.revoked).contains(&capability)
+    }
+
+    /// True if this authority draws (directly or transitively) on `other`.
+    fn depends_on(&self, other: &Arc<Authority>) -> bool {
+        read(&self.received)
+            .iter()
+            .any(|grant| Arc::ptr_eq(&grant.from, other) || grant.from.depends_on(other))
+    }
+
+    /// Permanently removes capabilities, own and lent alike.
+    fn revoke(&self, capabilities: &[TrustCapability]) {
+        write(&self.revoked).extend(capabilities.iter().copied());
+        let mut own = write(&self.own);
+        for cap in capabilities {
+            own.remove(cap);
+        }
+    }
+
+    /// Drops everything: a dead actor holds and lends nothing.
+    fn retire(&self) {
+        let all: Vec<TrustCapability> = TrustCapability::full_set().into_iter().collect();
+        self.revoke(&all);
+        write(&self.received).clear();
+    }
+}
+
+/// Authorization for an attempt, taken before it runs
+#[derive(Debug, Clone, Copy)]
+struct Access {
+    /// Every required capability was held
+    authorized: bool,
+    /// A required capability had been revoked
+    revoked: bool,
+}
+
+fn read<T>(lock: &RwLock<T>) -> std::sync::RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn write<T>(lock: &RwLock<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 /// Trust-aware actor wrapping Lineage identity.
@@ -207,22 +448,86 @@ pub enum TrustResult {
 pub struct TrustedActor {
     lineage: Lineage,
     trust: TrustProfile,
+    /// Own and delegated capabilities, shared with delegates
+    authority: Arc<Authority>,
+    /// Key signing the delegations this actor grants
+    signing_key: SigningKey,
+    /// Delegations granted to others
+    granted: Vec<Delegation>,
+    /// Delegations received from others
+    received: Vec<Delegation>,
+    /// Set once an ontological violation is detected; never cleared
+    quarantine: Option<OntologicalViolation>,
+    /// Rules for `attempt_named_action`
+    policies: Arc<PolicySet>,
+    /// Watches attempts and raises violations on anomalies
+    detector: Option<AnomalyDetector>,
 }
 
 impl TrustedActor {
     /// Create new trusted actor with full capabilities.
+    ///
+    /// LAZARUS CHECK: Panics if the new identity is already buried
+    /// (see `try_create`).
     pub fn create(initial_energy: u64) -> Self {
-        TrustedActor {
-            lineage: Lineage::create(initial_energy),
-            trust: TrustProfile::new(),
+        Self::with_profile(initial_energy, TrustProfile::new())
+    }
+
+    /// Create new trusted actor, refusing identities the Lazarus guard knows are dead.
+    pub fn try_create(initial_energy: u64) -> Result<Self, LazarusViolation> {
+        Self::try_with_profile(initial_energy, TrustProfile::new())
+    }
+    
+    /// Create a service account: an actor with no capabilities of its own.
+    /// 
+    /// It can act only on capabilities delegated to it, and loses them the
+    /// moment its delegator does.
+    pub fn service_account(initial_energy: u64) -> Self {
+        Self::with_profile(initial_energy, TrustProfile::empty())
+    }
+    
+    fn with_profile(initial_energy: u64, trust: TrustProfile) -> Self {
+        match Self::try_with_profile(initial_energy, trust) {
+            Ok(actor) => actor,
+            Err(violation) => panic!("ONTOLOGICAL ERROR: {}", violation),
         }
     }
+
+    fn try_with_profile(
+        initial_energy: u64,
+        trust: TrustProfile,
+    ) -> Result<Self, LazarusViolation> {
+        // LAZARUS PREVENTION: the lineage passes the central guard
+        let lineage = Lineage::try_create(initial_energy)?;
+
+        Ok(TrustedActor {
+            lineage,
+            authority: Arc::new(Authority::new(trust.active_capabilities())),
+            trust,
+            signing_key: SigningKey::generate(&mut OsRng),
+            granted: Vec::new(),
+            received: Vec::new(),
+            quarantine: None,
+            policies: Arc::new(PolicySet::new()),
+            detector: None,
+        })
+    }
     
     /// Get lineage identity.
     pub fn identity(&self) -> &crate::identity::Identity {
         self.lineage.identity()
     }
     
+    /// Public key (hex) this actor signs the delegations it grants with.
+    pub fn delegation_key(&self) -> String {
+        hex::encode(self.signing_key.verifying_key().as_bytes())
+    }
+    
+    /// True if `delegation` was granted by this actor and signed with its key.
+    pub fn verify_delegation(&self, delegation: &Delegation) -> bool {
+        delegation.delegator_id == self.identity().id() && delegation.verify(&self.delegation_key())
+    }
+    
     /// Check if actor is alive (lineage not terminated).
     pub fn is_alive(&self) -> bool {
         self.lineage.is_alive()
@@ -233,6 +538,189 @@ impl TrustedActor {
         &self.trust
     }
     
+    /// Check if a capability can be exercised, in own right or by delegation.
+    pub fn has_capability(&self, capability: TrustCapability) -> bool {
+        self.quarantine.is_none() && self.lineage.is_alive() && self.authority.holds(capability)
+    }
+    
+    /// Check if this actor is quarantined after an ontological violation.
+    pub fn is_quarantined(&self) -> bool {
+        self.quarantine.is_some()
+    }
+    
+    /// The violation that quarantined this actor, if any.
+    pub fn quarantine_cause(&self) -> Option<&OntologicalViolation> {
+        self.quarantine.as_ref()
+    }
+    
+    /// Quarantines this actor and hands the violation to the global handler.
+    /// 
+    /// A quarantined actor lends nothing: its delegates lose every grant.
+    fn quarantine(&mut self, reason: String) -> OntologicalViolation {
+        let cause = violation::raise(self.lineage.identity().id(), reason);
+        self.authority.retire();
+        self.quarantine = Some(cause.clone());
+        cause
+    }
+    
+    /// Install the policy set enforced by `attempt_named_action`.
+    /// 
+    /// The set's digest is recorded in the causal chain, so the rules in
+    /// force at any point in the actor's history can be audited.
+    pub fn set_policies(&mut self, policies: Arc<PolicySet>) {
+        if !self.lineage.is_alive() || self.quarantine.is_some() {
+            return;
+        }
+        self.lineage.memory_mut().append(format!(
+            "Policy set installed: {} actions (digest: {})",
+            policies.len(),
+            policies.digest()
+        ));
+        self.policies = policies;
+    }
+    
+    /// The policy set enforced by `attempt_named_action`.
+    pub fn policies(&self) -> &PolicySet {
+        &self.policies
+    }
+    
+    /// Attach an anomaly detector to this actor's attempts.
+    /// 
+    /// From now on, every `attempt_action` and `attempt_named_action` is
+    /// observed; crossing a threshold records the evidence in memory and
+    /// then the configured violation, without a human in the loop.
+    pub fn set_detector(&mut self, detector: AnomalyDetector) {
+        if !self.lineage.is_alive() || self.quarantine.is_some() {
+            return;
+        }
+        self.lineage
+            .memory_mut()
+            .append(format!("Anomaly detector attached: {:?}", detector.config()));
+        self.detector = Some(detector);
+    }
+    
+    /// The attached anomaly detector, if any.
+    pub fn detector(&self) -> Option<&AnomalyDetector> {
+        self.detector.as_ref()
+    }
+    
+    /// Delegations this actor has granted.
+    pub fn delegations_granted(&self) -> &[Delegation] {
+        &self.granted
+    }
+    
+    /// Delegations this actor has received.
+    pub fn delegations_received(&self) -> &[Delegation] {
+        &self.received
+    }
+    
+    /// Lend a subset of this actor's capabilities to `to`.
+    /// 
+    /// ENFORCEMENT:
+    /// - Requires `TrustCapability::Delegate`
+    /// - Every capability must currently be held by this actor
+    /// - `to` must never have lost any of them
+    /// - Costs `energy_cost` from this actor's lineage
+    /// 
+    /// CONSEQUENCES:
+    /// - Signed delegation recorded in both causal chains
+    /// - Revoking this actor later revokes the grant for `to` (and its delegates)
+    pub fn delegate(
+        &mut self,
+        to: &mut TrustedActor,
+        capabilities: &[TrustCapability],
+        energy_cost: u64,
+    ) -> Result<Delegation, DelegationError> {
+        if let Some(cause) = self.quarantine.as_ref().or(to.quarantine.as_ref()) {
+            return Err(DelegationError::Quarantined(cause.clone()));
+        }
+        if !self.lineage.is_alive() || !to.lineage.is_alive() {
+            return Err(DelegationError::Terminated);
+        }
+        if !self.has_capability(TrustCapability::Delegate) {
+            return Err(DelegationError::CannotDelegate);
+        }
+        
+        let mut capabilities = capabilities.to_vec();
+        capabilities.sort();
+        capabilities.dedup();
+        if capabilities.is_empty() {
+            return Err(DelegationError::NothingToDelegate);
+        }
+        
+        let not_held: Vec<TrustCapability> = capabilities
+            .iter()
+            .copied()
+            .filter(|cap| !self.has_capability(*cap))
+            .collect();
+        if !not_held.is_empty() {
+            return Err(DelegationError::NotHeld(not_held));
+        }
+        
+        let previously_revoked: Vec<TrustCapability> = {
+            let revoked = read(&to.authority.revoked);
+            capabilities.iter().copied().filter(|cap| revoked.contains(cap)).collect()
+        };
+        if !previously_revoked.is_empty() {
+            return Err(DelegationError::PreviouslyRevoked(previously_revoked));
+        }
+        
+        if self.authority.depends_on(&to.authority) {
+            return Err(DelegationError::Cycle);
+        }
+        
+        let description = format!(
+            "Delegate {:?} to {} (cost: {})",
+            capabilities,
+            to.identity().id(),
+            energy_cost
+        );
+        match self.lineage.perform_operation(description, energy_cost) {
+            crate::lineage::OperationResult::Success { .. } => {}
+            crate::lineage::OperationResult::InsufficientEnergy { required, available } => {
+                return Err(DelegationError::InsufficientEnergy { required, available });
+            }
+            crate::lineage::OperationResult::Dead => {
+                self.authority.retire();
+                return Err(DelegationError::Terminated);
+            }
+            crate::lineage::OperationResult::OntologicalViolation { reason } => {
+                return Err(DelegationError::Quarantined(self.quarantine(reason)));
+            }
+        }
+        
+        let mut delegation = Delegation {
+            id: uuid::Uuid::new_v4().to_string(),
+            delegator_id: self.identity().id().to_string(),
+            delegate_id: to.identity().id().to_string(),
+            capabilities,
+            energy_cost,
+            granted_at: self.lineage.clock().now(),
+            signer_public_key: self.delegation_key(),
+            signature: String::new(),
+        };
+        delegation.signature =
+            hex::encode(self.signing_key.sign(delegation.signing_payload().as_bytes()).to_bytes());
+        
+        self.lineage.memory_mut().append(format!(
+            "Delegation {} granted to {}: {:?} (signature: {})",
+            delegation.id, delegation.delegate_id, delegation.capabilities, delegation.signature
+        ));
+        to.lineage.memory_mut().append(format!(
+            "Delegation {} received from {}: {:?} (signature: {})",
+            delegation.id, delegation.delegator_id, delegation.capabilities, delegation.signature
+        ));
+        
+        write(&to.authority.received).push(Grant {
+            from: Arc::clone(&self.authority),
+            capabilities: delegation.capabilities.iter().copied().collect(),
+        });
+        self.granted.push(delegation.clone());
+        to.received.push(delegation.clone());
+        
+        Ok(delegation)
+    }
+    
     /// Attempt to perform action requiring specific capability.
     /// 
     /// ENFORCEMENT:
@@ -240,38 +728,202 @@ impl TrustedActor {
     /// - Checks if capability is active
     /// - Consumes energy from lineage
     /// - Records operation in causal chain
+    /// - Feeds the anomaly detector, if attached
     pub fn attempt_action(
         &mut self,
         capability: TrustCapability,
         description: String,
         energy_cost: u64,
     ) -> TrustResult {
+        let access = Access {
+            authorized: self.authority.holds(capability),
+            revoked: self.authority.is_revoked(capability),
+        };
+        let result = self.try_action(capability, description.clone(), energy_cost);
+        self.observe(description, energy_cost, access, result)
+    }
+    
+    fn try_action(
+        &mut self,
+        capability: TrustCapability,
+        description: String,
+        energy_cost: u64,
+    ) -> TrustResult {
+        // A quarantined actor never acts again
+        if let Some(cause) = &self.quarantine {
+            return TrustResult::Quarantined { cause: cause.clone() };
+        }
+        
         // Check death state
         if !self.lineage.is_alive() {
             return TrustResult::Terminated;
         }
         
-        // Check capability
-        if !self.trust.has_capability(capability) {
+        // Check capability (own or delegated)
+        if !self.authority.holds(capability) {
             return TrustResult::Denied {
                 reason: format!("Capability {:?} has been revoked", capability),
             };
         }
         
+        self.spend(description, energy_cost)
+    }
+    
+    /// Attempt an action described by the installed policy set.
+    /// 
+    /// ENFORCEMENT:
+    /// - Unknown actions are denied (no rule, no permission)
+    /// - Every required capability must be held (own or delegated)
+    /// - Missing capabilities record the rule's violation: scar and revocation
+    /// - Permitted actions consume the rule's energy cost
+    /// - Feeds the anomaly detector, if attached
+    pub fn attempt_named_action(&mut self, action: &str) -> TrustResult {
+        let (energy_cost, access) = match self.policies.get(action) {
+            Some(policy) => (
+                policy.energy_cost,
+                Access {
+                    authorized: policy.requires.iter().all(|cap| self.authority.holds(*cap)),
+                    revoked: policy.requires.iter().any(|cap| self.authority.is_revoked(*cap)),
+                },
+            ),
+            None => (0, Access { authorized: false, revoked: false }),
+        };
+        let result = self.try_named_action(action);
+        self.observe(format!("Action: {}", action), energy_cost, access, result)
+    }
+    
+    fn try_named_action(&mut self, action: &str) -> TrustResult {
+        if let Some(cause) = &self.quarantine {
+            return TrustResult::Quarantined { cause: cause.clone() };
+        }
+        if !self.lineage.is_alive() {
+            return TrustResult::Terminated;
+        }
+        
+        let policies = Arc::clone(&self.policies);
+        let policy = match policies.get(action) {
+            Some(policy) => policy,
+            None => {
+                return TrustResult::Denied {
+                    reason: format!("No policy for action {}", action),
+                };
+            }
+        };
+        
+        let missing: Vec<TrustCapability> = policy
+            .requires
+            .iter()
+            .copied()
+            .filter(|cap| !self.authority.holds(*cap))
+            .collect();
+        if !missing.is_empty() {
+            return match self.record_violation(policy.on_violation) {
+                TrustResult::Denied { reason } => TrustResult::Denied {
+                    reason: format!("Action {} requires {:?}. {}", action, missing, reason),
+                },
+                other => other,
+            };
+        }
+        
+        self.spend(format!("Action: {}", action), policy.energy_cost)
+    }
+    
+    /// Feeds an attempt to the detector; raises its violation on a crossing.
+    /// 
+    /// Only authorization denials are observed: an authorized attempt that
+    /// fails for lack of energy is not suspicious. The evidence is appended
+    /// to memory before the violation is recorded.
+    fn observe(
+        &mut self,
+        description: String,
+        energy_cost: u64,
+        access: Access,
+        result: TrustResult,
+    ) -> TrustResult {
+        if !self.lineage.is_alive() {
+            return result;
+        }
+        let observation = match &result {
+            TrustResult::Allowed => Observation::Spent {
+                description,
+                energy: energy_cost,
+            },
+            TrustResult::Denied { .. } if access.authorized => return result,
+            TrustResult::Denied { reason } if access.revoked => Observation::RevokedCapability {
+                description,
+                reason: reason.clone(),
+            },
+            TrustResult::Denied { reason } => Observation::Denied {
+                description,
+                reason: reason.clone(),
+            },
+            TrustResult::Terminated | TrustResult::Quarantined { .. } => return result,
+        };
+        
+        let now = self.lineage.clock().now();
+        let report = match self.detector.as_mut().and_then(|d| d.observe(now, observation)) {
+            Some(report) => report,
+            None => return result,
+        };
+        
+        self.lineage.memory_mut().append(report.summary());
+        match self.record_violation(report.threshold.violation) {
+            TrustResult::Denied { .. } => result,
+            consequence => consequence,
+        }
+    }
+    
+    /// Consumes energy for a permitted action.
+    fn spend(&mut self, description: String, energy_cost: u64) -> TrustResult {
         // Perform operation (consumes energy, records in memory)
         match self.lineage.perform_operation(description, energy_cost) {
             crate::lineage::OperationResult::Success { .. } => TrustResult::Allowed,
             crate::lineage::OperationResult::InsufficientEnergy { .. } => TrustResult::Denied {
                 reason: "Insufficient energy".to_string(),
             },
-            crate::lineage::OperationResult::Dead => TrustResult::Terminated,
+            crate::lineage::OperationResult::Dead => {
+                // A dead actor lends nothing
+                self.authority.retire();
+                TrustResult::Terminated
+            }
             crate::lineage::OperationResult::OntologicalViolation { reason } => {
-                eprintln!("FATAL: Ontological violation: {}", reason);
-                std::process::exit(1);
+                TrustResult::Quarantined { cause: self.quarantine(reason) }
             }
         }
     }
     
+    /// Permanently revoke capabilities for a reason other than a violation,
+    /// such as a trust-grant downgrade. Returns the capabilities that were
+    /// still held in own right.
+    ///
+    /// No scar is inflicted, but the loss is recorded in the causal chain
+    /// and cascades to every delegate down the chain.
+    ///
+    /// IRREVERSIBLE: Revoked capabilities CANNOT be restored.
+    pub fn revoke_capabilities(
+        &mut self,
+        capabilities: &[TrustCapability],
+        reason: &str,
+    ) -> Vec<TrustCapability> {
+        let mut lost: Vec<TrustCapability> = capabilities
+            .iter()
+            .copied()
+            .filter(|cap| self.trust.has_capability(*cap))
+            .collect();
+        lost.sort();
+        lost.dedup();
+
+        self.trust.revoke_capabilities(capabilities);
+        self.authority.revoke(capabilities);
+
+        if !lost.is_empty() && self.lineage.is_alive() && self.quarantine.is_none() {
+            self.lineage
+                .memory_mut()
+                .append(format!("Capabilities revoked: {:?} - {}", lost, reason));
+        }
+        lost
+    }
+
     /// Record trust violation.
     /// 
     /// CONSEQUENCES:
@@ -279,8 +931,10 @@ impl TrustedActor {
     /// - Revokes capabilities (irreversible)
     /// - Fatal violations terminate lineage
     /// - All violations recorded in causal chain
+    /// - Revocation cascades to every delegate down the chain
     /// 
     /// IRREVERSIBLE: Lost capabilities CANNOT be restored.
+    /// Not even by delegation.
     pub fn record_violation(&mut self, violation: ViolationType) -> TrustResult {
         // Check if already dead
         if !self.lineage.is_alive() {
@@ -290,12 +944,21 @@ impl TrustedActor {
         // Revoke capabilities BEFORE recording error (prevents bypass)
         let revoked = violation.revoked_capabilities();
         self.trust.revoke_capabilities(&revoked);
+        self.authority.revoke(&revoked);
+        
+        // Never write to a quarantined (corrupted) lineage
+        if let Some(cause) = &self.quarantine {
+            return TrustResult::Quarantined { cause: cause.clone() };
+        }
         
         // Create error with appropriate severity
         let error = OperationError::new(
             violation.severity(),
             format!("Trust violation: {}", violation.description()),
-        );
+        )
+        .with_category(violation.category())
+        .with_code(violation.code())
+        .with_detail("revoked_capabilities", revoked.len().to_string());
         
         // Record in lineage (inflicts scar, may cause death)
         match self.lineage.record_error(error) {
@@ -310,11 +973,11 @@ impl TrustedActor {
             }
             crate::lineage::OperationResult::Dead => {
                 // Fatal violation or accumulated damage
+                self.authority.retire();
                 TrustResult::Terminated
             }
             crate::lineage::OperationResult::OntologicalViolation { reason } => {
-                eprintln!("FATAL: Ontological violation: {}", reason);
-                std::process::exit(1);
+                TrustResult::Quarantined { cause: self.quarantine(reason) }
             }
             _ => unreachable!("record_error cannot return InsufficientEnergy"),
         }
@@ -554,4 +1217,329 @@ mod tests {
         assert_eq!(actor.trust.capability_count(), after_violation);
         assert_eq!(actor.damage_score(), damage_after_violation);
     }
+
+    // DELEGATION TESTS
+
+    #[test]
+    fn delegation_is_signed_and_bounded_by_delegator() {
+        let mut admin = TrustedActor::create(1000);
+        let mut service = TrustedActor::service_account(500);
+        assert!(!service.has_capability(TrustCapability::Execute));
+
+        let delegation = admin
+            .delegate(&mut service, &[TrustCapability::Audit, TrustCapability::Execute], 10)
+            .unwrap();
+        assert!(delegation.verify(&admin.delegation_key()));
+        assert!(admin.verify_delegation(&delegation));
+        assert!(!service.verify_delegation(&delegation));
+        assert_eq!(delegation.capabilities, vec![TrustCapability::Execute, TrustCapability::Audit]);
+        assert_eq!(admin.lineage.metabolism().energy(), 990);
+        assert_eq!(service.delegations_received(), admin.delegations_granted());
+        assert!(service
+            .lineage
+            .memory()
+            .history()
+            .iter()
+            .any(|event| event.description().starts_with(&format!("Delegation {} received", delegation.id))));
+
+        assert_eq!(
+            service.attempt_action(TrustCapability::Execute, "Run job".to_string(), 5),
+            TrustResult::Allowed
+        );
+        assert!(!service.has_capability(TrustCapability::Configure));
+
+        // Service account cannot re-delegate without Delegate, nor lend what it lacks
+        let mut worker = TrustedActor::service_account(100);
+        assert_eq!(
+            service.delegate(&mut worker, &[TrustCapability::Execute], 1),
+            Err(DelegationError::CannotDelegate)
+        );
+        admin.record_violation(ViolationType::UnauthorizedAccess);
+        assert_eq!(
+            admin.delegate(&mut worker, &[TrustCapability::AccessSensitive, TrustCapability::Audit], 1),
+            Err(DelegationError::NotHeld(vec![TrustCapability::AccessSensitive]))
+        );
+
+        let mut tampered = delegation.clone();
+        tampered.capabilities.push(TrustCapability::Configure);
+        assert!(!tampered.verify(&admin.delegation_key()));
+    }
+
+    #[test]
+    fn self_signed_delegation_claiming_another_delegator_is_rejected() {
+        let admin = TrustedActor::create(1000);
+        let service = TrustedActor::service_account(500);
+        let forger = SigningKey::generate(&mut OsRng);
+
+        let mut forged = Delegation {
+            id: uuid::Uuid::new_v4().to_string(),
+            delegator_id: admin.identity().id().to_string(),
+            delegate_id: service.identity().id().to_string(),
+            capabilities: vec![TrustCapability::Configure],
+            energy_cost: 0,
+            granted_at: Utc::now(),
+            signer_public_key: hex::encode(forger.verifying_key().as_bytes()),
+            signature: String::new(),
+        };
+        forged.signature = hex::encode(forger.sign(forged.signing_payload().as_bytes()).to_bytes());
+
+        // Internally consistent, but not signed by the admin's key
+        assert!(forged.verify(&forged.signer_public_key));
+        assert!(!forged.verify(&admin.delegation_key()));
+        assert!(!admin.verify_delegation(&forged));
+    }
+
+    #[test]
+    fn revoking_delegator_cascades_down_the_chain() {
+        let mut admin = TrustedActor::create(1000);
+        let mut service = TrustedActor::service_account(500);
+        let mut worker = TrustedActor::service_account(500);
+
+        admin
+            .delegate(
+                &mut service,
+                &[TrustCapability::Execute, TrustCapability::Delegate, TrustCapability::Audit],
+                10,
+            )
+            .unwrap();
+        service
+            .delegate(&mut worker, &[TrustCapability::Execute, TrustCapability::Audit], 5)
+            .unwrap();
+        assert!(worker.has_capability(TrustCapability::Execute));
+
+        // Chains cannot loop back
+        assert_eq!(
+            service.delegate(&mut admin, &[TrustCapability::Audit], 1),
+            Err(DelegationError::Cycle)
+        );
+
+        // PolicyBypass revokes Execute, Configure and Delegate from the admin
+        admin.record_violation(ViolationType::PolicyBypass);
+        assert!(!service.has_capability(TrustCapability::Execute));
+        assert!(!worker.has_capability(TrustCapability::Execute));
+        assert_eq!(
+            worker.attempt_action(TrustCapability::Execute, "Run job".to_string(), 1),
+            TrustResult::Denied {
+                reason: "Capability Execute has been revoked".to_string()
+            }
+        );
+        assert!(worker.has_capability(TrustCapability::Audit));
+
+        // Death of the delegator takes everything with it
+        admin.record_violation(ViolationType::MaliciousAction);
+        assert!(!service.has_capability(TrustCapability::Audit));
+        assert!(!worker.has_capability(TrustCapability::Audit));
+    }
+
+    #[test]
+    fn delegation_cannot_restore_lost_trust() {
+        let mut admin = TrustedActor::create(1000);
+        let mut offender = TrustedActor::create(1000);
+        offender.record_violation(ViolationType::UnauthorizedAccess);
+
+        assert_eq!(
+            admin.delegate(&mut offender, &[TrustCapability::AccessSensitive], 1),
+            Err(DelegationError::PreviouslyRevoked(vec![TrustCapability::AccessSensitive]))
+        );
+
+        // A delegate that later violates loses the lent capability too
+        let mut service = TrustedActor::service_account(500);
+        admin.delegate(&mut service, &[TrustCapability::AccessSensitive], 1).unwrap();
+        service.record_violation(ViolationType::UnauthorizedAccess);
+        assert!(!service.has_capability(TrustCapability::AccessSensitive));
+        assert!(admin.has_capability(TrustCapability::AccessSensitive));
+    }
+
+    #[test]
+    fn ontological_violation_quarantines_actor_and_its_delegates() {
+        let _policy = crate::violation::scoped_policy(crate::violation::ViolationPolicy::Report);
+
+        let mut admin = TrustedActor::create(1000);
+        let mut service = TrustedActor::service_account(500);
+        admin.delegate(&mut service, &[TrustCapability::Audit], 1).unwrap();
+        admin.lineage.corrupt_for_test();
+
+        let cause = match admin.attempt_action(TrustCapability::Audit, "Audit".to_string(), 1) {
+            TrustResult::Quarantined { cause } => cause,
+            other => panic!("expected quarantine, got {:?}", other),
+        };
+        assert!(admin.is_quarantined());
+        assert_eq!(admin.quarantine_cause(), Some(&cause));
+        assert_eq!(
+            admin.record_violation(ViolationType::UnauthorizedAccess),
+            TrustResult::Quarantined { cause: cause.clone() }
+        );
+        assert!(!service.has_capability(TrustCapability::Audit));
+        assert_eq!(
+            admin.delegate(&mut service, &[TrustCapability::Audit], 1),
+            Err(DelegationError::Quarantined(cause))
+        );
+    }
+
+    // POLICY TESTS
+
+    #[test]
+    fn named_actions_follow_the_installed_policy() {
+        use crate::policy::ActionPolicy;
+
+        let mut policies = PolicySet::new();
+        policies
+            .register(ActionPolicy::new(
+                "rotate_keys",
+                &[TrustCapability::Configure, TrustCapability::AccessSensitive],
+                25,
+                ViolationType::PolicyBypass,
+            ))
+            .unwrap();
+        policies
+            .register(ActionPolicy::new("read_audit_log", &[TrustCapability::Audit], 1, ViolationType::UnauthorizedAccess))
+            .unwrap();
+        let policies = Arc::new(policies);
+
+        let mut admin = TrustedActor::create(1000);
+        admin.set_policies(Arc::clone(&policies));
+        assert!(admin
+            .lineage
+            .memory()
+            .latest()
+            .description()
+            .ends_with(&format!("(digest: {})", policies.digest())));
+
+        assert_eq!(admin.attempt_named_action("rotate_keys"), TrustResult::Allowed);
+        assert_eq!(admin.lineage.metabolism().energy(), 975);
+        assert_eq!(
+            admin.attempt_named_action("drop_tables"),
+            TrustResult::Denied {
+                reason: "No policy for action drop_tables".to_string()
+            }
+        );
+
+        // A service account holding only Audit misuses rotate_keys
+        let mut auditor = TrustedActor::service_account(500);
+        auditor.set_policies(policies);
+        admin.delegate(&mut auditor, &[TrustCapability::Audit], 1).unwrap();
+        assert_eq!(auditor.attempt_named_action("read_audit_log"), TrustResult::Allowed);
+        assert_eq!(
+            auditor.attempt_named_action("rotate_keys"),
+            TrustResult::Denied {
+                reason: "Action rotate_keys requires [Configure, AccessSensitive]. Violation recorded. 3 capabilities revoked."
+                    .to_string()
+            }
+        );
+        assert_eq!(auditor.lineage.scars().scars_by_code("TRUST_POLICY_BYPASS").len(), 1);
+        assert_eq!(auditor.lineage.metabolism().energy(), 499);
+    }
+
+    // ANOMALY TESTS
+
+    #[test]
+    fn repeated_revoked_attempts_raise_policy_bypass() {
+        use crate::anomaly::AnomalySignal;
+
+        let mut actor = TrustedActor::create(1000);
+        actor.set_detector(AnomalyDetector::default());
+        actor.record_violation(ViolationType::UnauthorizedAccess);
+
+        for n in 0..2 {
+            actor.attempt_action(TrustCapability::AccessSensitive, format!("Read vault {}", n), 5);
+        }
+        assert!(actor.trust.has_capability(TrustCapability::Execute));
+
+        // Third attempt within the window crosses the threshold
+        actor.attempt_action(TrustCapability::AccessSensitive, "Read vault 2".to_string(), 5);
+        assert!(!actor.trust.has_capability(TrustCapability::Execute));
+        assert_eq!(actor.lineage.scars().scars_by_code("TRUST_POLICY_BYPASS").len(), 1);
+
+        let report = &actor.detector().unwrap().reports()[0];
+        assert_eq!(report.signal, AnomalySignal::RevokedCapabilityAttempts);
+        assert_eq!(report.evidence.len(), 3);
+        assert!(actor
+            .lineage
+            .memory()
+            .history()
+            .iter()
+            .any(|event| event.description() == report.summary()));
+    }
+
+    #[test]
+    fn energy_shortfalls_are_not_anomalies() {
+        let mut actor = TrustedActor::create(10);
+        actor.set_detector(AnomalyDetector::default());
+
+        for n in 0..8 {
+            assert_eq!(
+                actor.attempt_action(TrustCapability::Execute, format!("Big job {}", n), 50),
+                TrustResult::Denied { reason: "Insufficient energy".to_string() }
+            );
+        }
+        assert!(actor.detector().unwrap().reports().is_empty());
+        assert_eq!(actor.lineage.scars().scar_count(), 0);
+    }
+
+    #[test]
+    fn never_held_capability_is_a_denial_not_a_revoked_attempt() {
+        use crate::anomaly::AnomalySignal;
+
+        let mut service = TrustedActor::service_account(1000);
+        service.set_detector(AnomalyDetector::default());
+
+        // A service account never held Configure: nothing was revoked
+        for n in 0..5 {
+            service.attempt_action(TrustCapability::Configure, format!("Reconfigure {}", n), 1);
+        }
+        let reports = service.detector().unwrap().reports();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].signal, AnomalySignal::DeniedActions);
+    }
+
+    #[test]
+    fn named_action_revoking_on_attempt_is_not_a_prior_revocation() {
+        use crate::policy::ActionPolicy;
+
+        let mut policies = PolicySet::new();
+        policies
+            .register(ActionPolicy::new(
+                "read_vault",
+                &[TrustCapability::AccessSensitive],
+                1,
+                ViolationType::UnauthorizedAccess,
+            ))
+            .unwrap();
+
+        let mut service = TrustedActor::service_account(1000);
+        service.set_policies(Arc::new(policies));
+        service.set_detector(AnomalyDetector::default());
+
+        // The first attempt records a violation revoking AccessSensitive;
+        // only later attempts hit an already-revoked capability
+        for _ in 0..3 {
+            service.attempt_named_action("read_vault");
+        }
+        assert!(service.detector().unwrap().reports().is_empty());
+        service.attempt_named_action("read_vault");
+        let reports = service.detector().unwrap().reports();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].signal, crate::anomaly::AnomalySignal::RevokedCapabilityAttempts);
+    }
+
+    #[test]
+    fn energy_spike_can_be_fatal() {
+        use crate::anomaly::{AnomalyConfig, Threshold};
+
+        let mut actor = TrustedActor::create(1000);
+        actor.set_detector(AnomalyDetector::new(AnomalyConfig {
+            energy_burn: Some(Threshold {
+                limit: 300,
+                violation: ViolationType::MaliciousAction,
+            }),
+            ..AnomalyConfig::default()
+        }).unwrap());
+
+        assert_eq!(actor.attempt_action(TrustCapability::Execute, "Export".to_string(), 200), TrustResult::Allowed);
+        assert_eq!(
+            actor.attempt_action(TrustCapability::Execute, "Export".to_string(), 200),
+            TrustResult::Terminated
+        );
+        assert!(!actor.is_alive());
+    }
 }
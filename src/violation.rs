@@ -0,0 +1,158 @@
+//! # Ontological Violation Handling
+//!
+//! ## What This Enforces
+//! - A lineage whose invariants break is quarantined and never operates again
+//! - Every violation goes through one process-wide handler
+//! - The host process, not the library, decides whether to die
+//!
+//! ## What This Forbids
+//! - Continuing to operate a corrupted agent
+//! - Lifting a quarantine
+//!
+//! ## Policies
+//! - [`ViolationPolicy::Abort`] - report and exit the process (the default,
+//!   and the historical behavior)
+//! - [`ViolationPolicy::Panic`] - panic, so the host can catch or unwind
+//! - [`ViolationPolicy::Report`] - report and return a typed result; the
+//!   agent stays quarantined
+//!
+//! Servers embedding many agents set `Report` once at startup:
+//!
+//! ```rust
+//! use lineage::violation::{set_violation_policy, ViolationPolicy};
+//!
+//! set_violation_policy(ViolationPolicy::Report);
+//! ```
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// A broken invariant detected in a lineage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OntologicalViolation {
+    /// Identity of the corrupted lineage
+    pub lineage_id: String,
+    /// Which invariant broke
+    pub reason: String,
+}
+
+impl std::fmt::Display for OntologicalViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ontological violation in {}: {}", self.lineage_id, self.reason)
+    }
+}
+
+impl std::error::Error for OntologicalViolation {}
+
+/// What happens to the process when a violation is detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViolationPolicy {
+    /// Report, then exit the process with status 1
+    #[default]
+    Abort,
+    /// Panic with the violation
+    Panic,
+    /// Report and return; the caller receives a typed result
+    Report,
+}
+
+/// Receives every violation before the policy is applied
+pub type ViolationReporter = Arc<dyn Fn(&OntologicalViolation) + Send + Sync>;
+
+static POLICY: AtomicU8 = AtomicU8::new(0);
+static REPORTER: RwLock<Option<ViolationReporter>> = RwLock::new(None);
+
+/// Set the process-wide violation policy.
+pub fn set_violation_policy(policy: ViolationPolicy) {
+    let code = match policy {
+        ViolationPolicy::Abort => 0,
+        ViolationPolicy::Panic => 1,
+        ViolationPolicy::Report => 2,
+    };
+    POLICY.store(code, Ordering::SeqCst);
+}
+
+/// The current process-wide violation policy.
+pub fn violation_policy() -> ViolationPolicy {
+    match POLICY.load(Ordering::SeqCst) {
+        1 => ViolationPolicy::Panic,
+        2 => ViolationPolicy::Report,
+        _ => ViolationPolicy::Abort,
+    }
+}
+
+/// Serializes tests that depend on the process-wide policy
+#[cfg(test)]
+static POLICY_SCOPE: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Restores the previous policy when dropped (see `scoped_policy`)
+#[cfg(test)]
+pub(crate) struct PolicyGuard {
+    previous: ViolationPolicy,
+    _scope: std::sync::MutexGuard<'static, ()>,
+}
+
+#[cfg(test)]
+impl Drop for PolicyGuard {
+    fn drop(&mut self) {
+        set_violation_policy(self.previous);
+    }
+}
+
+/// Set `policy` until the returned guard drops, then restore the previous one.
+///
+/// Guards are taken one at a time, so tests never see each other's policy.
+#[cfg(test)]
+pub(crate) fn scoped_policy(policy: ViolationPolicy) -> PolicyGuard {
+    let scope = POLICY_SCOPE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let previous = violation_policy();
+    set_violation_policy(policy);
+    PolicyGuard {
+        previous,
+        _scope: scope,
+    }
+}
+
+/// Route reports to `reporter` instead of stderr (e.g. into a log or alert).
+pub fn set_violation_reporter(reporter: ViolationReporter) {
+    *REPORTER.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(reporter);
+}
+
+/// Report a violation and apply the policy.
+///
+/// Returns the violation only under `Report`; `Abort` exits and `Panic` unwinds.
+pub(crate) fn raise(lineage_id: &str, reason: String) -> OntologicalViolation {
+    let violation = OntologicalViolation {
+        lineage_id: lineage_id.to_string(),
+        reason,
+    };
+
+    let reporter = REPORTER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    match reporter {
+        Some(reporter) => reporter(&violation),
+        None => eprintln!("FATAL: {}", violation),
+    }
+
+    match violation_policy() {
+        ViolationPolicy::Abort => std::process::exit(1),
+        ViolationPolicy::Panic => panic!("ONTOLOGICAL VIOLATION: {}", violation),
+        ViolationPolicy::Report => violation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "ONTOLOGICAL VIOLATION")]
+    fn panic_policy_unwinds_instead_of_exiting() {
+        let _guard = scoped_policy(ViolationPolicy::Panic);
+        raise("lineage-under-test", "Scar corruption".to_string());
+    }
+}